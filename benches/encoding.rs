@@ -0,0 +1,49 @@
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use decaf377::{Element, Encoding, Fq, Fr};
+use rand_chacha::ChaChaRng;
+use rand_core::{RngCore, SeedableRng};
+
+pub fn bench_batch_compress(c: &mut Criterion) {
+    let mut group = c.benchmark_group("batch_compress");
+    let mut rng = ChaChaRng::seed_from_u64(666);
+
+    for n in [1, 16, 256] {
+        // Compress the points P_i = 2 Q_i one at a time, in a batch, or in a
+        // batch from the Q_i.
+        let halves: Vec<Element> = (0..n)
+            .map(|_| {
+                let mut p_bytes = [0u8; 32];
+                rng.fill_bytes(&mut p_bytes);
+                Fr::from(3u64) * Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&p_bytes))
+            })
+            .collect();
+        let points: Vec<Element> = halves.iter().map(|q| q + q).collect();
+
+        group.bench_with_input(
+            BenchmarkId::new("VartimeCompress", n),
+            &points,
+            |b, points| {
+                b.iter(|| {
+                    points
+                        .iter()
+                        .map(|p| p.vartime_compress())
+                        .collect::<Vec<Encoding>>()
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("BatchCompress", n),
+            &points,
+            |b, points| b.iter(|| Element::batch_compress(points)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("BatchVartimeDoubleAndCompress", n),
+            &halves,
+            |b, halves| b.iter(|| Element::batch_vartime_double_and_compress(halves)),
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_batch_compress);
+criterion_main!(benches);
@@ -0,0 +1,65 @@
+use ark_ec::Group;
+use ark_ff::PrimeField;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use decaf377::{Element, Fq, Fr, GENERATOR_TABLE};
+use rand_chacha::ChaChaRng;
+use rand_core::{RngCore, SeedableRng};
+
+pub fn bench_scalar_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scalar_mul");
+    let n = 10;
+    let mut rng = ChaChaRng::seed_from_u64(666);
+    let mut test_inputs = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut p_bytes = [0u8; 32];
+        let mut s_bytes = [0u8; 32];
+        rng.fill_bytes(&mut p_bytes);
+        rng.fill_bytes(&mut s_bytes);
+        test_inputs.push((
+            Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&p_bytes[..])),
+            Fr::from_le_bytes_mod_order(&s_bytes[..]),
+        ))
+    }
+
+    for (i, (point, scalar)) in test_inputs.iter().enumerate() {
+        group.bench_with_input(
+            BenchmarkId::new("Arkworks", i),
+            &(point, scalar),
+            |b, (point, scalar)| b.iter(|| point.mul_bigint(scalar.into_bigint())),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("Radix16", i),
+            &(point, scalar),
+            |b, (point, scalar)| b.iter(|| *point * *scalar),
+        );
+    }
+    group.finish();
+}
+
+pub fn bench_basepoint_mul(c: &mut Criterion) {
+    let mut group = c.benchmark_group("basepoint_mul");
+    let n = 10;
+    let mut rng = ChaChaRng::seed_from_u64(666);
+    let mut test_scalars = Vec::with_capacity(n);
+    for _ in 0..n {
+        let mut s_bytes = [0u8; 32];
+        rng.fill_bytes(&mut s_bytes);
+        test_scalars.push(Fr::from_le_bytes_mod_order(&s_bytes[..]))
+    }
+
+    for (i, scalar) in test_scalars.iter().enumerate() {
+        group.bench_with_input(BenchmarkId::new("Radix16", i), scalar, |b, scalar| {
+            b.iter(|| Element::GENERATOR * *scalar)
+        });
+        group.bench_with_input(BenchmarkId::new("Table", i), scalar, |b, scalar| {
+            b.iter(|| GENERATOR_TABLE.mul(scalar))
+        });
+        group.bench_with_input(BenchmarkId::new("TableVartime", i), scalar, |b, scalar| {
+            b.iter(|| GENERATOR_TABLE.vartime_mul(scalar))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_scalar_mul, bench_basepoint_mul);
+criterion_main!(benches);
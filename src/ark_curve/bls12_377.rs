@@ -1,4 +1,4 @@
-use crate::fields::{fp::Fp, fq::Fq};
+use crate::fields::{fp::Fp, fp12, fp2, fp6, fq::Fq};
 use ark_ec::{
     bls12::{Bls12, Bls12Config, TwistType},
     models::short_weierstrass::SWCurveConfig,
@@ -17,6 +17,18 @@ impl Fp2Config for F2Config {
     const FROBENIUS_COEFF_FP2_C1: &'static [Fp] = &[Fp::ONE, Fp::MINUS_ONE];
 }
 
+/// Convert the native Frobenius coefficients, which are shared with
+/// [`crate::bls12_377`], into arkworks field elements.
+const fn to_ark_fp2s<const N: usize>(coeffs: [fp2::Fp2; N]) -> [Fp2<F2Config>; N] {
+    let mut out = [Fp2::new(Fp::ZERO, Fp::ZERO); N];
+    let mut i = 0;
+    while i < N {
+        out[i] = Fp2::new(coeffs[i].c0, coeffs[i].c1);
+        i += 1;
+    }
+    out
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct F6Config;
 
@@ -25,103 +37,11 @@ impl Fp6Config for F6Config {
 
     const NONRESIDUE: Fp2<Self::Fp2Config> = Fp2::new(Fp::ZERO, Fp::ONE);
 
-    const FROBENIUS_COEFF_FP6_C1: &'static [Fp2<Self::Fp2Config>] = &[
-        Fp2::new(Fp::ONE, Fp::ZERO),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                6382252053795993818,
-                1383562296554596171,
-                11197251941974877903,
-                6684509567199238270,
-                6699184357838251020,
-                19987743694136192,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                15766275933608376691,
-                15635974902606112666,
-                1934946774703877852,
-                18129354943882397960,
-                15437979634065614942,
-                101285514078273488,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(Fp::MINUS_ONE, Fp::ZERO),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                3203870859294639911,
-                276961138506029237,
-                9479726329337356593,
-                13645541738420943632,
-                7584832609311778094,
-                101110569012358506,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                12266591053191808654,
-                4471292606164064357,
-                295287422898805027,
-                2200696361737783943,
-                17292781406793965788,
-                19812798628221209,
-            ]),
-            Fp::ZERO,
-        ),
-    ];
+    const FROBENIUS_COEFF_FP6_C1: &'static [Fp2<Self::Fp2Config>] =
+        &to_ark_fp2s(fp6::FROBENIUS_COEFF_FP6_C1);
 
-    const FROBENIUS_COEFF_FP6_C2: &'static [Fp2<Self::Fp2Config>] = &[
-        Fp2::new(Fp::ONE, Fp::ZERO),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                15766275933608376691,
-                15635974902606112666,
-                1934946774703877852,
-                18129354943882397960,
-                15437979634065614942,
-                101285514078273488,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                3203870859294639911,
-                276961138506029237,
-                9479726329337356593,
-                13645541738420943632,
-                7584832609311778094,
-                101110569012358506,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(Fp::ONE, Fp::ZERO),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                15766275933608376691,
-                15635974902606112666,
-                1934946774703877852,
-                18129354943882397960,
-                15437979634065614942,
-                101285514078273488,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                3203870859294639911,
-                276961138506029237,
-                9479726329337356593,
-                13645541738420943632,
-                7584832609311778094,
-                101110569012358506,
-            ]),
-            Fp::ZERO,
-        ),
-    ];
+    const FROBENIUS_COEFF_FP6_C2: &'static [Fp2<Self::Fp2Config>] =
+        &to_ark_fp2s(fp6::FROBENIUS_COEFF_FP6_C2);
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -132,120 +52,8 @@ impl Fp12Config for F12Config {
 
     const NONRESIDUE: Fp6<Self::Fp6Config> = Fp6::new(Fp2::ZERO, Fp2::ONE, Fp2::ZERO);
 
-    const FROBENIUS_COEFF_FP12_C1: &'static [Fp2<F2Config>] = &[
-        Fp2::new(Fp::ONE, Fp::ZERO),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                7981638599956744862,
-                11830407261614897732,
-                6308788297503259939,
-                10596665404780565693,
-                11693741422477421038,
-                61545186993886319,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                6382252053795993818,
-                1383562296554596171,
-                11197251941974877903,
-                6684509567199238270,
-                6699184357838251020,
-                19987743694136192,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                10965161018967488287,
-                18251363109856037426,
-                7036083669251591763,
-                16109345360066746489,
-                4679973768683352764,
-                96952949334633821,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                15766275933608376691,
-                15635974902606112666,
-                1934946774703877852,
-                18129354943882397960,
-                15437979634065614942,
-                101285514078273488,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                2983522419010743425,
-                6420955848241139694,
-                727295371748331824,
-                5512679955286180796,
-                11432976419915483342,
-                35407762340747501,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(Fp::MINUS_ONE, Fp::ZERO),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                1604484313133888867,
-                8276860247155279292,
-                14368189973808974556,
-                9733385900839616209,
-                2590275544672608076,
-                59553125712608379,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                3203870859294639911,
-                276961138506029237,
-                9479726329337356593,
-                13645541738420943632,
-                7584832609311778094,
-                101110569012358506,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                17067705967832697058,
-                1855904398914139597,
-                13640894602060642732,
-                4220705945553435413,
-                9604043198466676350,
-                24145363371860877,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                12266591053191808654,
-                4471292606164064357,
-                295287422898805027,
-                2200696361737783943,
-                17292781406793965788,
-                19812798628221209,
-            ]),
-            Fp::ZERO,
-        ),
-        Fp2::new(
-            Fp::from_montgomery_limbs([
-                6602600494079890304,
-                13686311660529037330,
-                1502938825854351055,
-                14817371350334001107,
-                2851040547234545772,
-                85690550365747197,
-            ]),
-            Fp::ZERO,
-        ),
-    ];
+    const FROBENIUS_COEFF_FP12_C1: &'static [Fp2<F2Config>] =
+        &to_ark_fp2s(fp12::FROBENIUS_COEFF_FP12_C1);
 }
 
 pub struct OurG1Config;
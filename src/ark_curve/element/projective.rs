@@ -1,12 +1,13 @@
-use core::borrow::Borrow;
 use core::hash::Hash;
 
+use ark_ec::Group;
 use ark_ff::Zero;
 use ark_std::fmt::{Display, Formatter, Result as FmtResult};
 
+use subtle::{Choice, ConditionallySelectable};
 use zeroize::Zeroize;
 
-use crate::{ark_curve::EdwardsProjective, Fq, Fr};
+use crate::{ark_curve::EdwardsProjective, window::LookupTable, Fq, Fr};
 
 use super::super::constants::{B_T, B_X, B_Y, B_Z};
 
@@ -24,6 +25,14 @@ impl Element {
     pub const IDENTITY: Self = Self {
         inner: EdwardsProjective::new_unchecked(Fq::ZERO, Fq::ONE, Fq::ZERO, Fq::ONE),
     };
+
+    /// Construct an element from affine coordinates `(x, y)` and `t = x * y`,
+    /// without checking that it is valid.
+    pub(crate) const fn from_affine_unchecked(x: Fq, y: Fq, t: Fq) -> Self {
+        Self {
+            inner: EdwardsProjective::new_unchecked(x, y, t, Fq::ONE),
+        }
+    }
 }
 
 impl Hash for Element {
@@ -71,6 +80,19 @@ impl PartialEq for Element {
 
 impl Eq for Element {}
 
+impl ConditionallySelectable for Element {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Element {
+            inner: EdwardsProjective::new_unchecked(
+                Fq::conditional_select(&a.inner.x, &b.inner.x, choice),
+                Fq::conditional_select(&a.inner.y, &b.inner.y, choice),
+                Fq::conditional_select(&a.inner.t, &b.inner.t, choice),
+                Fq::conditional_select(&a.inner.z, &b.inner.z, choice),
+            ),
+        }
+    }
+}
+
 impl Zeroize for Element {
     fn zeroize(&mut self) {
         self.inner.zeroize()
@@ -85,35 +107,43 @@ impl Element {
         self.inner.x == Fq::zero()
     }
 
-    /// Given an iterator of public scalars and an iterator of public points,
-    /// compute
+    /// Multiply by a scalar in constant time, using a signed radix-16 fixed
+    /// window.
+    pub(crate) fn windowed_mul(&self, scalar: &Fr) -> Element {
+        let table = LookupTable::new(Element::IDENTITY, *self);
+        let digits = scalar.to_radix_16();
+
+        let mut acc = table.select(digits[63]);
+        for digit in digits[..63].iter().rev() {
+            acc = acc.mul_by_pow_2(4);
+            acc += table.select(*digit);
+        }
+        acc
+    }
+
+    /// Compute `2^k * self` by repeated doubling.
+    pub(crate) fn mul_by_pow_2(&self, k: u32) -> Element {
+        let mut inner = self.inner;
+        for _ in 0..k {
+            inner.double_in_place();
+        }
+        Element { inner }
+    }
+
+    /// Given a slice of public scalars and a slice of public points, compute
     /// $$
     /// Q = \[c\_1\] P\_1 + \cdots + \[c\_n\] P\_n,
     /// $$
     /// using variable-time operations.
     ///
-    /// It is an error to call this function with two iterators of different
-    /// lengths -- it would require `ExactSizeIterator`, but
-    /// `ExactSizeIterator`s are not closed under chaining, and disallowing
-    /// iterator chaining would destroy the utility of the function.
-    pub fn vartime_multiscalar_mul<I, J>(scalars: I, points: J) -> Element
-    where
-        I: IntoIterator,
-        I::Item: Borrow<Fr>,
-        J: IntoIterator,
-        J::Item: Borrow<Element>,
-    {
-        // XXX this is a stub implementation, try to use a real MSM later
-        let scalars = scalars.into_iter();
-        let points = points.into_iter();
-
-        // XXX panic on length mismatches ? or error?
-
-        scalars
-            .zip(points)
-            .fold(Element::default(), |acc, (scalar, point)| {
-                acc + (scalar.borrow() * point.borrow())
-            })
+    /// This uses Straus' method for small inputs and Pippenger's method for
+    /// large ones, and does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    pub fn vartime_multiscalar_mul(scalars: &[Fr], points: &[Element]) -> Element {
+        crate::msm::vartime_multiscalar_mul(scalars, points)
     }
 }
 
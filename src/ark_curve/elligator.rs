@@ -1,11 +1,16 @@
 #![allow(non_snake_case)]
 use ark_ec::twisted_edwards::TECurveConfig;
+use digest::{crypto_common::BlockSizeUser, Digest};
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConditionallySelectable};
 
 use crate::ark_curve::edwards::{Decaf377EdwardsConfig, EdwardsProjective};
 
 use crate::{
     ark_curve::constants::{ONE, TWO, ZETA},
     ark_curve::on_curve::OnCurve,
+    elligator_squared,
+    hash_to_field::hash_to_field,
     sign::Sign,
     Element, Fq,
 };
@@ -13,6 +18,15 @@ use crate::{
 impl Element {
     /// Elligator 2 map to decaf377 point
     fn elligator_map(r_0: &Fq) -> Element {
+        Element::elligator_map_both::<false>(r_0)
+    }
+
+    /// Constant-time Elligator 2 map to decaf377 point
+    fn elligator_map_ct(r_0: &Fq) -> Element {
+        Element::elligator_map_both::<true>(r_0)
+    }
+
+    fn elligator_map_both<const CT: bool>(r_0: &Fq) -> Element {
         // Ref: `Decaf_1_1_Point.elligator` (optimized) in `ristretto.sage`
         let A = Decaf377EdwardsConfig::COEFF_A;
         let D = Decaf377EdwardsConfig::COEFF_D;
@@ -23,26 +37,26 @@ impl Element {
         let num = (r + *ONE) * (A - *TWO * D);
 
         let x = num * den;
-        let (iss, mut isri) = Fq::sqrt_ratio_zeta(&ONE, &x);
-
-        let sgn;
-        let twiddle;
-        if iss {
-            sgn = *ONE;
-            twiddle = *ONE;
+        let (iss, mut isri) = if CT {
+            Fq::sqrt_ratio_zeta_ct(&ONE, &x)
         } else {
-            sgn = -(*ONE);
-            twiddle = *r_0;
-        }
+            let (iss, isri) = Fq::sqrt_ratio_zeta(&ONE, &x);
+            (Choice::from(iss as u8), isri)
+        };
+
+        // Case 1: iss is true, then sgn and twiddle are both 1
+        // Case 2: iss is false, then sgn is -1 and twiddle is r_0
+        let sgn = Fq::conditional_select(&-(*ONE), &ONE, iss);
+        let twiddle = Fq::conditional_select(r_0, &ONE, iss);
 
         isri *= twiddle;
 
         let mut s = isri * num;
         let t = -(sgn) * isri * s * (r - *ONE) * (A - *TWO * D).square() - *ONE;
 
-        if s.is_negative() == iss {
-            s = -s
-        }
+        // if s.is_negative() == iss { s = -s }
+        let cond_negate = !(s.ct_is_negative() ^ iss);
+        s = Fq::conditional_select(&s, &-s, cond_negate);
 
         // Convert point to extended projective (X : Y : Z : T)
         let E = *TWO * s;
@@ -50,7 +64,7 @@ impl Element {
         let G = *ONE - Decaf377EdwardsConfig::COEFF_A * s.square();
         let H = t;
         let result = Element {
-            inner: EdwardsProjective::new(E * H, F * G, E * G, F * H),
+            inner: EdwardsProjective::new_unchecked(E * H, F * G, E * G, F * H),
         };
 
         debug_assert!(
@@ -70,14 +84,143 @@ impl Element {
         &R_1 + &R_2
     }
 
+    /// Hash a message to a uniformly distributed decaf377 `Element`.
+    ///
+    /// This follows the random-oracle construction of RFC 9380: `msg` is hashed
+    /// to two field elements using `expand_message_xmd` with the hash function
+    /// `H` and the domain separation tag `dst`, and these are then mapped to the
+    /// group with `hash_to_curve`. With SHA-512, the suite ID is
+    /// `decaf377_XMD:SHA-512_D377MAP_RO_`.
+    pub fn hash_to_group<H: Digest + BlockSizeUser>(dst: &[u8], msg: &[u8]) -> Element {
+        let [r_1, r_2] = hash_to_field::<H>(dst, msg);
+        Element::hash_to_curve(&r_1, &r_2)
+    }
+
+    /// Constant-time equivalent of `hash_to_curve`.
+    pub fn hash_to_curve_ct(r_1: &Fq, r_2: &Fq) -> Element {
+        let R_1 = Element::elligator_map_ct(r_1);
+        let R_2 = Element::elligator_map_ct(r_2);
+        R_1 + R_2
+    }
+
     /// Maps a field element to a decaf377 `Element` suitable for CDH challenges.
     pub fn encode_to_curve(r: &Fq) -> Element {
         Element::elligator_map(r)
     }
+
+    /// Constant-time equivalent of `encode_to_curve`.
+    pub fn encode_to_curve_ct(r: &Fq) -> Element {
+        Element::elligator_map_ct(r)
+    }
+
+    /// Find all field elements `r` such that `encode_to_curve(r)` is this
+    /// element.
+    ///
+    /// Preimages come in pairs `r, -r`, and there are at most four such pairs.
+    /// The preimages fill the start of the returned array, with the remaining
+    /// entries set to `None`. This runs in variable time.
+    pub fn elligator_inverse(&self) -> [Option<Fq>; 8] {
+        let mut preimages = [None; 8];
+        let mut n = 0;
+        for r in self.elligator_inverse_candidates().into_iter().flatten() {
+            // r = ZETA * r_0^2, so r_0 exists exactly when r / ZETA is square.
+            let (was_square, r_0) = Fq::sqrt_ratio_zeta(&r, &ZETA);
+            if !was_square || Element::encode_to_curve(&r_0) != *self {
+                continue;
+            }
+            for r_0 in [r_0, -r_0] {
+                if !preimages.contains(&Some(r_0)) {
+                    preimages[n] = Some(r_0);
+                    n += 1;
+                }
+            }
+        }
+        preimages
+    }
+
+    /// Encode this element as 64 bytes which are indistinguishable from
+    /// uniformly random bytes, using Elligator Squared.
+    ///
+    /// The encoding is randomized, and decodes back to this element with
+    /// `from_uniform_bytes`. This runs in variable time.
+    pub fn to_uniform_bytes<R: CryptoRngCore>(&self, rng: &mut R) -> [u8; 64] {
+        elligator_squared::to_uniform_bytes(self, rng)
+    }
+
+    /// Decode 64 bytes produced by `to_uniform_bytes`, as
+    /// `hash_to_curve(r_1, r_2)` where `r_1` and `r_2` are the two 32-byte
+    /// halves reduced modulo the field order.
+    ///
+    /// Every 64-byte string decodes to some element.
+    pub fn from_uniform_bytes(bytes: &[u8; 64]) -> Element {
+        elligator_squared::from_uniform_bytes(bytes)
+    }
+
+    /// Compute the values of `r = ZETA * r_0^2` that could map to this element.
+    ///
+    /// Each candidate must still be checked against the forward map.
+    fn elligator_inverse_candidates(&self) -> [Option<Fq>; 4] {
+        // Ref: `Decaf_1_1_Point.elligatorSpec` in `ristretto.sage`, which
+        // produces a point on the Jacobi quartic with
+        //
+        //   s^2 = (r + 1)(a - 2d) / den, t = -(r - 1)(a - 2d)^2 / den - 1
+        //
+        // when the first is square (and then s is nonnegative), or otherwise
+        //
+        //   s^2 = r(r + 1)(a - 2d) / den, t = r(r - 1)(a - 2d)^2 / den - 1
+        //
+        // with s negative. In both cases k = (t + 1) / (s^2 (a - 2d)) only
+        // depends on r, which lets us solve for it.
+        let A = Decaf377EdwardsConfig::COEFF_A;
+        let D = Decaf377EdwardsConfig::COEFF_D;
+        let one = Fq::ONE;
+
+        if self.is_identity() {
+            // The identity is the image of r = -1, and of the two values of r
+            // where den vanishes.
+            let d_minus_a = D - A;
+            return [
+                Some(-one),
+                d_minus_a.inverse().map(|inv| D * inv),
+                D.inverse().map(|inv| d_minus_a * inv),
+                None,
+            ];
+        }
+
+        let mut candidates = [None; 4];
+        let z_inv = self.inner.z.inverse().expect("z is nonzero");
+        let (x, y) = (self.inner.x * z_inv, self.inner.y * z_inv);
+        let c = A - (one + one) * D;
+
+        // The element is represented by both (x, y) and (-x, -y) on the curve.
+        for (i, (x, y)) in [(x, y), (-x, -y)].into_iter().enumerate() {
+            // Each of these has two preimages on the Jacobi quartic, the roots
+            // of x = 2s / (1 + a s^2), and then t = (1 - a s^2) / y.
+            let (is_square, root) = Fq::sqrt_ratio_zeta(&(one - A * x.square()), &one);
+            if !is_square {
+                continue;
+            }
+            for (j, root) in [root, -root].into_iter().enumerate() {
+                candidates[2 * i + j] = (A * x).inverse().and_then(|inv| {
+                    let s = (one + root) * inv;
+                    let t = (one - A * s.square()) * y.inverse()?;
+                    let k = (t + one) * (s.square() * c).inverse()?;
+                    if s.is_nonnegative() {
+                        Some((one - k) * (one + k).inverse()?)
+                    } else {
+                        Some((one + k) * (one - k).inverse()?)
+                    }
+                });
+            }
+        }
+        candidates
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use proptest::prelude::*;
+
     use crate::ark_curve::edwards::EdwardsAffine;
 
     use super::*;
@@ -202,8 +345,43 @@ mod tests {
             };
 
             let actual = Element::elligator_map(&input_element);
-
             assert_eq!(actual, expected);
+
+            let actual_ct = Element::elligator_map_ct(&input_element);
+            assert_eq!(actual_ct, expected);
+
+            assert!(expected.elligator_inverse().contains(&Some(input_element)));
+        }
+    }
+
+    #[test]
+    fn test_elligator_inverse_identity() {
+        for r_0 in Element::IDENTITY.elligator_inverse().into_iter().flatten() {
+            assert_eq!(Element::encode_to_curve(&r_0), Element::IDENTITY);
+        }
+    }
+
+    proptest! {
+        #[test]
+        fn elligator_inverse_round_trip(bytes: [u8; 32]) {
+            let r_0 = Fq::from_le_bytes_mod_order(&bytes);
+            let point = Element::encode_to_curve(&r_0);
+            let preimages = point.elligator_inverse();
+
+            assert!(preimages.contains(&Some(r_0)));
+            assert!(preimages.contains(&Some(-r_0)));
+            for r in preimages.into_iter().flatten() {
+                assert_eq!(Element::encode_to_curve(&r), point);
+            }
+        }
+
+        #[test]
+        fn elligator_inverse_of_random_element(bytes: [u8; 32], scalar_bytes: [u8; 32]) {
+            let point = crate::Fr::from_le_bytes_mod_order(&scalar_bytes)
+                * Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&bytes));
+            for r in point.elligator_inverse().into_iter().flatten() {
+                assert_eq!(Element::encode_to_curve(&r), point);
+            }
         }
     }
 }
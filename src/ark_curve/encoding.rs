@@ -4,6 +4,9 @@ use core::convert::{TryFrom, TryInto};
 
 use ark_ec::twisted_edwards::TECurveConfig;
 use ark_serialize::{CanonicalDeserialize, CanonicalSerialize, Read, Write};
+use ark_std::vec::Vec;
+use subtle::{ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
 
 use crate::ark_curve::{
     constants::TWO, edwards::Decaf377EdwardsConfig, on_curve::OnCurve, EdwardsProjective, Element,
@@ -14,6 +17,12 @@ use crate::{EncodingError, Fq};
 #[derive(Copy, Clone, Default, Eq, Ord, PartialOrd, PartialEq)]
 pub struct Encoding(pub [u8; 32]);
 
+impl Zeroize for Encoding {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
 impl core::fmt::Debug for Encoding {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         f.write_fmt(format_args!(
@@ -24,7 +33,7 @@ impl core::fmt::Debug for Encoding {
 }
 
 impl Encoding {
-    #[deprecated(note = "please use `vartime_decompress` instead")]
+    #[deprecated(note = "please use `vartime_decompress` or `decompress_ct` instead")]
     pub fn decompress(&self) -> Result<Element, EncodingError> {
         self.vartime_decompress()
     }
@@ -81,6 +90,84 @@ impl Encoding {
             inner: EdwardsProjective::new(x, y, t, z),
         })
     }
+
+    /// Decompress a batch of encodings, equivalent to calling
+    /// `vartime_decompress` on each of them, but reporting the index of the
+    /// first invalid encoding.
+    ///
+    /// No work is shared between encodings. Decompression already produces
+    /// affine points, so there is no inversion to batch, and the inverse square
+    /// root of step 5 cannot be batched either: it is also the check that
+    /// `u_2` is square, and a product of values can be square when the values
+    /// are not, so each encoding needs its own quadratic residuosity test.
+    pub fn batch_vartime_decompress(
+        encodings: &[Encoding],
+    ) -> Result<Vec<Element>, (usize, EncodingError)> {
+        encodings
+            .iter()
+            .enumerate()
+            .map(|(i, encoding)| encoding.vartime_decompress().map_err(|e| (i, e)))
+            .collect()
+    }
+
+    /// Constant-time equivalent of `vartime_decompress`.
+    ///
+    /// Every validation step is evaluated regardless of whether an earlier one
+    /// failed, and the result is only available through the returned `CtOption`.
+    pub fn decompress_ct(&self) -> CtOption<Element> {
+        // This isn't a constant, only because traits don't have const methods
+        // yet and multiplication is only implemented as part of the Mul trait.
+        let D4: Fq = Decaf377EdwardsConfig::COEFF_D * Fq::from(4u32);
+
+        // Top three bits of last byte should be zero
+        let top_bits_clear = (self.0[31] >> 5).ct_eq(&0u8);
+
+        // 1/2. Reject unless s is canonically encoded and nonnegative.
+        let s = Fq::from_raw_bytes(&self.0);
+        let is_canonical = s.to_bytes_le().ct_eq(&self.0);
+        let is_nonnegative = s.ct_is_nonnegative();
+
+        // 3. u_1 <- 1 - s^2
+        let ss = s.square();
+        let u_1 = Fq::ONE - ss;
+
+        // 4. u_2 <- u_1^2 - 4d s^2
+        let u_2 = u_1.square() - D4 * ss;
+
+        // 5. sqrt. The ratio is square exactly when its denominator is a
+        // nonzero square, in which case we can take the root of the inverse.
+        let den = u_2 * u_1.square();
+        let was_square = den
+            .pow_le_limbs(&Fq::MODULUS_MINUS_ONE_DIV_TWO_LIMBS)
+            .ct_eq(&Fq::ONE);
+        let mut v = den.ct_inverse().ct_sqrt();
+
+        // 6. sign check
+        let two_s_u_1 = *TWO * s * u_1;
+        let check = two_s_u_1 * v;
+        v = Fq::conditional_select(&v, &-v, check.ct_is_negative());
+
+        // 7. coordinates
+        let x = two_s_u_1 * v.square() * u_2;
+        let y = (Fq::ONE + ss) * v * u_1;
+        let z = Fq::ONE;
+        let t = x * y;
+
+        let is_valid = top_bits_clear & is_canonical & is_nonnegative & was_square;
+
+        debug_assert!(
+            !bool::from(is_valid) || EdwardsProjective::new_unchecked(x, y, t, z).is_on_curve(),
+            "resulting point must be on the curve",
+        );
+
+        let element = Element {
+            inner: EdwardsProjective::new_unchecked(x, y, t, z),
+        };
+        CtOption::new(
+            Element::conditional_select(&Element::IDENTITY, &element, is_valid),
+            is_valid,
+        )
+    }
 }
 
 impl Element {
@@ -126,6 +213,130 @@ impl Element {
 
         Encoding(bytes)
     }
+
+    /// Compress a batch of elements, equivalent to calling `vartime_compress`
+    /// on each element.
+    ///
+    /// The `Z` coordinates are inverted together with a single field
+    /// inversion. Each point still needs its own inverse square root, which
+    /// cannot be shared with other points.
+    pub fn batch_compress(elements: &[Element]) -> Vec<Encoding> {
+        let mut z_invs: Vec<Fq> = elements.iter().map(|p| p.inner.z).collect();
+        Fq::batch_inverse(&mut z_invs);
+
+        elements
+            .iter()
+            .zip(z_invs)
+            .map(|(p, z_inv)| {
+                let x = p.inner.x * z_inv;
+                let y = p.inner.y * z_inv;
+                Element {
+                    inner: EdwardsProjective::new_unchecked(x, y, x * y, Fq::ONE),
+                }
+                .vartime_compress()
+            })
+            .collect()
+    }
+
+    /// Compress the doubles of a batch of elements, equivalent to calling
+    /// `vartime_compress` on `P + P` for each element `P`.
+    ///
+    /// For a doubled point `2Q`, the ratio whose square root the encoding
+    /// needs is a square of a rational function of the coordinates of `Q`,
+    /// so unlike [`Element::batch_compress`] the whole batch costs a single
+    /// field inversion. To compress a batch of points `x_i * B` this way,
+    /// pass in the points `(x_i / 2) * B`.
+    pub fn batch_vartime_double_and_compress(elements: &[Element]) -> Vec<Encoding> {
+        // This isn't a constant, only because traits don't have const methods
+        // yet and subtraction is only implemented as part of the Sub trait.
+        let A = Decaf377EdwardsConfig::COEFF_A;
+        let A_MINUS_D = A - Decaf377EdwardsConfig::COEFF_D;
+        let A_MINUS_D_INV = A_MINUS_D.inverse().expect("a - d is nonzero");
+
+        // The doubling 2Q = (e/g, h/f) in affine coordinates, with the
+        // denominators f and g nonzero since d is nonsquare.
+        let efgh: Vec<[Fq; 4]> = elements
+            .iter()
+            .map(|q| {
+                let q = &q.inner;
+                let xx = q.x.square();
+                let yy = q.y.square();
+                let g = A * xx + yy;
+                [*TWO * q.x * q.y, *TWO * q.z.square() - g, g, yy - A * xx]
+            })
+            .collect();
+        let mut invs: Vec<Fq> = efgh.iter().map(|[e, f, g, _]| *e * f * g).collect();
+        Fq::batch_inverse(&mut invs);
+
+        efgh.iter()
+            .zip(invs)
+            .map(|([e, f, g, h], inv)| {
+                // Since (a - d)(1 - y^2) = ((a - d) e / f)^2, the inverse square
+                // root of step 2 is v = f g^2 / ((a - d) e^3), up to a sign that
+                // the absolute values below remove. If e = 0 then Q is 4-torsion
+                // and inv = 0, which gives the identity encoding.
+                let e_inv = inv * f * g;
+                let x = *e * e * f * inv;
+                let y = *h * e * g * inv;
+                let t = x * y;
+                let v = *f * g.square() * e_inv.square() * e_inv * A_MINUS_D_INV;
+
+                // 1.
+                let u_1 = (x + t) * (x - t);
+
+                // 3.
+                let u_2 = (v * u_1).abs();
+
+                // 4.
+                let u_3 = u_2 - t;
+
+                // 5.
+                let s = (A_MINUS_D * v * u_3 * x).abs();
+
+                Encoding(s.to_bytes_le())
+            })
+            .collect()
+    }
+
+    /// Constant-time equivalent of `vartime_compress_to_field`.
+    pub fn compress_to_field(&self) -> Fq {
+        // This isn't a constant, only because traits don't have const methods
+        // yet and subtraction is only implemented as part of the Sub trait.
+        let A_MINUS_D = Decaf377EdwardsConfig::COEFF_A - Decaf377EdwardsConfig::COEFF_D;
+        let p = &self.inner;
+
+        // 1.
+        let u_1 = (p.x + p.t) * (p.x - p.t);
+
+        // 2. the ratio is always square, so we can take the square root of the
+        // inverse directly. On the identity point the inverse is zero, which
+        // gives v=0 as in the variable-time path.
+        let v = (u_1 * A_MINUS_D * p.x.square()).ct_inverse().ct_sqrt();
+
+        // 3.
+        let u_2 = (v * u_1).ct_abs();
+
+        // 4.
+        let u_3 = u_2 * p.z - p.t;
+
+        // 5.
+        (A_MINUS_D * v * u_3 * p.x).ct_abs()
+    }
+
+    /// Constant-time equivalent of `vartime_compress`.
+    pub fn compress(&self) -> Encoding {
+        let s = self.compress_to_field();
+
+        // Encode.
+        let mut bytes = [0u8; 32];
+        debug_assert_eq!(s.serialized_size(ark_serialize::Compress::Yes), 32);
+        s.serialize_compressed(&mut bytes[..])
+            .expect("serialization into array should be infallible");
+        // Set top three bits of last byte to zero
+        bytes[31] &= 0b00011111;
+
+        Encoding(bytes)
+    }
 }
 
 impl From<&Element> for Encoding {
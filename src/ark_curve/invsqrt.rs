@@ -201,6 +201,18 @@ mod tests {
         }
     }
 
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+        #[test]
+        fn sqrt_ratio_zeta_ct_matches_sqrt_ratio_zeta(u in fq_strategy(), v in fq_strategy()) {
+            let (was_square, sqrt_zeta_uv) = Fq::sqrt_ratio_zeta(&u, &v);
+            let (was_square_ct, sqrt_zeta_uv_ct) = Fq::sqrt_ratio_zeta_ct(&u, &v);
+            assert_eq!(was_square, bool::from(was_square_ct));
+            // The two implementations may pick square roots of opposite sign.
+            assert!(sqrt_zeta_uv_ct == sqrt_zeta_uv || sqrt_zeta_uv_ct == -sqrt_zeta_uv);
+        }
+    }
+
     #[test]
     fn sqrt_ratio_edge_cases() {
         // u = 0
@@ -208,5 +220,13 @@ mod tests {
 
         // v = 0
         assert_eq!(Fq::sqrt_ratio_zeta(&ONE, &Fq::zero()), (false, Fq::zero()));
+
+        // u = 0, constant-time
+        let (was_square, root) = Fq::sqrt_ratio_zeta_ct(&Fq::zero(), &ONE);
+        assert_eq!((bool::from(was_square), root), (true, Fq::zero()));
+
+        // v = 0, constant-time
+        let (was_square, root) = Fq::sqrt_ratio_zeta_ct(&ONE, &Fq::zero());
+        assert_eq!((bool::from(was_square), root), (false, Fq::zero()));
     }
 }
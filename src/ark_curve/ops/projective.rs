@@ -104,13 +104,11 @@ impl Neg for Element {
 }
 
 impl<'b> MulAssign<&'b Fr> for Element {
-    // Scalar multiplication is performed through the implementation
-    // of `MulAssign` on `ProjectiveDecaf377` which is a type alias for
-    // `Group<EdwardsConfig>`.
+    // Scalar multiplication is performed in constant time using a signed
+    // radix-16 fixed window, rather than through the generic double-and-add
+    // of `ProjectiveDecaf377`.
     fn mul_assign(&mut self, point: &'b Fr) {
-        let mut p = self.inner;
-        p *= *point;
-        *self = Element { inner: p }
+        *self = self.windowed_mul(point)
     }
 }
 
@@ -124,9 +122,7 @@ impl<'a, 'b> Mul<&'b Fr> for &'a Element {
     type Output = Element;
 
     fn mul(self, point: &'b Fr) -> Element {
-        let mut p = self.inner;
-        p *= *point;
-        Element { inner: p }
+        self.windowed_mul(point)
     }
 }
 
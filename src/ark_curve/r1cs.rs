@@ -3,6 +3,9 @@ pub mod fqvar_ext;
 mod inner;
 mod lazy;
 pub mod ops;
+pub mod pedersen;
+pub mod schnorr;
+mod sha512;
 
 use ark_ff::ToConstraintField;
 use ark_std::vec::Vec;
@@ -48,6 +48,51 @@ impl ElementVar {
     pub fn encode_to_curve(r_var: &FqVar) -> Result<ElementVar, SynthesisError> {
         Self::elligator_map(r_var)
     }
+
+    /// Computes `bits * base` for a constant `base` and a little-endian scalar.
+    ///
+    /// This uses 3-bit windows of precomputed multiples of `base`, and is much
+    /// cheaper than `scalar_mul_le` on a constant `ElementVar`.
+    pub fn fixed_base_scalar_mul(
+        base: Element,
+        bits: &[Boolean<Fq>],
+    ) -> Result<ElementVar, SynthesisError> {
+        let inner = InnerElementVar::fixed_base_scalar_mul(base, bits)?;
+        Ok(Self {
+            inner: LazyElementVar::new_from_element(inner),
+        })
+    }
+
+    /// Computes `sum_i scalars[i] * bases[i]` for little-endian scalars.
+    ///
+    /// Doublings are shared among the variable bases, and constant bases use
+    /// the lookup tables of `fixed_base_scalar_mul`, so this is cheaper than
+    /// summing separate `scalar_mul_le` calls.
+    ///
+    /// Returns `SynthesisError::Unsatisfiable` if the number of scalars and
+    /// bases differ.
+    pub fn multiscalar_mul<B: AsRef<[Boolean<Fq>]>>(
+        scalars: &[B],
+        bases: &[ElementVar],
+    ) -> Result<ElementVar, SynthesisError> {
+        let bases = bases
+            .iter()
+            .map(|base| base.inner.element())
+            .collect::<Result<Vec<_>, _>>()?;
+        let inner = InnerElementVar::multiscalar_mul(scalars, &bases)?;
+        Ok(Self {
+            inner: LazyElementVar::new_from_element(inner),
+        })
+    }
+
+    /// R1CS equivalent of `Element::hash_to_curve`
+    pub fn hash_to_curve(r_1_var: &FqVar, r_2_var: &FqVar) -> Result<ElementVar, SynthesisError> {
+        let R_1 = InnerElementVar::elligator_map(r_1_var)?;
+        let R_2 = InnerElementVar::elligator_map(r_2_var)?;
+        Ok(Self {
+            inner: LazyElementVar::new_from_element(R_1 + R_2),
+        })
+    }
 }
 
 impl EqGadget<Fq> for ElementVar {
@@ -240,6 +285,16 @@ impl CurveVar<Element, Fq> for ElementVar {
         Ok(())
     }
 
+    fn scalar_mul_le<'a>(
+        &self,
+        bits: impl Iterator<Item = &'a Boolean<Fq>>,
+    ) -> Result<Self, SynthesisError> {
+        let product = self.inner.element()?.scalar_mul_le(bits)?;
+        Ok(Self {
+            inner: LazyElementVar::new_from_element(product),
+        })
+    }
+
     fn double_in_place(&mut self) -> Result<(), SynthesisError> {
         let mut inner_element = self.inner.element().expect("element will exist");
         inner_element.double_in_place()?;
@@ -0,0 +1,49 @@
+//! R1CS equivalents of [`crate::pedersen`] commitments.
+//!
+//! Scalars are given as little-endian bits. The generators are circuit
+//! constants, so the commitment uses the fixed-base lookup tables of
+//! [`ElementVar::multiscalar_mul`].
+
+use ark_r1cs_std::prelude::{Boolean, CurveVar};
+use ark_relations::r1cs::SynthesisError;
+use ark_std::vec::Vec;
+
+use crate::{ark_curve::r1cs::ElementVar, pedersen::Generators, Fq};
+
+/// R1CS equivalent of `Generators::commit`
+pub fn commit(
+    generators: &Generators,
+    value: &[Boolean<Fq>],
+    blinding: &[Boolean<Fq>],
+) -> Result<ElementVar, SynthesisError> {
+    commit_vector(generators, &[value], blinding)
+}
+
+/// R1CS equivalent of `Generators::commit_vector`
+///
+/// # Panics
+///
+/// Panics if there are more values than value generators.
+pub fn commit_vector<B: AsRef<[Boolean<Fq>]>>(
+    generators: &Generators,
+    values: &[B],
+    blinding: &[Boolean<Fq>],
+) -> Result<ElementVar, SynthesisError> {
+    let value_generators = generators.value_generators();
+    assert!(
+        values.len() <= value_generators.len(),
+        "cannot commit to {} values with {} generators",
+        values.len(),
+        value_generators.len()
+    );
+
+    let mut scalars: Vec<&[Boolean<Fq>]> = values.iter().map(AsRef::as_ref).collect();
+    scalars.push(blinding);
+    let mut bases: Vec<ElementVar> = value_generators[..values.len()]
+        .iter()
+        .map(|generator| ElementVar::constant(*generator))
+        .collect();
+    bases.push(ElementVar::constant(generators.blinding_generator()));
+
+    ElementVar::multiscalar_mul(&scalars, &bases)
+}
@@ -0,0 +1,96 @@
+//! R1CS verification of [`crate::schnorr`] signatures.
+//!
+//! The message is a sequence of field elements, and corresponds to the native
+//! message formed by concatenating their 32-byte encodings. The challenge is
+//! recomputed with SHA-512 inside the circuit, which accounts for most of the
+//! constraints.
+
+#![allow(non_snake_case)]
+
+use core::borrow::Borrow;
+
+use ark_r1cs_std::{
+    prelude::{AllocVar, AllocationMode, Boolean, CurveVar, EqGadget, ToBitsGadget},
+    uint8::UInt8,
+    ToBytesGadget,
+};
+use ark_relations::r1cs::{Namespace, SynthesisError};
+use ark_std::vec::Vec;
+
+use super::sha512::sha512;
+use crate::{
+    ark_curve::r1cs::{ElementVar, FqVar},
+    schnorr::{Signature, CHALLENGE_DOMAIN_SEPARATOR},
+    Element, Fq, Fr,
+};
+
+/// R1CS equivalent of a `Signature`.
+#[derive(Clone, Debug)]
+pub struct SignatureVar {
+    /// The encoding of the nonce commitment `R`, as a field element.
+    R: FqVar,
+    /// The little-endian bits of the scalar `s`.
+    s: Vec<Boolean<Fq>>,
+}
+
+impl AllocVar<Signature, Fq> for SignatureVar {
+    fn new_variable<T: Borrow<Signature>>(
+        cs: impl Into<Namespace<Fq>>,
+        f: impl FnOnce() -> Result<T, SynthesisError>,
+        mode: AllocationMode,
+    ) -> Result<Self, SynthesisError> {
+        let ns = cs.into();
+        let cs = ns.cs();
+        let bytes = f().map(|signature| signature.borrow().to_bytes());
+
+        let R = FqVar::new_variable(
+            cs.clone(),
+            || {
+                let mut R_bytes = [0u8; 32];
+                R_bytes.copy_from_slice(&bytes?[..32]);
+                Fq::from_bytes_checked(&R_bytes).map_err(|_| SynthesisError::AssignmentMissing)
+            },
+            mode,
+        )?;
+
+        let mut s = Vec::with_capacity(256);
+        for i in 0..32 {
+            let byte = UInt8::new_variable(cs.clone(), || Ok(bytes?[32 + i]), mode)?;
+            s.extend(byte.to_bits_le()?);
+        }
+        // Mirror `Signature::from_bytes`, which rejects non-canonical scalars.
+        Boolean::enforce_smaller_or_equal_than_le(&s, (-Fr::ONE).to_le_limbs())?;
+
+        Ok(Self { R, s })
+    }
+}
+
+/// Enforce that `signature` is a valid signature on `message` under `vk`.
+///
+/// As with `VerificationKey::from_bytes`, the identity is not accepted as a
+/// verification key.
+pub fn verify(
+    vk: &ElementVar,
+    message: &[FqVar],
+    signature: &SignatureVar,
+) -> Result<(), SynthesisError> {
+    vk.enforce_not_equal(&ElementVar::zero())?;
+
+    // This enforces that `R` is a valid encoding.
+    let R = ElementVar::decompress_from_field(signature.R.clone())?;
+
+    let mut input = UInt8::constant_vec(CHALLENGE_DOMAIN_SEPARATOR);
+    input.extend(signature.R.to_bytes()?);
+    input.extend(vk.compress_to_field()?.to_bytes()?);
+    for element in message {
+        input.extend(element.to_bytes()?);
+    }
+    let mut c = Vec::with_capacity(512);
+    for byte in sha512(&input)? {
+        c.extend(byte.to_bits_le()?);
+    }
+
+    let s_B = ElementVar::fixed_base_scalar_mul(Element::GENERATOR, &signature.s)?;
+    let c_A = vk.scalar_mul_le(c.iter())?;
+    s_B.enforce_equal(&(R + c_A))
+}
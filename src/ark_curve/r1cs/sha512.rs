@@ -0,0 +1,225 @@
+//! SHA-512 over bytes in R1CS, used to recompute Schnorr challenges.
+
+#![allow(non_snake_case)]
+
+use ark_r1cs_std::{
+    prelude::{Boolean, ToBitsGadget},
+    uint64::UInt64,
+    uint8::UInt8,
+};
+use ark_relations::r1cs::SynthesisError;
+use ark_std::vec::Vec;
+
+use crate::Fq;
+
+const H0: [u64; 8] = [
+    0x6a09e667f3bcc908,
+    0xbb67ae8584caa73b,
+    0x3c6ef372fe94f82b,
+    0xa54ff53a5f1d36f1,
+    0x510e527fade682d1,
+    0x9b05688c2b3e6c1f,
+    0x1f83d9abfb41bd6b,
+    0x5be0cd19137e2179,
+];
+
+#[rustfmt::skip]
+const K: [u64; 80] = [
+    0x428a2f98d728ae22, 0x7137449123ef65cd,
+    0xb5c0fbcfec4d3b2f, 0xe9b5dba58189dbbc,
+    0x3956c25bf348b538, 0x59f111f1b605d019,
+    0x923f82a4af194f9b, 0xab1c5ed5da6d8118,
+    0xd807aa98a3030242, 0x12835b0145706fbe,
+    0x243185be4ee4b28c, 0x550c7dc3d5ffb4e2,
+    0x72be5d74f27b896f, 0x80deb1fe3b1696b1,
+    0x9bdc06a725c71235, 0xc19bf174cf692694,
+    0xe49b69c19ef14ad2, 0xefbe4786384f25e3,
+    0x0fc19dc68b8cd5b5, 0x240ca1cc77ac9c65,
+    0x2de92c6f592b0275, 0x4a7484aa6ea6e483,
+    0x5cb0a9dcbd41fbd4, 0x76f988da831153b5,
+    0x983e5152ee66dfab, 0xa831c66d2db43210,
+    0xb00327c898fb213f, 0xbf597fc7beef0ee4,
+    0xc6e00bf33da88fc2, 0xd5a79147930aa725,
+    0x06ca6351e003826f, 0x142929670a0e6e70,
+    0x27b70a8546d22ffc, 0x2e1b21385c26c926,
+    0x4d2c6dfc5ac42aed, 0x53380d139d95b3df,
+    0x650a73548baf63de, 0x766a0abb3c77b2a8,
+    0x81c2c92e47edaee6, 0x92722c851482353b,
+    0xa2bfe8a14cf10364, 0xa81a664bbc423001,
+    0xc24b8b70d0f89791, 0xc76c51a30654be30,
+    0xd192e819d6ef5218, 0xd69906245565a910,
+    0xf40e35855771202a, 0x106aa07032bbd1b8,
+    0x19a4c116b8d2d0c8, 0x1e376c085141ab53,
+    0x2748774cdf8eeb99, 0x34b0bcb5e19b48a8,
+    0x391c0cb3c5c95a63, 0x4ed8aa4ae3418acb,
+    0x5b9cca4f7763e373, 0x682e6ff3d6b2b8a3,
+    0x748f82ee5defb2fc, 0x78a5636f43172f60,
+    0x84c87814a1f0ab72, 0x8cc702081a6439ec,
+    0x90befffa23631e28, 0xa4506cebde82bde9,
+    0xbef9a3f7b2c67915, 0xc67178f2e372532b,
+    0xca273eceea26619c, 0xd186b8c721c0c207,
+    0xeada7dd6cde0eb1e, 0xf57d4f7fee6ed178,
+    0x06f067aa72176fba, 0x0a637dc5a2c898a6,
+    0x113f9804bef90dae, 0x1b710b35131c471b,
+    0x28db77f523047d84, 0x32caab7b40c72493,
+    0x3c9ebe0a15c9bebc, 0x431d67c49c100d4c,
+    0x4cc5d4becb3e42b6, 0x597f299cfc657e2a,
+    0x5fcb6fab3ad6faec, 0x6c44198c4a475817,
+];
+
+/// R1CS equivalent of SHA-512 on `input`, returning the 64-byte digest.
+///
+/// The length of `input` is fixed when the circuit is synthesized, so the
+/// padding consists of constants.
+pub(crate) fn sha512(input: &[UInt8<Fq>]) -> Result<Vec<UInt8<Fq>>, SynthesisError> {
+    let mut padded = input.to_vec();
+    padded.push(UInt8::constant(0x80));
+    while padded.len() % 128 != 112 {
+        padded.push(UInt8::constant(0));
+    }
+    let bit_len = input.len() as u128 * 8;
+    padded.extend(bit_len.to_be_bytes().iter().map(|b| UInt8::constant(*b)));
+
+    let mut state: Vec<UInt64<Fq>> = H0.iter().map(|h| UInt64::constant(*h)).collect();
+    for block in padded.chunks(128) {
+        state = compress(&state, block)?;
+    }
+
+    let mut digest = Vec::with_capacity(64);
+    for word in &state {
+        // Words are big-endian, while their bits are little-endian.
+        let bits = word.to_bits_le();
+        digest.extend(bits.chunks(8).rev().map(UInt8::from_bits_le));
+    }
+    Ok(digest)
+}
+
+fn compress(state: &[UInt64<Fq>], block: &[UInt8<Fq>]) -> Result<Vec<UInt64<Fq>>, SynthesisError> {
+    let mut w = Vec::with_capacity(80);
+    for bytes in block.chunks(8) {
+        let mut bits = Vec::with_capacity(64);
+        for byte in bytes.iter().rev() {
+            bits.extend(byte.to_bits_le()?);
+        }
+        w.push(UInt64::from_bits_le(&bits));
+    }
+    for t in 16..80 {
+        let s_0 = xor3(&w[t - 15].rotr(1), &w[t - 15].rotr(8), &shr(&w[t - 15], 7))?;
+        let s_1 = xor3(&w[t - 2].rotr(19), &w[t - 2].rotr(61), &shr(&w[t - 2], 6))?;
+        let w_t = add(&[w[t - 16].clone(), s_0, w[t - 7].clone(), s_1])?;
+        w.push(w_t);
+    }
+
+    let mut v = state.to_vec();
+    for t in 0..80 {
+        let (a, b, c, d) = (&v[0], &v[1], &v[2], &v[3]);
+        let (e, f, g, h) = (&v[4], &v[5], &v[6], &v[7]);
+
+        let S_1 = xor3(&e.rotr(14), &e.rotr(18), &e.rotr(41))?;
+        let t_1 = add(&[
+            h.clone(),
+            S_1,
+            ch(e, f, g)?,
+            UInt64::constant(K[t]),
+            w[t].clone(),
+        ])?;
+        let S_0 = xor3(&a.rotr(28), &a.rotr(34), &a.rotr(39))?;
+        let t_2 = add(&[S_0, maj(a, b, c)?])?;
+
+        v = [
+            add(&[t_1.clone(), t_2])?,
+            a.clone(),
+            b.clone(),
+            c.clone(),
+            add(&[d.clone(), t_1])?,
+            e.clone(),
+            f.clone(),
+            g.clone(),
+        ]
+        .to_vec();
+    }
+
+    state
+        .iter()
+        .zip(v)
+        .map(|(s, v)| add(&[s.clone(), v]))
+        .collect()
+}
+
+/// Modular addition of any number of words.
+///
+/// `UInt64::addmany` can only add three words at a time without overflowing
+/// the field, so longer sums are split up.
+fn add(operands: &[UInt64<Fq>]) -> Result<UInt64<Fq>, SynthesisError> {
+    let (first, rest) = operands.split_at(operands.len().min(3));
+    let mut sum = UInt64::addmany(first)?;
+    for chunk in rest.chunks(2) {
+        let mut terms = Vec::with_capacity(3);
+        terms.push(sum);
+        terms.extend_from_slice(chunk);
+        sum = UInt64::addmany(&terms)?;
+    }
+    Ok(sum)
+}
+
+fn xor3(a: &UInt64<Fq>, b: &UInt64<Fq>, c: &UInt64<Fq>) -> Result<UInt64<Fq>, SynthesisError> {
+    a.xor(b)?.xor(c)
+}
+
+fn shr(a: &UInt64<Fq>, by: usize) -> UInt64<Fq> {
+    let mut bits = a.to_bits_le();
+    bits.drain(..by);
+    bits.resize(64, Boolean::FALSE);
+    UInt64::from_bits_le(&bits)
+}
+
+/// `(e & f) ^ (!e & g)`, computed as `g ^ (e & (f ^ g))`.
+fn ch(e: &UInt64<Fq>, f: &UInt64<Fq>, g: &UInt64<Fq>) -> Result<UInt64<Fq>, SynthesisError> {
+    bitwise(e, f, g, |e, f, g| g.xor(&e.and(&f.xor(g)?)?))
+}
+
+/// `(a & b) ^ (a & c) ^ (b & c)`, computed as `(a & b) ^ (c & (a ^ b))`.
+fn maj(a: &UInt64<Fq>, b: &UInt64<Fq>, c: &UInt64<Fq>) -> Result<UInt64<Fq>, SynthesisError> {
+    bitwise(a, b, c, |a, b, c| a.and(b)?.xor(&c.and(&a.xor(b)?)?))
+}
+
+fn bitwise(
+    x: &UInt64<Fq>,
+    y: &UInt64<Fq>,
+    z: &UInt64<Fq>,
+    f: impl Fn(&Boolean<Fq>, &Boolean<Fq>, &Boolean<Fq>) -> Result<Boolean<Fq>, SynthesisError>,
+) -> Result<UInt64<Fq>, SynthesisError> {
+    let bits = x
+        .to_bits_le()
+        .iter()
+        .zip(y.to_bits_le().iter())
+        .zip(z.to_bits_le().iter())
+        .map(|((x, y), z)| f(x, y, z))
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(UInt64::from_bits_le(&bits))
+}
+
+#[cfg(test)]
+mod tests {
+    use ark_r1cs_std::R1CSVar;
+    use ark_relations::r1cs::ConstraintSystem;
+    use sha2::{Digest, Sha512};
+
+    use super::*;
+
+    #[test]
+    fn sha512_matches_native() {
+        // Lengths around the one- and two-block padding boundaries.
+        for len in [0, 3, 111, 112, 128, 200] {
+            let input: Vec<u8> = (0..len).map(|i| (i * 7 + 1) as u8).collect();
+
+            let cs = ConstraintSystem::<Fq>::new_ref();
+            let input_var = UInt8::new_witness_vec(cs.clone(), &input).unwrap();
+            let digest_var = sha512(&input_var).unwrap();
+
+            let digest: Vec<u8> = digest_var.iter().map(|b| b.value().unwrap()).collect();
+            assert_eq!(digest[..], Sha512::digest(&input)[..]);
+            assert!(cs.is_satisfied().unwrap());
+        }
+    }
+}
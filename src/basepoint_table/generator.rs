@@ -0,0 +1,1350 @@
+// This file is generated by `generate_generator_table` in
+// `tests/basepoint_table.rs`; do not edit it by hand.
+
+#[rustfmt::skip]
+pub(super) static GENERATOR_TABLE_LIMBS: [[[[u64; 4]; 3]; 8]; 32] = [
+    [
+        [
+            [5825153684096051627, 16988948339439369204, 186539475124256708, 1230075515893193738],
+            [9786171649960077610, 13527783345193426398, 10983305067350511165, 1251302644532346138],
+            [7466800842436274004, 14314110021432015475, 14108125795146788134, 1305086759679105397],
+        ],
+        [
+            [16438505847371416180, 6066103766807169912, 10900432926504210966, 1229311324330744411],
+            [2569361903425292577, 15950080011386018067, 9364521879620664072, 424704851838643168],
+            [13764717433233083565, 15281922971275660312, 6223224060562378297, 373375269720399642],
+        ],
+        [
+            [8555548921469279032, 7275300521244745559, 2760283741245965272, 1328140036300956992],
+            [8561102653595515902, 14592160701579918430, 10978379764201199720, 1189265948866493222],
+            [4022209287948247970, 15522304143429882564, 17760818559952410417, 1038398816485098649],
+        ],
+        [
+            [289089258763854301, 5914520097556933713, 14492130458982495295, 915054882856175649],
+            [8928176966099718339, 3556458200684460331, 6821096698124349684, 563632495501427834],
+            [7101301431087356926, 8695058967843445274, 11442682425348906260, 322386784354679259],
+        ],
+        [
+            [16469735386530159358, 18046393983778620411, 6718750012064945028, 1203447131227062026],
+            [1626392133524124006, 1568139033014553774, 2389780214595904145, 622090168419594344],
+            [14636860781370999017, 9538510288174917881, 15987624687231809816, 1173512478492499129],
+        ],
+        [
+            [12864966376973233230, 15397514694586083479, 2363916718658272813, 1024706859417979830],
+            [593097526591030656, 14487606585532175796, 11882966972408759957, 571199150017975558],
+            [7916897706522632687, 9073877641276169061, 1757173101042392147, 141933957058497478],
+        ],
+        [
+            [5589008521172508253, 10945958637812610094, 13429165174725509013, 520421108767421137],
+            [17694776909589640389, 14702289391918298159, 7585629196591125999, 1232357786450988092],
+            [9100973170624461572, 5971171630010815180, 9986479034912737924, 253288743159660023],
+        ],
+        [
+            [6782584394736739550, 18369150977932476550, 18112708652258357847, 1026917277458639918],
+            [7019256407818296220, 7040996920773623224, 113472608146487506, 989923661841342413],
+            [6283299152092209128, 2112249075013129027, 3023365994368676877, 643365907677077729],
+        ],
+    ],
+    [
+        [
+            [843798090247652213, 10648337829335988637, 17986820013311499952, 777359836808538544],
+            [5291264590546177117, 11779873113449048616, 12938030469337576031, 1174360663061012789],
+            [7335877757983469662, 5983795978763747285, 7449938009829826840, 1305772546240029492],
+        ],
+        [
+            [7570715015450645972, 12599593407461856309, 6220980257311583335, 144653225119791233],
+            [18292996903552087928, 15372395006145362184, 11296106145291355380, 126858825051390990],
+            [13263802839775408635, 4456191054124702743, 13853090906637778132, 558539668746701178],
+        ],
+        [
+            [12015947119266218698, 3450794437721950600, 10906983507324509948, 24520931828796332],
+            [4272978664206505346, 1235956846616311892, 14241151874695360384, 869165413146095430],
+            [13549294008211195538, 8238254047144791600, 53005564254398307, 521712685476863347],
+        ],
+        [
+            [16763612123013279236, 7915490945789360346, 9830200793635295571, 442060872840880673],
+            [17314426072890782928, 18096257518169402756, 12527918856866621766, 523346689524288197],
+            [6443526219845971236, 746773757155868541, 10753034516854358989, 457935656641441018],
+        ],
+        [
+            [17601509811528966901, 16551083732379243897, 2338035840647895812, 576134902060007880],
+            [9489995455849940448, 7604516710324763145, 941360115314440007, 999792220625183922],
+            [3856079142742126890, 533986646635722993, 13346322741457332435, 836850412439385914],
+        ],
+        [
+            [9686992281252675261, 4965072069301541469, 4077282670850884612, 1239670823213624683],
+            [12509865103356720871, 13960142458736803474, 7118485406590148897, 832127070963126598],
+            [17162406118044072252, 15433963491677620364, 3810448618929408680, 1135910443804060697],
+        ],
+        [
+            [10509911006134826998, 17863568528608153153, 6508069456008667758, 383675216380388035],
+            [16585552947567311905, 16668078100433167571, 9569354127778563731, 441100037733993768],
+            [5215026652645893867, 7627082624662515194, 13473080581794103958, 1037302214763449429],
+        ],
+        [
+            [14813260168623701308, 6559528784603435865, 10340558680150901667, 810450110580042669],
+            [569111386133163109, 824264411461712777, 15668464518474527536, 37699189143567050],
+            [13778134056433358991, 13933096372655532068, 16072311029595773723, 27292143935996086],
+        ],
+    ],
+    [
+        [
+            [11876003488137614071, 1943480323467273163, 5620106014393553884, 122978287582881449],
+            [11357537507073320099, 18272848872703287096, 10573418851551611138, 809086350222815312],
+            [9582049520217945728, 9181460869390416973, 11043753690965805800, 558029788448622426],
+        ],
+        [
+            [8995256826632421990, 4491703512955439464, 12877641251005783024, 902150807098092194],
+            [1332547629120828198, 11039661611265140806, 350331785847320949, 206804350849322025],
+            [15054628067321464789, 7009642184535759734, 9653664188825174161, 1296733783119877244],
+        ],
+        [
+            [664189267134361616, 2209482660277699852, 9533838736058092201, 614042306666437657],
+            [1356216942772991928, 10737319875835432650, 130970080322332829, 622653226090677326],
+            [13641263004906042179, 17335318023051454578, 17738376557633614548, 1329519185213757268],
+        ],
+        [
+            [16968860843677633320, 17754987060034378145, 14119893845309920592, 280771026022176041],
+            [7499825380417481729, 5847862106587763891, 6319870022618091486, 482441371326343046],
+            [6347583159918199397, 1766166409497678197, 6951051124712058227, 986186967068000049],
+        ],
+        [
+            [6068372199880764887, 7031398108234596345, 13671811064842808059, 318697370814084121],
+            [2441424016810087151, 4065827094557229778, 5079285215387103149, 626197337927080660],
+            [4972865300032014319, 15014396386555683369, 6585901395783784770, 212587451688420330],
+        ],
+        [
+            [12055485963886434505, 4584704092338398351, 9671524409246621845, 577096417225318507],
+            [18350324459759107363, 1709859924258882331, 15380758001962184303, 1073172775848877630],
+            [12370525227330700260, 330823246748957936, 12666148051999358693, 941798658842046832],
+        ],
+        [
+            [12804188202554429136, 13736512645753362839, 17900969754511684517, 555961803697826858],
+            [11626164478486469101, 465448157334744314, 14273465512335531597, 23723390398237892],
+            [1374960419966299133, 5404696826625384251, 1863234828614744191, 1285080922430565053],
+        ],
+        [
+            [1296602424505155915, 2559983433285299673, 1558773429371005248, 522576180080412854],
+            [13198378111437784956, 6556505010023965661, 241611423996134510, 866094921827846820],
+            [2730322514101150656, 6981917502760918344, 4182105974736017642, 1005990268796848413],
+        ],
+    ],
+    [
+        [
+            [5196407425084504227, 4561065805950449174, 926369121103994005, 410160194025262007],
+            [3170185498926716766, 6396813527883701337, 4401038189401948079, 1267528589696708396],
+            [1636339257043155641, 14868146561035753510, 1279579999294519023, 586296891373038419],
+        ],
+        [
+            [16203715423734977771, 14974229398265729109, 5378163280008435260, 792004404985989735],
+            [161762426604702084, 814381980492528361, 9842799734043063151, 3440148018437111],
+            [691511510634645041, 4663373178279183208, 14902060454794486419, 316717452607343375],
+        ],
+        [
+            [8011586001886836361, 3107772204617199020, 10644903918989714024, 438652793147014754],
+            [7452368462796657842, 13934482338727912448, 18219441401293884900, 835971789417701610],
+            [6057216003062168738, 1096500668519363807, 8219021595696871995, 392434434730531923],
+        ],
+        [
+            [544056828006586678, 10956497234039979700, 4165155569770141261, 460458178499269312],
+            [17035828371895818933, 17515914347744494363, 6122893331138609481, 884356739395197909],
+            [9183779792902721970, 6266727856755378378, 12971563965116452454, 1231205766826216125],
+        ],
+        [
+            [8769072895516867942, 11553793041122101155, 5714149493671571151, 271646222649891377],
+            [2489680541153678293, 11510842815162440229, 7710512325819823069, 432684910948755291],
+            [13331631449637073893, 9458709691773135199, 8031130716818000784, 831909679701080272],
+        ],
+        [
+            [1673827889350439194, 13882534788934085923, 16990135740660412299, 259407895293329532],
+            [2990639639218538733, 2914178371896124887, 5545844388025116230, 772522461897519113],
+            [10456940078156097902, 1929344479449893675, 7195158954455533131, 482154306979042801],
+        ],
+        [
+            [18430153144192661142, 8715404211941556326, 3595625578165343242, 1252753951546812273],
+            [11928466534873835631, 258855092843822975, 14018653331360647844, 725930943686596286],
+            [2507107169301837225, 16306363476703935224, 12124496569840732317, 42757077808306667],
+        ],
+        [
+            [953861330484835501, 14944582209878953506, 9050274157571388206, 270259538750922788],
+            [10721548277254976324, 13110855101561644829, 15816652306177509045, 1283758385166311423],
+            [10040863550373593183, 5452538258126486157, 15685704276935755164, 1223731744407639295],
+        ],
+    ],
+    [
+        [
+            [2612607580248805726, 13439839702653716192, 1480484511965812680, 1164780457139761900],
+            [5599844188998205556, 4033836820021967795, 13633834007196423686, 1095612066633998666],
+            [8478345411050737813, 9578508888205952392, 422616929236599894, 387771541075616889],
+        ],
+        [
+            [5376868324302327963, 11699819506162211029, 13442092847681210038, 1209121357332995026],
+            [2491026764942576451, 2022227895364962652, 16442988038251526320, 1315297868898746069],
+            [599536709514020800, 5896957097550403477, 3654541505243546952, 1032507231482012993],
+        ],
+        [
+            [6910764206022782450, 8238043591086878248, 15637540767485753034, 502661514938696892],
+            [7835878551424469827, 1483465693818774772, 11171561528338606385, 369030534538949152],
+            [267113891534695264, 4266872015923843116, 12931335950830140933, 655158255623716052],
+        ],
+        [
+            [15894702386795726394, 3926967066998098264, 13397343292066498186, 308542830032477496],
+            [1941460760480043334, 9943762221186182716, 15743306307355519438, 37614846732140842],
+            [18173395994501919977, 4673936796279897004, 17865208973987439682, 199831031576741640],
+        ],
+        [
+            [14489563092236927703, 14501866303112855112, 9018370864480265795, 931259074723850005],
+            [16205977488867582177, 10523854537668827930, 7255408186735602187, 817198325287824449],
+            [7382404014444633003, 8631283817801042835, 3395415497819737853, 1021431046957497121],
+        ],
+        [
+            [11475033390445026447, 12459395502585687137, 9172667997226988694, 49513165188398168],
+            [1402035664138235490, 11363363580424766282, 7162910430551002235, 284115659079249878],
+            [13256763318646985939, 455770762347106446, 10214452740508612384, 628329987120131227],
+        ],
+        [
+            [14040052196984723662, 1530926268925499178, 1164623755102469501, 192309722985026582],
+            [14491776654029773775, 10034326139514138532, 12366587592256995379, 222298637670733636],
+            [13754238984563598391, 2474731204739064609, 8254988822370554058, 965781963410061712],
+        ],
+        [
+            [1293992391962151967, 17298147066076347389, 13771019340723733635, 574217014800931312],
+            [6397129739144798821, 4079508350134832072, 16459264682370645648, 261169120113473079],
+            [10481573074316171219, 9077640404712139015, 4036031228720583398, 874018080719879129],
+        ],
+    ],
+    [
+        [
+            [16300600096416212590, 16156253936719956076, 17923990085283839331, 1101846666531473590],
+            [18006084803930364741, 15895682484972899072, 18384354119374391035, 484283053128241644],
+            [1543806992095196201, 6694630034027089884, 18121070916727566183, 1022332545664088374],
+        ],
+        [
+            [16104356800655732112, 15583890599645143068, 11139671869986735324, 1094738187953455700],
+            [1925078602525249711, 17607467774123283194, 7669013054481110090, 684313999358674872],
+            [16958583555870117100, 9674957683669775819, 3129195490832871381, 387332724514327743],
+        ],
+        [
+            [1389100658964538918, 13587409202009426913, 10205216658792305164, 525706035567912278],
+            [18252834678266246107, 4004384556991558855, 12125775118259009162, 574463029780528361],
+            [3395850788407344707, 8989115368352688417, 10277640509821586104, 796175824848176001],
+        ],
+        [
+            [1288869164136259191, 15638922790686981445, 18055181280233314522, 274537942326189720],
+            [12409771475999912418, 3027592208078703675, 7256559012956473515, 1303190463048167269],
+            [14500187856155083983, 5721166724723716578, 5536707227342341578, 255119499071623357],
+        ],
+        [
+            [18069026708868395981, 4756843927128604765, 13679823915757663273, 901859411028361711],
+            [11863165488391495317, 100802801671934239, 6985128288162497702, 444988406397037314],
+            [3869205783388161318, 5556987023759554083, 1584237757697301613, 92708587616385419],
+        ],
+        [
+            [17838812051069469023, 1017218301080930917, 6239101396843317965, 957179072429673799],
+            [8451739192777669724, 7743686711470960216, 17254427494235953327, 356575399170439887],
+            [14835044632793082045, 5690170555836639362, 14871075397505515410, 820184757893169481],
+        ],
+        [
+            [11008181884464452062, 7192151311515847340, 13547582472601416794, 9832118035002211],
+            [235785124911786975, 14174256170787597251, 13040221129102741635, 93366204679166026],
+            [1113243916140525323, 15345358803585324075, 3074742245995833026, 1080080337186681472],
+        ],
+        [
+            [730775661186587403, 15502191666374143070, 15954695504613607022, 28674307528523092],
+            [2248130541129893854, 12695179844107203619, 1368256861743791065, 147209341396478744],
+            [12823147855758261104, 8297670256669511642, 1498457974392952983, 68181985415735860],
+        ],
+    ],
+    [
+        [
+            [5139103426027503115, 11954149759558817176, 3035489804845716383, 562127655510332753],
+            [6227170150229345586, 16006990920525802542, 17237480077202157387, 958188336990967606],
+            [17769665883996567456, 17509089562599880209, 16111569266771553280, 1048429467214268971],
+        ],
+        [
+            [17199335100435798697, 9471073809610711337, 18323723507214395509, 356844625090928840],
+            [6114686716261188895, 1723035882570584635, 8283496692417296698, 268725949906574279],
+            [11166230997496896774, 18388604642207043985, 16216844231348582094, 35096655737395940],
+        ],
+        [
+            [9970075258059529417, 14454320973087375932, 8630147483134879986, 465798245301069401],
+            [9959169136344474968, 4622085251105883752, 6365510118203377483, 499011012944797222],
+            [3984576595151156192, 12147246733657339924, 4348126236364061160, 1191449899135824587],
+        ],
+        [
+            [5252710545508964745, 7927244929721052720, 10142082182208774198, 1152746364053802435],
+            [3118946787208259465, 15397430191269745862, 17467340664630934322, 874963686217487171],
+            [9464303854170029629, 9532485406901139605, 14864029798133932451, 249528482676701704],
+        ],
+        [
+            [11492078977375547119, 8484227413906343319, 1679068403777874214, 899318628936316502],
+            [9142314492980425748, 17205014044827005211, 14301521637644960488, 478271704230712778],
+            [16859135480447018752, 6702632543062007210, 585352240852504919, 241878971853005721],
+        ],
+        [
+            [17995584301806033875, 14688042584723194421, 18057381910207877878, 587566983455725203],
+            [6449808270704198066, 7035326313996308203, 5943997218079022134, 831274506000279912],
+            [15378417324650549671, 14308383974217799495, 16738998459562520667, 1148016183904533773],
+        ],
+        [
+            [5017344668980793224, 6033371789077984970, 3608884125166732297, 349152884290707433],
+            [9429230014583917964, 11126707698851707611, 1850573204551989417, 1231827285711066500],
+            [6047614054851449091, 11664270902350207589, 12204492253378037224, 1107062876055787503],
+        ],
+        [
+            [2218975654217863476, 8989445651091535663, 5387576733552789849, 940995314125176354],
+            [18033553524056189853, 11790630787154691236, 16304251155289355342, 1055179147992863935],
+            [3667774590513967912, 16391132066619567904, 2413519077150750305, 90344256319357781],
+        ],
+    ],
+    [
+        [
+            [18162131108132200377, 3038180972641130692, 16549332734177903157, 781407965755968156],
+            [17034070065731671047, 13503045090651486193, 11715444172469869243, 612677706836253938],
+            [7641476915649039195, 779090078078472651, 15984833562455099524, 791195305680259703],
+        ],
+        [
+            [14997254548129581077, 15811330778225519092, 3915260827010512626, 397710521225288131],
+            [17917964045780446332, 7186783614700949330, 13289854133474901531, 449320234087141911],
+            [1580115618100860576, 10199515394804826186, 3740110035122811022, 1276239942990672160],
+        ],
+        [
+            [12260147859958719079, 8344897767554430511, 16110873985322012946, 692075511562506838],
+            [10632132915857520139, 14054341711459847637, 10894382664224938920, 1238129529657808136],
+            [10663130737017004508, 13673893551050576588, 8485008044065357614, 251351597520797611],
+        ],
+        [
+            [8621917302675847714, 13345789727476594456, 17820782740330725830, 366714467028153172],
+            [2655041800719203376, 3253436978569373967, 14314790176608640785, 527157488502734260],
+            [9927427925883748166, 11236902106028460162, 13955518790487695844, 407653157005075408],
+        ],
+        [
+            [7700725173919302306, 18090586768610000607, 8027849403651223755, 331243423425557374],
+            [2132181816048567601, 11392812745237160181, 14884747543707709779, 232107632933387997],
+            [10476503084321524397, 9606921070367309435, 3703698429780284845, 960228561482337801],
+        ],
+        [
+            [8424428514886708631, 7900750150333143193, 15939901984868738699, 163225255276082052],
+            [12619647263969518611, 5748975557158678204, 7094811431378787348, 177027691568349127],
+            [17251335056941430486, 13924369826636585969, 10511025457539383180, 1009871351155407690],
+        ],
+        [
+            [273016674680161949, 1703940218127482377, 16267682252834038106, 537370664094904630],
+            [7361864720996167680, 7158211485460576177, 5892675605634712046, 627516056439079102],
+            [11515514900897518915, 1919551176467182421, 8050931978202945550, 626963550104941418],
+        ],
+        [
+            [1334287447058940497, 7148340877063518536, 694960206525576260, 1300949211051521755],
+            [14113257577922022009, 13372773325741926366, 8975927558983319209, 532743735503709801],
+            [5864654305192052735, 3900832057318705366, 4872666856826177605, 1304849132942348648],
+        ],
+    ],
+    [
+        [
+            [2070983797461169259, 17671810771643716074, 17682968376522807693, 584918652359160458],
+            [9198138751384719395, 13123887945975562407, 4288307863633810898, 545734923303399897],
+            [16694342726492507619, 10505797592058387716, 14898284581445142109, 205360558336503650],
+        ],
+        [
+            [18344667597714860655, 3231317262487802270, 7975350697138691746, 46011758761062858],
+            [7357703943103513460, 15860152912088695170, 13409478651880778803, 1297131025397076851],
+            [5448983747398685530, 13142614283156688519, 14313671184953860801, 998409170592950800],
+        ],
+        [
+            [12792672625437694488, 13302933864565438579, 2073971906481021513, 761231733239226544],
+            [10137873068793334122, 5667897682842635548, 9557055444391145305, 213684343071845264],
+            [13816280659880934115, 3589988568047666753, 573282750055458022, 756144207342213518],
+        ],
+        [
+            [12490870105991629381, 8231317161945738661, 12999235149838399413, 962832417272528094],
+            [456367313393571661, 9418513927805125755, 4238015546969896771, 1215972627636316156],
+            [1523078176160327558, 8159832306574567783, 16570272945524084462, 864530863338024995],
+        ],
+        [
+            [582900565392278487, 13868653033653683118, 10718968617652382205, 116536893701528597],
+            [12504280689130711094, 10578430979814309068, 13770686500567616442, 435981830751064246],
+            [7376407807790120019, 5224011861272240575, 4459102204739299017, 466321920515376760],
+        ],
+        [
+            [8723390605387284500, 4249003744644387260, 15494350207623419884, 643941333593540389],
+            [9445600428567914919, 13798351116881887201, 12824304424746651991, 1190015035483387668],
+            [17192660796304045213, 2855328358426360787, 1021252571478519328, 1174613629264080106],
+        ],
+        [
+            [13391948580243278886, 1419757602808645136, 15041267728753004522, 609466233348492119],
+            [2476417397981194638, 10461351139155129750, 17165516527581842730, 61823350520065627],
+            [6470303079439594957, 7144092564015518914, 6265091444294378396, 1250314615034726733],
+        ],
+        [
+            [5351615795281479266, 6950890471635678905, 15505972216678320650, 1076855073208420265],
+            [3912328580310688527, 3950303393829334587, 10905202629094716961, 431440057770988240],
+            [14521445843562677607, 16963786337380228716, 4907523873004957860, 719265898208031548],
+        ],
+    ],
+    [
+        [
+            [3812721145227630877, 14091744250862378211, 18189689683195058625, 40129895363781905],
+            [5253612970720074571, 9771188595027100958, 8587607149510788053, 98468877786926448],
+            [9338791874755865511, 4653301373859292700, 3855196530315631050, 676329498163009533],
+        ],
+        [
+            [8238724230924020387, 17448833294221353699, 4543268619038338873, 910036747815420038],
+            [2908064668728645934, 9469173789322206272, 17849892966021118946, 730027812021271194],
+            [15988491092158110302, 68086679239480011, 15527540032533785533, 398636687413900131],
+        ],
+        [
+            [16610354866496726525, 14518144437568420278, 11581031677375479433, 1286213999539772435],
+            [12307576231181228770, 3081482816788117158, 7747437477545191111, 1298393093531134883],
+            [12707129843473612449, 12258358551409478693, 381562505930219400, 178339615914165872],
+        ],
+        [
+            [11623637493897675897, 837294994219618971, 3006929712856519373, 97639835270585307],
+            [8252497638418184058, 17776331871706585027, 2712138532178848628, 1073740571066577920],
+            [1827988422634101920, 12922763664603520801, 8824166665073010845, 1135656827276295376],
+        ],
+        [
+            [4142317448480691243, 12112844876710209870, 6352701025262791848, 911829343484492164],
+            [13045911409507544389, 11998461550222387235, 4268031834155200614, 675039506849685564],
+            [12492898807894925218, 2892339261752766875, 3650443440319291666, 163174639309197997],
+        ],
+        [
+            [11155254485052646553, 14212487598306261761, 2260378841647409534, 323894893137062484],
+            [1853006286861936389, 4227171001932243442, 17542005726840313252, 944111966166724870],
+            [5977883635146073282, 1306375108072223030, 13087010731355514279, 856270028105963714],
+        ],
+        [
+            [14157024138523705251, 17476818783155040591, 13679130128372916615, 1257743085298394586],
+            [2843596073022530668, 14606733739120023811, 723714993175541454, 465223030821868702],
+            [9345206407373768138, 6747799498673736613, 14579599188201023731, 1073778396027099677],
+        ],
+        [
+            [4598715869563422555, 10570246078364663254, 9089176696923504481, 323506091697188932],
+            [2827737238774226855, 1282723953406597357, 1617650934526423621, 1007206875330887054],
+            [10259135049080664466, 1794945236014145899, 9444202208809673078, 1326574195025535590],
+        ],
+    ],
+    [
+        [
+            [4363379238025799128, 10947940176484340900, 9211636051711577793, 627213626655981651],
+            [10298136854459313743, 16834096892264140329, 10686436904158227255, 979565567870415900],
+            [3768402888264114495, 16441884098065137703, 11707140042096782363, 797437268392853857],
+        ],
+        [
+            [11360855444724693642, 1776668644755808588, 11179694665628616504, 1121349686057654069],
+            [12802660301464765002, 9894328461358982875, 1602481844840808384, 754634980480705805],
+            [6299946368301694603, 17416377702736613548, 15760603917632190239, 1127122213144074164],
+        ],
+        [
+            [5859207347132572104, 16068618646196678928, 15638816860924772782, 195455884166164806],
+            [2241422832771569704, 16609084424099949083, 1391911384580685233, 94540764726623120],
+            [14141432253391862310, 6023201628769208197, 13044602618026634971, 665815834876610185],
+        ],
+        [
+            [4394123144738247808, 438359738918087787, 17274369777505295269, 1038536652288672345],
+            [2501968920361441727, 1440647952370820868, 232342370050844244, 229265865679647028],
+            [7630121355864172999, 14231376825611938602, 3648855770278847205, 328863183330577012],
+        ],
+        [
+            [5433388628102954354, 7291976697808170446, 15690139531207474601, 565166358254518765],
+            [563096433292913316, 729779869008329367, 967660426560066282, 438639547723949282],
+            [3736630799387931999, 18160309636568462836, 11726946242834183044, 632699144084562591],
+        ],
+        [
+            [5461928749937202565, 1429276563489434142, 9209465660568972003, 1042671681385456795],
+            [18143983649632508378, 5818623296242725915, 12980188107989346553, 1161219737739450408],
+            [17089117818366836868, 17189074597926746562, 4374535165609078037, 635008697403077721],
+        ],
+        [
+            [2090982374609908028, 14878295278110557562, 16676140840121633924, 114629671462638930],
+            [335786725197889966, 14555795367936716699, 1736287942250429666, 968245829571534073],
+            [17439610845396236840, 17996252678703071039, 16511160432577595947, 137569057709377900],
+        ],
+        [
+            [18038274567430126171, 5668492721047431357, 16003589269787496716, 1204959428691659760],
+            [10147353338674360523, 6046064465317077402, 14173210346828165807, 703467309987128680],
+            [8823502320325570789, 761456836704069407, 14236166208318353179, 915751701707271343],
+        ],
+    ],
+    [
+        [
+            [8208206998131647181, 5721659393394122057, 10733117842336842848, 84101971649336810],
+            [5902781001002542842, 10091867408681551833, 17897512517097295188, 237934633153871616],
+            [12932350311205573338, 9294299550610130056, 7785251857156456111, 156443783419439594],
+        ],
+        [
+            [14170799648544893559, 11707950834020439214, 13749259492289802328, 887315116726402784],
+            [1910843919983411765, 7471501511892909519, 4907738509767528648, 1251960849117373488],
+            [11953741213217041574, 9981011414188371084, 8507400682523412500, 19236202208077298],
+        ],
+        [
+            [1773909183526460854, 8347554214765846948, 446976885973290990, 1269194709652699416],
+            [18011337888762788066, 7729301402565907436, 10108166464008117123, 53875301074764997],
+            [8164173619650046352, 10081237486699165573, 2831732900903119499, 173451397250127009],
+        ],
+        [
+            [4026170758409354997, 3818890887072834273, 17501042970017908095, 359165531110909367],
+            [1881200952430359127, 3376923137931809406, 17603487947868311507, 580931832952319260],
+            [17059211849423289074, 1215668109365336607, 6973990821700053418, 221470992980355181],
+        ],
+        [
+            [1176148913408242067, 3306227922188889685, 4139517625367067506, 899279056386561497],
+            [4247163506112380657, 4047095870062944472, 10746305050468963933, 284982896598501206],
+            [2842374175166754427, 16307989703749295962, 11972250424381098321, 1098844388779986285],
+        ],
+        [
+            [12163609698755996380, 2705422258839192606, 8129675205435136085, 93321269723610046],
+            [12063804826395534159, 10422272912070825204, 5847620160994439014, 708355797884107711],
+            [8568620481676265302, 12386062192297251477, 2994111660611564979, 461227100957329082],
+        ],
+        [
+            [9048110452841801083, 8880529627260197302, 1971440850319662276, 587486518602603350],
+            [15941779392214205553, 7445664536859666917, 18087355787321431271, 876419360520762927],
+            [11314570656916923954, 15301178738294472937, 10255428811437875134, 849311192418168731],
+        ],
+        [
+            [8798366430664841990, 6807298225594416428, 8874959066275350828, 660353385285324198],
+            [13790545029543551004, 9995153491222874471, 3306828529448375532, 542064602669274103],
+            [10636906503589959017, 3295081917314631090, 18320178437851964965, 729278581306133889],
+        ],
+    ],
+    [
+        [
+            [5079964585555302032, 5281587089822576915, 17767674601141392416, 1126123210662456724],
+            [5359372883925964732, 6165541736621274935, 7042284152532655074, 358798173565164054],
+            [844966280472340569, 1612293804788138906, 2858576982172823593, 140947738400893471],
+        ],
+        [
+            [8346125127164373328, 5574954700958315444, 10857116095472770038, 818363921121401875],
+            [14150779137405591382, 15246771416064637743, 16286527951552217235, 1339384266440824732],
+            [8619007730774247436, 2526217570386146463, 12096685289067086034, 740571597456441685],
+        ],
+        [
+            [9976582342081189764, 7991629513501999805, 13883057797657808415, 1306645459143759847],
+            [16045443235166068461, 1968775044744594609, 2034321462070854634, 1013659382651735896],
+            [17253186125910017618, 9079943513407989599, 2928582828388072943, 97682943775671694],
+        ],
+        [
+            [11842967191047107814, 4410658231970792919, 16573815816056804928, 346637678845224267],
+            [6253382561076247916, 7823006854230966725, 11663390491663107558, 626503702486755444],
+            [17414869265785214191, 11919220392564078792, 8230247379618146066, 1301761960180067301],
+        ],
+        [
+            [13256409809283789582, 11392427249125956437, 18030484338700977324, 1068656285858604830],
+            [11133171831885906148, 12400405191869755110, 8284418662919824042, 628249180391480645],
+            [13072486269415389614, 8255206848915999134, 16357027309736157829, 815032431546148290],
+        ],
+        [
+            [16079930583722808590, 16238480113973538251, 4028647589048898538, 992331713458460167],
+            [17553011642134817302, 10568236125206572856, 11499917290460776320, 1339198353902271422],
+            [16097140233281464722, 2562232921019526975, 15166954734928515211, 225809755530648448],
+        ],
+        [
+            [16956775678714525229, 434963618927806276, 5981575328492902489, 448380687494916035],
+            [15834945636354804267, 17502357310759079246, 2215965942902145282, 308519690935074128],
+            [2916321827975515271, 8687335394313493405, 6255899869358852648, 283076923268186918],
+        ],
+        [
+            [17887177768857813707, 4170347795474803274, 8962034727125444270, 624523836176574979],
+            [1485588986055661924, 5374656584049883884, 10391322203249807591, 1116911547507835922],
+            [15820486598740317783, 13250145774318127975, 1858184449959672683, 675735806481930922],
+        ],
+    ],
+    [
+        [
+            [14101647863428994541, 11720675572496623515, 16332330497452061164, 903013814559864995],
+            [3544527059886513447, 6571762000032493897, 7940231324697756478, 707924075928491533],
+            [8420703391264819017, 10718337262917693982, 14065157669371390962, 454561086958980539],
+        ],
+        [
+            [10604964049968948956, 15101681511234446329, 5454196919141090824, 868892939031113311],
+            [16964634600244867233, 2712485060343621895, 15485136842780540130, 270297210921079321],
+            [5582776628790803846, 230927605438748228, 10384654394809575453, 450035353300448629],
+        ],
+        [
+            [1637533552904314527, 16058668624149754199, 17299306249347028840, 1144318850075798928],
+            [5143701033311658219, 9792243315672979827, 6498743719995441619, 28530806484070536],
+            [17662801595075902304, 5089990737184986038, 14852673809770133661, 1174880825792834779],
+        ],
+        [
+            [2959287785128352439, 10279076831538830923, 949861082587969533, 1299238422166407688],
+            [191464116753556791, 2577348754242692911, 9254428209188249802, 907645272068803806],
+            [373705968307403365, 204324887399502898, 1911351580355738805, 1038183980519835449],
+        ],
+        [
+            [1198106266853770944, 2434752387878010843, 6604371574461816236, 9178532202347461],
+            [16077740460049887363, 966220850227333946, 5094111209759624551, 1157351055303735120],
+            [3942625290007519301, 13004103925254872611, 3647979386666624923, 1170289069817283137],
+        ],
+        [
+            [614583494617591962, 16399362341081336332, 15207381303495751158, 391056305910092969],
+            [1965067574355453047, 12343048075061547215, 8792845754890204477, 707715876251919742],
+            [4335790070403496015, 8769774853169786977, 15758559457766722652, 345665685938537636],
+        ],
+        [
+            [6023658521974446405, 13210902007831528744, 17593033992986542496, 174672540331833166],
+            [13124289038040565551, 2974652286353995602, 9568917231426195342, 884410649100079577],
+            [16948556146113778631, 9193703989217843592, 9931219426750845387, 792906825440175783],
+        ],
+        [
+            [14950531121733057636, 17718788030526820913, 11050996605501982191, 941322229113151541],
+            [14548553104381751336, 4749364817516757762, 4474054352700920976, 1191501932747929277],
+            [12896112584537145606, 1424992265109695357, 2948700281785707369, 856633370645729469],
+        ],
+    ],
+    [
+        [
+            [16895394847176962872, 6608531352881646055, 12343287833103562265, 1265114222062097589],
+            [12439481245255004670, 9567023095535248542, 11395756198269916901, 349324243665608177],
+            [7300520596340301108, 12053800563243847261, 15912226393243744182, 949094116346000418],
+        ],
+        [
+            [17188331147151777060, 12901777371064211934, 11733340548187538481, 458526755101864264],
+            [11480444327702221326, 879021839451301379, 3138214723773870876, 869597949452991865],
+            [9802465317084373667, 6458281181497479262, 2689968703628626462, 984903190707761957],
+        ],
+        [
+            [6549447626183076280, 15114273882992249007, 4065046680075191061, 775402120909829029],
+            [16115500889617373664, 7632276379598389194, 14918302190849352905, 1255094021471312270],
+            [8085377436705060701, 3375678952724703017, 12077700620222021120, 43655353604609031],
+        ],
+        [
+            [5290095805323691310, 10295445958858831822, 6642117679828222326, 565652859273844229],
+            [4448134685051122837, 5587205616288320901, 10911843712959167994, 178641679747063471],
+            [17111479390217728135, 12552735128362347849, 4465654343999913508, 998648585319507230],
+        ],
+        [
+            [1571902506201344613, 13551055661734509547, 15168901722348738802, 946551125085081012],
+            [17378651369067996786, 7327573662480103565, 5844298597958904840, 639911342069274813],
+            [4486167348807201534, 7751150026655579842, 12646184240306709921, 278671157397448258],
+        ],
+        [
+            [6834990427608947265, 1923030660784213593, 11793918076919919903, 1019635923300582127],
+            [10143036804074077855, 9030040698902563049, 7081762844076384618, 1193599306878234437],
+            [2728254721211705669, 17631479659308050063, 2766459511874604818, 1255481221087953515],
+        ],
+        [
+            [12070785723315041416, 3306987222846997110, 2176076277469980427, 433224798049156185],
+            [7895362512315731437, 7622409743136001389, 5284447226599123908, 383769563378922723],
+            [15350833512682896084, 1513179678933602547, 5843979293748894506, 1254793857603463967],
+        ],
+        [
+            [857006542717134369, 6409912944942887126, 17129020915774139344, 1037202033287654879],
+            [14093896975819010147, 921313464861852969, 13562338575261949913, 164824670049914910],
+            [16386147161353754193, 13791891254163176773, 3848317574458555151, 153708265896154631],
+        ],
+    ],
+    [
+        [
+            [7074267258586924967, 17254908380685526895, 4761693968495734569, 462966150387612434],
+            [13495597389073620340, 7155246037566800190, 14686375657542881326, 1181189375921262627],
+            [16662745101505081239, 15079433269856930429, 10544662409851958639, 200940250790793794],
+        ],
+        [
+            [11081870589300230626, 7500956954981629517, 12292646519950593861, 923635844815277657],
+            [9674166269479574566, 881803266166946633, 16186027961661878584, 432877233684808701],
+            [17040384600619947281, 7835187984998133367, 16961494335820965091, 1008388085235159781],
+        ],
+        [
+            [2816409263999792236, 8442788375072158676, 3929371319715594555, 487860109113996833],
+            [7619871407345071915, 3193745036702780472, 12686140695454877853, 1291137285310260546],
+            [16231660929335605924, 12607868600665011283, 11891668711789926467, 175295543955412182],
+        ],
+        [
+            [3949992430319856079, 16536068810009277731, 13823531531853974649, 144511977151838436],
+            [16591215415621121211, 15282809897045906075, 3158602650573020828, 942899700473665539],
+            [11141681205213444331, 1998779762027089498, 6016223226327847727, 427195254265330605],
+        ],
+        [
+            [10507034312185331920, 2377781586396660741, 12646365736052284750, 812643065988564809],
+            [1288348878413216081, 4438473025289997308, 9093903128147316733, 1246698970172431534],
+            [3763049877301113169, 15083830181144676534, 11330623655686789590, 97156538548834362],
+        ],
+        [
+            [1100898644093913750, 6859746875239129133, 17483910117405449234, 513283262419127368],
+            [16827991750171504717, 15515695666479881151, 9406702595951387272, 1237294330781669531],
+            [11821484294102144964, 1749298492058900194, 13850453161425575161, 1059335597645547487],
+        ],
+        [
+            [13846360387047264515, 4423204252825842289, 3584226019020904810, 820953020820439796],
+            [771236284780776880, 10550424166832876094, 12645300028941209287, 325958350384093498],
+            [17911941196371558123, 16769687733813467643, 3986881928766299349, 446464350971268654],
+        ],
+        [
+            [18146399858219281967, 9120115813015160392, 6296092699575222673, 616530923637574490],
+            [4967217975662946444, 7939444436844241791, 9972259497172821808, 854840238681819078],
+            [10880599927615239174, 15855761093519194179, 6396179812633084956, 965738604987056733],
+        ],
+    ],
+    [
+        [
+            [18104647325025640233, 15830034890430047938, 10356280732618544068, 500704742493727546],
+            [12604939083458217326, 3608860448846857927, 8417062827397834349, 1162009352502989656],
+            [13233217828960565865, 33933023147546052, 2695372127694443470, 281974306580099089],
+        ],
+        [
+            [11403401968295666781, 5729427489103749852, 4146746755663448199, 477231025673110006],
+            [12830249447858375867, 3962325302532469461, 1549427118014386774, 1252955746846529227],
+            [10262479262606534137, 13141393585485072963, 2802094563239633216, 1151108440131080718],
+        ],
+        [
+            [7839164589934327184, 8724171849432785615, 13448173447467894946, 1121680718701763071],
+            [7806750247219425695, 7106626901417212097, 1090585235650269863, 1282696834236599618],
+            [5836567029181080494, 8561525719733556875, 10755904918046111375, 341061866018023133],
+        ],
+        [
+            [8055245423169365285, 4131429457464501642, 9003841376373980255, 1290655401768203314],
+            [18277797557879414512, 11165000530608266588, 10606791179713458834, 958966964776234593],
+            [9414479115549599399, 16125916611467845813, 2048561945924434539, 580239777987843347],
+        ],
+        [
+            [14537272963826918135, 18024085508612784971, 6066118998360680739, 1034985352134097347],
+            [7413744531463529683, 16557830849322507321, 17186911863377085328, 50866269003076814],
+            [2286893584556336984, 13861802872749544651, 16944327004689931205, 1050711857383805521],
+        ],
+        [
+            [6761457581737141256, 3905743216602451288, 15669712767499351197, 713832109549756355],
+            [14259129824114141936, 7921123340714866093, 15668502329900222596, 402232374255146657],
+            [17000094338602845114, 5264814148191284128, 4978136650848869912, 1204716337179734469],
+        ],
+        [
+            [16178654374848837474, 8370113299858929498, 2150261962879302843, 1128532443408627788],
+            [9416917716945806135, 643741321089167991, 14413712294407051480, 427408272504087486],
+            [17071639802966213471, 17009775305457711046, 15926922886570854936, 1068378590260261129],
+        ],
+        [
+            [7856789093359848468, 7085831334349358927, 7598822744855657530, 380157353071130523],
+            [9472897714640730142, 16514513729381575214, 4776111065150979635, 1167581309341432284],
+            [7047543371620397733, 3500840933360426641, 11544474657069602115, 189767893337382980],
+        ],
+    ],
+    [
+        [
+            [3842685832528556289, 766766905618468186, 9532871896973048374, 1279728239458301899],
+            [5940435020701864691, 4950957489640782314, 5429225289093303210, 140885100948114038],
+            [12687583686834701452, 1783898143817675358, 9584204541611723196, 1032493515048031249],
+        ],
+        [
+            [2850619596781088658, 14480421963845130566, 16065023904015643488, 904110357780775050],
+            [4136680986283187366, 4511979495936592717, 2754044771260655526, 265913140207792348],
+            [638668571892359736, 634401702057273299, 13604518667020513847, 295754957251882403],
+        ],
+        [
+            [4435838736228900424, 17784218514093709746, 4421589610171634475, 574177423644858490],
+            [2215555669694678992, 16027933458544348697, 12074996261321628252, 358999368385641417],
+            [13372717831728389087, 17230201229860368871, 17619637142168509923, 642296320094320004],
+        ],
+        [
+            [17225299682676050905, 899732486913560059, 17831957662989257939, 191905843690870293],
+            [2805453223518253147, 14905670275676940567, 12971316359600871371, 22469902066286638],
+            [14777099820094217113, 15161900320246043840, 10908503012507344837, 1113208938998143751],
+        ],
+        [
+            [4887274165652264005, 3384152726377089462, 2732039170293111020, 195961034797109717],
+            [6960293547249995301, 12833292840755971280, 4620399083467223276, 1285997225597873526],
+            [5002377646216012731, 5617380688221406526, 68847639934371304, 393382513450516179],
+        ],
+        [
+            [2385166500879066862, 17151927022175082327, 4682887376165631787, 1088302524844916504],
+            [7629588849239768030, 10626093853583000899, 13823406140609801752, 769237551293991062],
+            [9014048552624368922, 9980779896173169622, 2925111493567163519, 629003535756666841],
+        ],
+        [
+            [1291033940633953103, 3144064211506303974, 498052058246155547, 616201646022783504],
+            [10486266367359977790, 12866572257886619127, 5037790565412221292, 115316242171022336],
+            [6834484344167818283, 6328760974319358127, 8348275605535685995, 917244360604573318],
+        ],
+        [
+            [17890294829709260409, 9893437124049747271, 13540935597960844151, 238352569614912641],
+            [12174323853023706035, 14088735262972483346, 3140905730610327002, 434168874423323051],
+            [14157213226762083946, 4255162680788516593, 9468566965560824688, 825597278040613312],
+        ],
+    ],
+    [
+        [
+            [13700161484551333606, 872284274056718965, 14346554065407096723, 596090879579966458],
+            [6201394288120992240, 12461394372189601362, 9248721735751378458, 993934628444849539],
+            [14041165962266596917, 7580508090859705158, 843832327979407218, 167326123540344764],
+        ],
+        [
+            [9178357232495809961, 16835240985768430405, 17778710203721420071, 681474975904202790],
+            [18192444499943615213, 7680270209606721301, 10208615895869000887, 691905252698676351],
+            [11531063795294477792, 9842659517998496720, 10414550763959657596, 166017664897403738],
+        ],
+        [
+            [7883925153544692066, 948577347409522531, 12483199396326498012, 259879251890851224],
+            [4856704901727865462, 18394265607080697772, 14839294419193810799, 742658949618254077],
+            [5834341748258320355, 9281766323045455625, 3694431264282356332, 223451080554546961],
+        ],
+        [
+            [4560909345165183896, 15876062471035727919, 6643518719577256821, 57629631156361565],
+            [16355809982478517445, 10868162794583870430, 3053877079097911865, 817421862226949650],
+            [17540827721285990398, 12892533528984849401, 12540061493212933359, 620858808121675863],
+        ],
+        [
+            [10548924701252445496, 1136686930435357959, 3746818225943647201, 475957666354484536],
+            [14000962613448151980, 6144418293997665305, 11701362639891839824, 888173931563152648],
+            [10405691016761621701, 2708096416260244631, 14543620621976757149, 677975448226425452],
+        ],
+        [
+            [2875849216271351417, 8561705992471962769, 13400205014603687752, 65151226187159596],
+            [15417850652027911254, 3596478979332768991, 14845366311858384220, 307382137672882753],
+            [7175664730512768781, 7454201623170024907, 3285579509422471602, 618596441199873697],
+        ],
+        [
+            [3313854870854106103, 13299813889814820192, 4547077770548728919, 700443099605089408],
+            [6098826434253644998, 10135470625394878615, 13290173441394666668, 36423924232575719],
+            [2084326233654375409, 15624878992296147512, 9271639755076430488, 579196488525499820],
+        ],
+        [
+            [1728558323634344119, 16277700196064083303, 12923514882533846135, 1284207415932172909],
+            [14745975771312705861, 194107896470828031, 8988511386448139269, 1231020081700021010],
+            [6033910897443488158, 10563745563412942119, 1832459380782051975, 555678662168857148],
+        ],
+    ],
+    [
+        [
+            [18315818381429267505, 7310740726417655440, 3607298164583694909, 342996641875844300],
+            [3076098381225937367, 17078537771018941997, 2704199898755439803, 889095795797996199],
+            [9899702145242483752, 7487833452460891007, 2477455403539832366, 961065297849524467],
+        ],
+        [
+            [14098488927451707726, 14899019493558662998, 14111095761385408791, 247076970753183339],
+            [9986814665562426124, 168134193863311679, 15416240169645535589, 844231401941236632],
+            [14936615026792808886, 1137865652338337793, 4166048198705524474, 1204031121691992537],
+        ],
+        [
+            [14584070491546569671, 8657196199077302603, 9503566313027584783, 963925833621166685],
+            [939664485034578619, 3626074772907701068, 2711904388535972168, 82534122036113879],
+            [57347999214439925, 15218916210420313126, 15273428922037531981, 598731007927332046],
+        ],
+        [
+            [2808519934766738327, 2032148550898671496, 6292444863857649700, 631922767178969280],
+            [5537438589814122847, 9902663706829137103, 3752412717169931517, 825593620856179969],
+            [7286082333812163249, 4583508228552019896, 4195986751762866216, 1025883767566631754],
+        ],
+        [
+            [9859404402408520287, 12400970874943046116, 10733258522178183670, 1342450276840728821],
+            [3321339265112133596, 6472454480174899140, 4872625854154289567, 438986621629871391],
+            [10129951014856245635, 476926437105601575, 13945464611908909998, 196868354429238362],
+        ],
+        [
+            [1871266567497257714, 2849953574415640971, 16935207108116442540, 197719668198933939],
+            [519101531528074323, 6125200128628118975, 16643173581801172920, 803335925674956376],
+            [8373541019303226973, 14541953206380222786, 10282768306888455397, 1332824261261256400],
+        ],
+        [
+            [13806343676788141869, 2455761045355301266, 7129039467612453056, 1016291472347712362],
+            [7987260725410527630, 15019043020714472703, 6073903073251739652, 701047490034982243],
+            [13663960419783201653, 7953612693848523885, 13648586724918242557, 928632317877628328],
+        ],
+        [
+            [7518314699140138423, 7504021639492314616, 14891518794243603425, 519330145816483041],
+            [17728524633119097711, 1269211644787603098, 7003005836086284028, 584683454621505830],
+            [13069775591565842892, 7580619880495570315, 16313096540051439513, 627035974300626961],
+        ],
+    ],
+    [
+        [
+            [6556121443033031656, 191738291200213756, 1717885417835564695, 271670437052863091],
+            [15085340510230048772, 11166815919302193898, 17922049468152888034, 383478279185325074],
+            [11737228335812430899, 9750634306383541345, 4126676708731709472, 862433619063860658],
+        ],
+        [
+            [16610024276107627501, 10692963293477296621, 104963574985333372, 877325949178536861],
+            [13610807797339864725, 2443135606675355470, 10183010463337650540, 689225560635720371],
+            [16891500655841194171, 6753375717045063825, 3643233524775245575, 363942494647180704],
+        ],
+        [
+            [8496071749556879403, 16947867839843169005, 14647465248210855048, 591124448201645339],
+            [2561337762717260161, 14630077591959539533, 5899392952040520201, 691528693033477850],
+            [14788536580917940828, 1797317353849952537, 195503667529424332, 264686873537102980],
+        ],
+        [
+            [8620409446973678147, 13164231213185446640, 18200180341628877360, 695753928164695542],
+            [17422840536868429700, 1399860626172074337, 5437440637408084134, 194528249629710693],
+            [596751728032757787, 15031911044558452142, 6626789099170675292, 984553502196518634],
+        ],
+        [
+            [7643456075725326020, 1419299958480921340, 6625122166293048641, 677639088185701526],
+            [12207280981311324614, 17633995451456978220, 12882954980955742554, 1172255309636391643],
+            [1806173438449807400, 10209854701867480601, 5806581245713621591, 708478731091209944],
+        ],
+        [
+            [10979086983141902218, 16713193290822373913, 9464049655279265438, 448187462072700964],
+            [14445194893604236775, 1864492301965560442, 13687488933437481676, 1305691002298704373],
+            [10849949931442050812, 17188069564709821241, 14390559478788903292, 507239819076800671],
+        ],
+        [
+            [10780411202887974589, 7422468259991809912, 6570358781802326469, 1199670523149756113],
+            [76803385756625062, 12708416397348604282, 8900486991233752608, 1141086378655136650],
+            [8894186426351067104, 9657132409485225253, 5059354443821243192, 182046961149704262],
+        ],
+        [
+            [12091189375673465794, 12411226934490742589, 899757994430497908, 411198822807040298],
+            [8040706074733389314, 15552348558979327433, 5054816932626210370, 791240549005948864],
+            [8315865399534661096, 13822675731513119685, 14430667689704335528, 767216862198904603],
+        ],
+    ],
+    [
+        [
+            [6507409017571260748, 1639689633005201954, 18163748914077575544, 937163955383512462],
+            [6167436670541451716, 4879869308995697433, 5413104993683840957, 13548620905973537],
+            [14858320449820068894, 3030618996317000761, 2536266383123371596, 176175593645488937],
+        ],
+        [
+            [13128015034699531176, 448381095130653694, 14816302416265555727, 424637793213940008],
+            [17597011690171656888, 13612416625934570516, 15867392437476663724, 555629202326535033],
+            [7878422558641001516, 15682223976026472560, 4894484710208094227, 310861715450675672],
+        ],
+        [
+            [18412788858063958597, 12483179287134804685, 3263914848229031172, 571190675076814801],
+            [55722799927044178, 11025705906220673086, 14020259902169552619, 155776673196843853],
+            [14487380474273962405, 4804600665085489731, 17086530911044412029, 444502488454391130],
+        ],
+        [
+            [12074284046549443818, 16042296933073636473, 3545244325645176853, 509505686093254246],
+            [413173909756769950, 14412489229960294424, 9879681275336172254, 824152527379397465],
+            [5941885484867756795, 12722745564442574461, 4318741134415476476, 777076597656525353],
+        ],
+        [
+            [7219078116317657721, 7320100973737017791, 17265159771600242858, 1105315319372522629],
+            [1036469382291965157, 11957916841255261065, 3469131316628435727, 554640133419832713],
+            [5882227277256177138, 1396798413368875526, 16411903240790500685, 598348946776179512],
+        ],
+        [
+            [1709610843242581013, 6435419729466226119, 14879264855143683382, 228120638886951389],
+            [17035441689241223174, 9349037513003855154, 759535062663959072, 681244170904555168],
+            [8604909908873033827, 15985740402151148850, 3785602500622650065, 115182298514513091],
+        ],
+        [
+            [17338867579233982014, 9664658545787675916, 3432211128469814125, 19897654994706161],
+            [1146449644561997547, 1253635947818858961, 14462533749419437185, 221435181183184270],
+            [12796029702681690673, 15106184722014909005, 393523573899149323, 975097978100592897],
+        ],
+        [
+            [1755488835956441902, 18178312215936645393, 9914935896623179917, 1156346654976380703],
+            [2638362073392790155, 10319933634271116009, 4766537882980323007, 321150182557977198],
+            [16706964788411064801, 15834122225121052576, 14220625908683492413, 860576303211899617],
+        ],
+    ],
+    [
+        [
+            [7407356202401166893, 10783148257597685405, 520194752395110351, 893955270636332042],
+            [14523226386518065159, 8629423183856276739, 17858246667642425607, 1187948209669537025],
+            [2574869704689445860, 16631563405250506729, 15980834609673932796, 200148028304238674],
+        ],
+        [
+            [10238686453719623126, 14457947395678394772, 8176476826937717675, 1029511717244049555],
+            [15404978121481809495, 4412295440922574613, 7557886920598642745, 1187668687687989972],
+            [16642225467087301729, 13678258312948378671, 12263772612642159578, 728000105077999184],
+        ],
+        [
+            [12263508017595039186, 15100885503573297883, 6214518049065748577, 755520609392977063],
+            [6834789181004079997, 3003066258455171504, 14818758020793607997, 320208006145328222],
+            [3478726037100588807, 10971805904964063441, 3798959393218593476, 412554544577405338],
+        ],
+        [
+            [6133960150604826488, 14115861260534636140, 2772652213163322069, 156829626572725607],
+            [2735635699610088474, 281931292066850368, 9646729312340465707, 503483821875024395],
+            [5259651932286275300, 7960967491535005452, 11070574649599842771, 336200967358427702],
+        ],
+        [
+            [5231085358118190293, 16074626534949056268, 14254295619048040122, 120987893482419512],
+            [7934952817896631427, 17680325956532630988, 14596121692889786705, 80837020510270803],
+            [16598107355673436155, 17664274478512937211, 16270212816829438622, 703797348724489225],
+        ],
+        [
+            [8956538296364283091, 9663242337728086584, 2254664311213934585, 254142644759581853],
+            [7796205846045793561, 4093232815291268029, 7639806144042434653, 1175149969827111649],
+            [7361676088504370520, 14050491349878432496, 17832537351632894960, 173559855801050231],
+        ],
+        [
+            [17926424170158067089, 2517745980504442622, 10709845394474063193, 1021031935575852027],
+            [7522420221477878918, 14035019106673601832, 9695978065725532546, 922067541228038537],
+            [8378349659427015640, 13690752237826716445, 18251694577368681034, 908088935176472398],
+        ],
+        [
+            [13256699166166073785, 13460978205419666646, 5964138810063760699, 572994701924190639],
+            [2941365676726528092, 706174435313472799, 5230960278306022523, 1077929453283024426],
+            [2082892712027384363, 10378044177770632534, 13505014370375578738, 95627438608746883],
+        ],
+    ],
+    [
+        [
+            [559454329872172003, 5684073798881370072, 9247210072741842291, 592197376891184660],
+            [4896453982093702577, 2765298369120150394, 6572623982243788858, 1210858440434917680],
+            [6310407839824886032, 537564906013607579, 16106613773542277668, 173694378648524503],
+        ],
+        [
+            [10574779366565154168, 2567756722669898697, 13882874102798903720, 322542200892349897],
+            [11609276366410653833, 5589719452341326758, 2958594446435143862, 400856660532439929],
+            [4085139723854116922, 12566993685597763241, 1301497040374675974, 381372692022920396],
+        ],
+        [
+            [12337980571615897375, 5458146706244592780, 6388600965014500823, 325670385260456614],
+            [9959980182502583352, 71901620730252819, 16868120218078736067, 727499568531837422],
+            [14726849645655825893, 13565040906722087535, 2639658141050398069, 554296314008997565],
+        ],
+        [
+            [4764854210181782567, 13243840047387193645, 8442763779786022937, 329279623171622113],
+            [11702768790946699078, 13102489354079895214, 4893119006734333248, 401778037274436523],
+            [3713714812628216059, 12178289431186844122, 9305659437487634418, 918571604959802081],
+        ],
+        [
+            [1252309618403682451, 2365506948635185955, 8577785905377812973, 616277092778266829],
+            [9661976477384134559, 8878927488396078941, 6627912435574734020, 1323416520019631180],
+            [7471263747207793741, 16726330460213119018, 11999638087479982757, 492985887122426467],
+        ],
+        [
+            [7363337068064474496, 1836197434181249572, 15753539390091647985, 200159397108165280],
+            [8322171665305341714, 3835567575695465246, 8027217355243431944, 317101912987344415],
+            [12255697999034329596, 7111024367396835338, 4688534461657465553, 892600564395177690],
+        ],
+        [
+            [15317342008366807477, 860145701768258150, 7061089553735026423, 878170947656321396],
+            [7961903275689383697, 13250049631237159642, 2993751152322728813, 949983762571764724],
+            [10023231429927666218, 15449682389177814492, 13366941611415753716, 1338602418641862423],
+        ],
+        [
+            [3689838361738390526, 3252258030822650846, 398820799157849407, 255358392146691440],
+            [14359749446159356128, 5003617051866667094, 4873311522625734537, 691483014330061776],
+            [15786517789679908209, 1817567609430516443, 8881100129438278482, 468443794569239868],
+        ],
+    ],
+    [
+        [
+            [17766393230942909118, 6311050699354174332, 13349247966093093794, 758461879363868745],
+            [17808310367612195010, 4775107146828761560, 17986349577740764765, 371871164893837284],
+            [16222370381221063369, 6965864854738340489, 2300852577602933557, 1270622847558747352],
+        ],
+        [
+            [1152079923983567565, 6712006511934294276, 2601332777635200292, 291430300369620709],
+            [13440214926772579495, 12098232697041587018, 14478707712659340021, 259018708875059492],
+            [7450335081790056122, 16159865855255706635, 3500636569153825990, 789092736943954779],
+        ],
+        [
+            [7926890947881870090, 15200946105782240578, 13353852191600918587, 298760921993228017],
+            [6548817824273072731, 17841305800514490176, 3001300205745012601, 1211185045801090584],
+            [2347219819638765588, 15096693834746815006, 5711339373045156553, 1006394488357919283],
+        ],
+        [
+            [10729901486603302945, 12929860285400667532, 3549370657411960375, 1205843410407181047],
+            [8016331067448039386, 13454173734121225249, 7665568470893834526, 387298152959924770],
+            [13333916364016105888, 17443347409702388680, 8871035852612193766, 758507901790837471],
+        ],
+        [
+            [11011800189385240726, 6460346658372763675, 10404787373940076640, 227575255131850179],
+            [11509991050061578288, 6976426774570055503, 12641871350742790316, 1101929273153349702],
+            [13874702815388773584, 2049989787090375760, 522526127682741813, 669089254461553872],
+        ],
+        [
+            [6270560643983895608, 776438493798282721, 16834028095505904695, 1258593205360853483],
+            [10679986970006116488, 15492178065235130036, 16112343417837361308, 194756511208039361],
+            [16348836887758817009, 1841454942090014868, 17792030864764988736, 1152657447761558472],
+        ],
+        [
+            [3144887556870808972, 3884777195714948358, 11638584612791203798, 563354964484960295],
+            [14368922710585486763, 11394234739308984468, 15142988705784414662, 241262810302923770],
+            [1158076449282038970, 11259439670502948705, 5524966857887127640, 234343732483006133],
+        ],
+        [
+            [10782577804536355309, 6231259877279085656, 18061910020897908264, 686717626574799797],
+            [7649333570444478483, 7330333364960590674, 5433673584143202245, 855014731284454894],
+            [7009662193727731939, 14217973713038020622, 11862012927389048009, 810218541503792252],
+        ],
+    ],
+    [
+        [
+            [8183817718641744995, 12226394044932788341, 7561905371639495333, 18241773018482664],
+            [4798859494491505967, 8322692873037070544, 6421548752282814986, 243784061841666738],
+            [15373338121920687505, 3526861869905266313, 14601900760384648741, 1002741010897122489],
+        ],
+        [
+            [16333789689024221648, 2516735733502338388, 17187554625077809811, 453787947273684596],
+            [5496178850653162388, 16706692064131321321, 16592814972546625671, 1120361400610780276],
+            [15191560363627769983, 7821216076278610814, 13390236206173338375, 1274620904516826394],
+        ],
+        [
+            [11281899211097713422, 14340895245658992128, 8851312064852296811, 1322038499427410767],
+            [7263181433071904085, 14152485348346154465, 10374690378230823175, 907101415557659942],
+            [14141142984831278190, 4507237120157107113, 16000500023208572170, 1064999083007100257],
+        ],
+        [
+            [17540990588388279340, 10669200175760178604, 16585312944958501707, 1184534384668332326],
+            [17136743410364840081, 9214689695439415592, 5688814190613267280, 989705642671267151],
+            [3368348846080355849, 8478756978829858884, 14925282270514561945, 684739368169379630],
+        ],
+        [
+            [7850013357038961956, 15156030388462814824, 16093449489829876763, 86154204100508158],
+            [10496044260530127074, 10378822559822506922, 956783802161926656, 343547403470041314],
+            [15694979520414124611, 12405283433673811576, 612748905220739571, 1310914616038325102],
+        ],
+        [
+            [2513373788941770362, 13590261364570775127, 12210629133336578684, 23148061574277360],
+            [14185194149565092591, 16467515790925440533, 90327570772868075, 1131049248382283474],
+            [15424269057778268319, 5865746915775559298, 8155316872205427676, 839591868653061290],
+        ],
+        [
+            [8076622734750986152, 7296707225847151214, 18127255677767933111, 523280277913725023],
+            [1201475764930947197, 16309383344507526841, 8211336633615411840, 352609429368974592],
+            [13513865689219218925, 4310971789342473826, 6725265961756578643, 170989554140914255],
+        ],
+        [
+            [6086223182180679943, 7108399303948585241, 2669967977463642092, 324338959992358328],
+            [18117781764066086191, 563117391661493618, 5058343368346999493, 698521162727196724],
+            [9397916085709436073, 2092422890086298039, 14183916220400733076, 194925069227426532],
+        ],
+    ],
+    [
+        [
+            [5555368483897597433, 2361838395681119672, 9627934419348058444, 403797396611145881],
+            [18437910656470338541, 2543086022316864812, 16753911744386514821, 156675598819263820],
+            [16588348275258206055, 12011815467919657120, 9580312764357686064, 70512882220915958],
+        ],
+        [
+            [16041185207516266576, 1533640204771375396, 8731448927833281167, 889526155974171424],
+            [10346019521004881701, 5087250210330341199, 5902054623766975367, 1087652459089336935],
+            [11758414953423279673, 14185235078324231796, 7582728466943097557, 1315429839082797034],
+        ],
+        [
+            [13378581929690640390, 502646405013321154, 16103007359580092886, 468056846064909053],
+            [16966662267672260712, 3718455567656773882, 3865430738950656122, 195147304709540018],
+            [9400952372861876771, 2096425554422756478, 2620338104400977795, 533063783803523516],
+        ],
+        [
+            [7558089315298809209, 13329196547655663189, 10170878481221049427, 602960016916611142],
+            [14163553117067375329, 13417411023569267803, 2109173787586035762, 41581483825918951],
+            [6364782636880889226, 7472170404378515445, 4016734552722929814, 1067641543643954155],
+        ],
+        [
+            [13682121568494795079, 4284872116681804295, 9472321687489387921, 100123827052563849],
+            [10633055367887821304, 11388974544064301406, 959485234778662697, 1249196720896048403],
+            [2117247943420530492, 17484037361587923188, 15553028844749259816, 554406270109032484],
+        ],
+        [
+            [7482351098849932030, 1267073884704885165, 8491885996542008186, 237158186283794375],
+            [10342949694586755742, 1184706848276212173, 7443563241312393836, 13060982173599844],
+            [10549833195429395228, 3989551869138605533, 15810941329683388386, 1181027981631362067],
+        ],
+        [
+            [14582499449150664178, 3756337387547240883, 17526006783905025923, 162701681442583875],
+            [12768105103559441149, 5404736601399689202, 16256789136335730264, 515006565656335978],
+            [8924928699385577836, 17998770912164277296, 377815851611512592, 1091810457661558064],
+        ],
+        [
+            [13642100489225718724, 9633671535846983613, 16294950938274261698, 476970220767150421],
+            [12945203914514761651, 14523430458470348031, 4887485989583064894, 393075941451799298],
+            [12019048662159052848, 16014014705725585280, 17846278860213193861, 128234906235805433],
+        ],
+    ],
+    [
+        [
+            [8512180541443976305, 5550838541852970562, 4066106235932822509, 1160066995792266718],
+            [13177998824107463165, 11029056276762099253, 1306236913604314059, 925529626318525048],
+            [10019718130488003575, 10333031650051812170, 16616555126336638540, 462752303360417310],
+        ],
+        [
+            [6975308422666394840, 8477414621550156480, 8799366478717013371, 813538923688021961],
+            [12168816295314544459, 14890288521428118570, 5822997160877028439, 52933613228483115],
+            [16634562091531132547, 7814297989622042443, 17876478352757392657, 463270091646598836],
+        ],
+        [
+            [1903301771006472776, 10950069074150282830, 10474965292438656501, 244623948699709120],
+            [10296369383246853744, 5750257127996681837, 8799480127341687668, 90832136840099514],
+            [15460103633259272401, 742022541739486644, 2023979218400169202, 904078191360488892],
+        ],
+        [
+            [2685463541824154430, 6030744826409990333, 7102274982258284318, 773818926392479165],
+            [14866720033424359857, 4509077697135962598, 530654582762639265, 446750594528094092],
+            [15445583805574591101, 8457805948547429344, 3612572220630059807, 601288377684150549],
+        ],
+        [
+            [9489075892346166577, 15231340951658819302, 5423737362804768145, 671768195158605451],
+            [7135384392063928410, 6506228579706043925, 17364262098059908871, 827967321206800303],
+            [5037698591775970586, 14492828547137397118, 13583046341753986979, 451750605325462997],
+        ],
+        [
+            [9320525519175196776, 15229814494102412917, 12873635252240154058, 261236416958154760],
+            [15021700252670063906, 9093918149727713179, 7692711686328143228, 91428045240705332],
+            [8646735632642500881, 2687496407398055805, 7294735248075010856, 188710904010280886],
+        ],
+        [
+            [11810808450188556330, 8531881903204242166, 8544340838245638082, 977184610412816484],
+            [18213658515484789488, 6683028761758042372, 28541975798177035, 121755615930530035],
+            [9051512424211768078, 15109161132478581382, 1159101975038614830, 124924024977637081],
+        ],
+        [
+            [12182855899446186386, 8087819103200123682, 4765025648105334070, 1167952376775466690],
+            [15002883441560641750, 15337577028140058050, 464243273847844190, 762053987275627436],
+            [16589469107057897993, 2586865087658044315, 7951855961417629743, 456681676525571962],
+        ],
+    ],
+    [
+        [
+            [11825534551720511195, 9087078217184804336, 18012849236119548587, 1325332646976588410],
+            [10320095214422159744, 14482817815894128298, 15357150001685292052, 212679670244241594],
+            [8140221531482642118, 11147384842952596098, 9950610298351279545, 407123464397609509],
+        ],
+        [
+            [17872374584219145805, 4657352305483547118, 8584958757959085712, 1298888943979212577],
+            [9208689207005486852, 12023707121515135270, 6762344775985344315, 525807732621867174],
+            [11811677878395709686, 553495022020267859, 4188345976301831547, 826530293044805248],
+        ],
+        [
+            [405738384242474821, 15888230724739980783, 11227652243923681717, 810417802522380418],
+            [11895133935180027409, 2419324119323851549, 5909496119325506622, 979269021510636679],
+            [15845695771109017407, 11740710522570349206, 14977038125718838343, 1145209040434171378],
+        ],
+        [
+            [5954353747854678037, 15805052767138523912, 16169252896192524634, 843464021102847206],
+            [3674360165044105308, 7917708664719629726, 17843488219014272419, 698166265162399994],
+            [8134852240703854580, 15653616211390186992, 14630186636168872984, 617328419913955107],
+        ],
+        [
+            [17763145444430435093, 3377408001517646638, 17421713512583436651, 467241060259224360],
+            [12340796008119522090, 6578251899783963371, 14994672138397563519, 822575109987144071],
+            [6902706723800723190, 4741163442348261409, 5588984551639702248, 278059674127734775],
+        ],
+        [
+            [819101277386944302, 3627579711538260781, 15189336505818262555, 516171357748266374],
+            [11211963330126227310, 11016146954450419126, 4401103150480570246, 175597563643751716],
+            [6586591319031392454, 10220003594658971026, 2994627487578382566, 1328418777274842405],
+        ],
+        [
+            [12906140217861483381, 15947778392568306561, 2988227910549194063, 1088193975608127753],
+            [10807841558409128904, 2422565430165771037, 4094263107151843491, 396115297565651670],
+            [4516480904513249959, 17943095412505264266, 16647497090086169387, 105489214180054695],
+        ],
+        [
+            [1928655209167924533, 13668713026690488511, 12432892072860071064, 1036936131016373671],
+            [7722201316332819691, 4257994600997248602, 10946540519145222627, 364784195346254792],
+            [7914435857145308629, 14587615977552497178, 15604224742487486426, 139379792871130164],
+        ],
+    ],
+    [
+        [
+            [15181001767190759391, 4951472098367507952, 11522634238143893078, 1139013351749225205],
+            [9269214752660347585, 1711425641506623573, 6205008075056840570, 204469736041888710],
+            [3244384892622385799, 14783798752832798985, 15867493324046674523, 160556957229414074],
+        ],
+        [
+            [10549003815806535110, 1316423066142839218, 942192007903440486, 136000025134428536],
+            [4473911317354943722, 17318756990631249994, 14856179565995481793, 743648185031404640],
+            [10881316096877841604, 13671758193351720597, 16044878273776666309, 779194483820703055],
+        ],
+        [
+            [5267990358758932323, 16589719485065557463, 4395439732221804762, 1133122563348914629],
+            [4632985426574701105, 1442999433406326665, 11750857899999078851, 1182192246794019014],
+            [12656885099988963564, 9396629119379202576, 13115483765529576125, 1052464535494101884],
+        ],
+        [
+            [1810023616780223793, 8847591620145756062, 11123210313684682841, 1278117413246880782],
+            [6884407043007426292, 12706616676816961913, 11227579605435596778, 1187724814011723834],
+            [8020194497929991645, 15388725786961873312, 9179127304180174791, 539612116948616836],
+        ],
+        [
+            [13627559171076978292, 14927464006707178929, 13514569052817455271, 1281618760481012143],
+            [3503505976927375954, 14569547378139851474, 13683029522497047501, 87440813779743461],
+            [4746679778086178394, 10774850303322858281, 4283308151599898754, 547733016553875833],
+        ],
+        [
+            [12389577293264490845, 8403465218503210970, 5511250667159466935, 767322630053116093],
+            [16662052339727942290, 15450023359706646902, 8188829753094275159, 533936507839153286],
+            [986404455489539194, 10578000487756689168, 16169498157958059180, 960734991745013771],
+        ],
+        [
+            [2714959503598840737, 9485078614775745138, 6745989711314857567, 216106910024968386],
+            [14494452700663599841, 7250298597831808777, 4090033189220747560, 1163464597067524242],
+            [15437554445829806026, 14180005103788319506, 10390667099356569734, 556695311200877509],
+        ],
+        [
+            [9200141744416349070, 11676984059815178620, 16359572878834426694, 414644732873831059],
+            [6278232498169812273, 16023061085235642611, 1793329570111414611, 807104839111962020],
+            [13607298290217446653, 14365682670803226582, 1196548516257281982, 1223076824905529940],
+        ],
+    ],
+    [
+        [
+            [13061177064259811313, 6405916588475754532, 98859331131472106, 311404769585316417],
+            [6404570933930576720, 8094462784078452188, 18180365499014410105, 1185232285333100347],
+            [11294698817787872394, 1617050551304626395, 8611096637702069717, 897207163735573263],
+        ],
+        [
+            [2608152646791445005, 3587081087953533201, 16275374756923402579, 157577421480531662],
+            [4240999746087125081, 2534592556497765659, 9832473923274994332, 832305262863184372],
+            [16371798075634061772, 7258160306266795101, 263697838974904452, 18164162959545436],
+        ],
+        [
+            [10890954923691334316, 16999953630612344251, 700486827700653610, 402819653431708558],
+            [1509269354850257819, 10282152979934749469, 11286697893213221090, 612530608044689234],
+            [8444606978292303591, 18146095442349649828, 12852213957442476340, 91000550620096088],
+        ],
+        [
+            [12032885757980665219, 1358866772692253255, 6215626817635551683, 1310577628282543693],
+            [10858840369766386960, 1736308469857265970, 245502274002749257, 221969091087759717],
+            [2186766825206464828, 9163209399310154404, 11744813894167315509, 293347614488032864],
+        ],
+        [
+            [5648655592465888560, 3471211822615660931, 3994649200650282637, 506475974104406348],
+            [8662123390292779884, 12476500432451802680, 359041909979502963, 382415630540443190],
+            [9694426945890123027, 14984043338796191140, 13309603108347095228, 957259003834480590],
+        ],
+        [
+            [9852056413822366802, 4157299875619249566, 8785938133868709443, 659859973755195976],
+            [6520212677818939897, 938261667386765594, 9816728979438653763, 132227544786438924],
+            [6252268172223427923, 1382035593291690191, 5272546740570352371, 957451982052853354],
+        ],
+        [
+            [13161925511028213895, 1261404016471716008, 8005163049768840909, 187737196894052703],
+            [1577584468343992000, 16671814138325594180, 3469383121668342790, 831616627816913137],
+            [3419664494746673477, 3689515033045020472, 16419477283300893713, 222317679610028342],
+        ],
+        [
+            [13345633274921434455, 16848967522860797600, 16219406359397952544, 742053613607185638],
+            [9250351130977977045, 11237853525090371685, 4641564236194781058, 560328248300701243],
+            [5495498526638252357, 16534799920650162740, 17529212653558858436, 1230996625059666526],
+        ],
+    ],
+    [
+        [
+            [17840709658187128808, 2213041427652050103, 17551979453844000918, 1324235940077413253],
+            [7514616471474100763, 14560579287134402772, 6346466156610574156, 162152368557693415],
+            [752033419706544749, 757272930112735454, 307797363238597383, 1323962883969884046],
+        ],
+        [
+            [15476523433310469312, 3565386698268880655, 7952652098387041307, 1112067700117660499],
+            [6783800760747396238, 15456722901552977643, 1667975632050596253, 1193216038144335299],
+            [10834542827248622534, 1787823789386059981, 9366779990318509563, 539088821195871081],
+        ],
+        [
+            [12640246778492509400, 4374968346078584319, 2757787825518803594, 17943428612720442],
+            [12360511829532590684, 1041231381134393440, 6761504493816597132, 794410241623085683],
+            [15410703675751017458, 12028777937036414056, 1433502267616582491, 415700288739255781],
+        ],
+        [
+            [12144692331982245993, 9626546456066656868, 18309258840419775950, 372465775588361394],
+            [11237118810663266265, 13629015393328491356, 9606418304696715911, 819270653829573698],
+            [3856254055009914784, 2767417298597007759, 1116746957459797640, 55360758162699577],
+        ],
+        [
+            [6405190946179469240, 7433190952252702792, 10183126098182465389, 1280717700500159348],
+            [7503080214064273792, 9839730161252748705, 16705082586799291668, 1000038805859745566],
+            [15132820188672278265, 7069593122234232170, 8911227460692100351, 579461635709594195],
+        ],
+        [
+            [7432368119377795332, 3178001249206529460, 2870455347316070690, 114294323537605079],
+            [10147455706098118397, 7944034474082794336, 10263893486086214956, 542825371273227214],
+            [6487493734523106665, 804542374074442319, 9487360394766916533, 392984610629585796],
+        ],
+        [
+            [4461738674632363923, 14114728426303374995, 5567700373269416293, 893648508650327968],
+            [13824318255972652135, 18030246397318393292, 6499317020603496107, 388273480089348897],
+            [11601247820986172865, 6679455299432821632, 1118046900242719081, 325834177816980145],
+        ],
+        [
+            [3152467830604593768, 17813352503575981739, 4112569950254441268, 389306084824723329],
+            [9032700920855430999, 2250843371030835954, 7725006782027781695, 1088578520040288771],
+            [9369151691344328936, 14023824147773013652, 4046402575141388242, 795943299771260551],
+        ],
+    ],
+];
@@ -0,0 +1,322 @@
+//! BLS signatures over BLS12-377.
+//!
+//! Verification keys are points of G1 and signatures are points of G2. A
+//! signature on `msg` under the signing key `x` is `x * H(msg)`, where `H`
+//! hashes to G2 with the `BLS12377G2_XMD:SHA-256_SSWU_RO_` suite, and it is
+//! checked with the pairing equation `e(g1, sig) = e(x * g1, H(msg))`.
+//!
+//! This is the basic scheme of the IRTF BLS signature draft, with the
+//! ciphersuite ID `BLS_SIG_BLS12377G2_XMD:SHA-256_SSWU_RO_NUL_`. Signatures on
+//! distinct messages can be aggregated into one, and aggregate verification
+//! rejects repeated messages, which rules out rogue-key attacks without
+//! proofs of possession.
+
+use rand_core::CryptoRngCore;
+use sha2::Sha256;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{
+    bls12_377::{
+        multi_miller_loop, multi_pairing, G1Affine, G1Projective, G2Affine, G2Prepared,
+        G2Projective,
+    },
+    Fq, SignatureError,
+};
+
+const DOMAIN_SEPARATOR: &[u8] = b"BLS_SIG_BLS12377G2_XMD:SHA-256_SSWU_RO_NUL_";
+
+/// A key used to create signatures, zeroized on drop.
+pub struct SigningKey {
+    sk: Fq,
+    vk: VerificationKey,
+}
+
+/// A key used to verify signatures, a nonzero point of G1.
+#[derive(Copy, Clone, Debug)]
+pub struct VerificationKey {
+    point: G1Affine,
+    bytes: [u8; 48],
+}
+
+/// A 96-byte signature, the compressed encoding of a point of G2.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signature(G2Affine);
+
+impl PartialEq for VerificationKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.bytes == other.bytes
+    }
+}
+
+impl Eq for VerificationKey {}
+
+impl core::fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("vk", &self.vk)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Zeroize for SigningKey {
+    fn zeroize(&mut self) {
+        self.sk.zeroize()
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+impl ZeroizeOnDrop for SigningKey {}
+
+impl SigningKey {
+    /// Generate a new random signing key.
+    pub fn new<R: CryptoRngCore>(rng: &mut R) -> Self {
+        loop {
+            if let Ok(key) = Self::from_scalar(Fq::rand(rng)) {
+                return key;
+            }
+        }
+    }
+
+    /// Parse a signing key from its canonical encoding as a nonzero scalar.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, SignatureError> {
+        let sk = Fq::from_bytes_checked(bytes).map_err(|_| SignatureError::InvalidSigningKey)?;
+        Self::from_scalar(sk)
+    }
+
+    fn from_scalar(sk: Fq) -> Result<Self, SignatureError> {
+        if sk == Fq::ZERO {
+            return Err(SignatureError::InvalidSigningKey);
+        }
+        let point = (G1Projective::GENERATOR * sk).to_affine();
+        let vk = VerificationKey {
+            point,
+            bytes: point.to_compressed(),
+        };
+        Ok(Self { sk, vk })
+    }
+
+    /// Encode this signing key as its scalar.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.sk.to_bytes()
+    }
+
+    /// The verification key for signatures made with this key.
+    pub fn verification_key(&self) -> VerificationKey {
+        self.vk
+    }
+
+    /// Sign `msg`. Signing is deterministic.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        Signature((hash_message(msg) * self.sk).to_affine())
+    }
+}
+
+impl VerificationKey {
+    /// Parse a verification key from the compressed encoding of a point of G1,
+    /// rejecting points outside the prime-order subgroup and the identity.
+    pub fn from_bytes(bytes: &[u8; 48]) -> Result<Self, SignatureError> {
+        let point =
+            G1Affine::from_compressed(bytes).map_err(|_| SignatureError::InvalidVerificationKey)?;
+        if point.is_identity() {
+            return Err(SignatureError::InvalidVerificationKey);
+        }
+        Ok(Self {
+            point,
+            bytes: *bytes,
+        })
+    }
+
+    /// Encode this verification key as a compressed point of G1.
+    pub fn to_bytes(&self) -> [u8; 48] {
+        self.bytes
+    }
+
+    /// Check that `signature` is a valid signature on `msg` under this key.
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), SignatureError> {
+        let check = multi_pairing(&[
+            (
+                &self.point,
+                &G2Prepared::from(hash_message(msg).to_affine()),
+            ),
+            (&-G1Affine::GENERATOR, &G2Prepared::from(signature.0)),
+        ]);
+        if check.is_identity() {
+            Ok(())
+        } else {
+            Err(SignatureError::InvalidSignature)
+        }
+    }
+}
+
+impl Signature {
+    /// Parse a signature from the compressed encoding of a point of G2,
+    /// rejecting points outside the prime-order subgroup.
+    pub fn from_bytes(bytes: &[u8; 96]) -> Result<Self, SignatureError> {
+        G2Affine::from_compressed(bytes)
+            .map(Self)
+            .map_err(|_| SignatureError::InvalidSignature)
+    }
+
+    /// Encode this signature as a compressed point of G2.
+    pub fn to_bytes(&self) -> [u8; 96] {
+        self.0.to_compressed()
+    }
+}
+
+/// Aggregate `signatures` into a single signature, their sum.
+///
+/// The aggregate of no signatures is the identity, which
+/// [`verify_aggregate`] never accepts.
+pub fn aggregate(signatures: &[Signature]) -> Signature {
+    let sum: G2Projective = signatures
+        .iter()
+        .map(|signature| G2Projective::from(signature.0))
+        .sum();
+    Signature(sum.to_affine())
+}
+
+/// Check that `signature` is an aggregate of valid signatures on each
+/// `(key, message)` pair in `items`.
+///
+/// This checks `e(g1, sig) = ∏ e(vk_i, H(msg_i))`, computing one Miller loop
+/// per pair and a single final exponentiation. It fails if `items` is empty or
+/// if any message appears more than once, since aggregates over repeated
+/// messages are forgeable with rogue keys.
+pub fn verify_aggregate(
+    items: &[(VerificationKey, &[u8])],
+    signature: &Signature,
+) -> Result<(), SignatureError> {
+    if items.is_empty() {
+        return Err(SignatureError::InvalidSignature);
+    }
+    for (i, (_, msg)) in items.iter().enumerate() {
+        if items[..i].iter().any(|(_, other)| other == msg) {
+            return Err(SignatureError::InvalidSignature);
+        }
+    }
+
+    let mut f = multi_miller_loop(&[(&-G1Affine::GENERATOR, &G2Prepared::from(signature.0))]);
+    for (vk, msg) in items {
+        let h = G2Prepared::from(hash_message(msg).to_affine());
+        f *= multi_miller_loop(&[(&vk.point, &h)]);
+    }
+    if f.final_exponentiation().is_identity() {
+        Ok(())
+    } else {
+        Err(SignatureError::InvalidSignature)
+    }
+}
+
+fn hash_message(msg: &[u8]) -> G2Projective {
+    G2Projective::hash_to_group::<Sha256>(DOMAIN_SEPARATOR, msg)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rand_core::OsRng;
+
+    #[test]
+    fn sign_and_verify() {
+        let sk = SigningKey::new(&mut OsRng);
+        let vk = sk.verification_key();
+        let signature = sk.sign(b"hello");
+
+        assert_eq!(vk.verify(b"hello", &signature), Ok(()));
+        assert_eq!(
+            vk.verify(b"goodbye", &signature),
+            Err(SignatureError::InvalidSignature)
+        );
+        let other = SigningKey::new(&mut OsRng).verification_key();
+        assert_eq!(
+            other.verify(b"hello", &signature),
+            Err(SignatureError::InvalidSignature)
+        );
+
+        assert_eq!(sk.sign(b"hello"), signature);
+    }
+
+    #[test]
+    fn keys_and_signatures_roundtrip() {
+        let sk = SigningKey::new(&mut OsRng);
+        let vk = sk.verification_key();
+        let signature = sk.sign(b"hello");
+
+        let sk2 = SigningKey::from_bytes(&sk.to_bytes()).unwrap();
+        assert_eq!(sk2.verification_key(), vk);
+        assert_eq!(VerificationKey::from_bytes(&vk.to_bytes()), Ok(vk));
+        assert_eq!(Signature::from_bytes(&signature.to_bytes()), Ok(signature));
+    }
+
+    #[test]
+    fn zeroize_clears_signing_key() {
+        let mut sk = SigningKey::new(&mut OsRng);
+        sk.zeroize();
+        assert_eq!(sk.to_bytes(), [0u8; 32]);
+    }
+
+    #[test]
+    fn rejects_degenerate_keys_and_signatures() {
+        assert_eq!(
+            SigningKey::from_bytes(&[0u8; 32]).unwrap_err(),
+            SignatureError::InvalidSigningKey
+        );
+        assert_eq!(
+            SigningKey::from_bytes(&[0xff; 32]).unwrap_err(),
+            SignatureError::InvalidSigningKey
+        );
+        assert_eq!(
+            VerificationKey::from_bytes(&G1Affine::IDENTITY.to_compressed()),
+            Err(SignatureError::InvalidVerificationKey)
+        );
+        assert_eq!(
+            Signature::from_bytes(&[0xff; 96]),
+            Err(SignatureError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn aggregate_verification() {
+        let msgs: [&[u8]; 3] = [b"", b"a", b"bc"];
+        let keys = msgs.map(|_| SigningKey::new(&mut OsRng));
+        let signature = aggregate(&[
+            keys[0].sign(msgs[0]),
+            keys[1].sign(msgs[1]),
+            keys[2].sign(msgs[2]),
+        ]);
+        let mut items = [0, 1, 2].map(|i| (keys[i].verification_key(), msgs[i]));
+
+        assert_eq!(verify_aggregate(&items, &signature), Ok(()));
+        assert_eq!(
+            verify_aggregate(&items[..2], &signature),
+            Err(SignatureError::InvalidSignature)
+        );
+        assert_eq!(
+            verify_aggregate(&[], &aggregate(&[])),
+            Err(SignatureError::InvalidSignature)
+        );
+
+        items[2].1 = b"bd";
+        assert_eq!(
+            verify_aggregate(&items, &signature),
+            Err(SignatureError::InvalidSignature)
+        );
+
+        // Repeated messages are rejected even when the aggregate is valid.
+        let repeated = [
+            (keys[0].verification_key(), msgs[0]),
+            (keys[1].verification_key(), msgs[0]),
+        ];
+        let signature = aggregate(&[keys[0].sign(msgs[0]), keys[1].sign(msgs[0])]);
+        assert_eq!(
+            verify_aggregate(&repeated, &signature),
+            Err(SignatureError::InvalidSignature)
+        );
+    }
+}
@@ -0,0 +1,186 @@
+//! Native BLS12-377 group arithmetic, implemented over this crate's own `Fp`
+//! and available with either field backend.
+//!
+//! Points are compatible with `ark-bls12-377`: the compressed encodings are
+//! the ones produced by its `serialize_compressed`, and scalars are elements
+//! of the BLS12-377 scalar field, which is [`Fq`](crate::Fq).
+//!
+//! The pairing computes the same values as `ark_bls12_377::Bls12_377`, and
+//! needs no allocation. Hashing to G2 with
+//! [`G2Projective::hash_to_group`] matches the arkworks G2 hasher.
+
+mod curve;
+mod g1;
+mod g2;
+mod hash_to_curve;
+mod pairing;
+
+pub use curve::{Affine, BaseField, CurveConfig, Projective};
+pub use g1::{G1Affine, G1Config, G1Projective};
+pub use g2::{G2Affine, G2Config, G2Projective};
+pub use pairing::{multi_miller_loop, multi_pairing, pairing, G2Prepared, Gt, MillerLoopResult};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::{fields::fp2::Fp2, Fp, Fq};
+
+    fn scalars() -> impl Iterator<Item = Fq> {
+        (1..8u64).map(|i| Fq::from(i) * Fq::from(0x1234_5678_9abc_def0u64).square() - Fq::ONE)
+    }
+
+    #[test]
+    fn generators_are_valid() {
+        assert!(G1Affine::GENERATOR.is_on_curve());
+        assert!(G1Affine::GENERATOR.is_torsion_free());
+        assert!(G2Affine::GENERATOR.is_on_curve());
+        assert!(G2Affine::GENERATOR.is_torsion_free());
+    }
+
+    #[test]
+    fn group_laws() {
+        let g = G1Projective::GENERATOR;
+        assert_eq!(g + G1Projective::IDENTITY, g);
+        assert_eq!(g - g, G1Projective::IDENTITY);
+        assert_eq!(g + g, g.double());
+        assert_eq!(G1Projective::IDENTITY.double(), G1Projective::IDENTITY);
+        assert_eq!(g * -Fq::ONE, -g);
+        assert_eq!(g * Fq::ZERO, G1Projective::IDENTITY);
+
+        for (a, b) in scalars().zip(scalars().skip(1)) {
+            assert_eq!(g * a + g * b, g * (a + b));
+            assert_eq!((g * a) * b, g * (a * b));
+            assert_eq!(g * a, g.mul_by_le_limbs(&a.to_le_limbs()));
+
+            let h = G2Projective::GENERATOR;
+            assert_eq!(h * a + h * b, h * (a + b));
+            assert!((h * a).to_affine().is_on_curve());
+        }
+    }
+
+    #[test]
+    fn compressed_encodings_roundtrip() {
+        for point in [G1Projective::IDENTITY, G1Projective::GENERATOR]
+            .into_iter()
+            .chain(scalars().map(|a| G1Projective::GENERATOR * a))
+        {
+            let affine = point.to_affine();
+            assert_eq!(
+                G1Affine::from_compressed(&affine.to_compressed()),
+                Ok(affine)
+            );
+            assert_eq!(
+                G1Affine::from_compressed(&(-affine).to_compressed()),
+                Ok(-affine)
+            );
+        }
+        for point in [G2Projective::IDENTITY, G2Projective::GENERATOR]
+            .into_iter()
+            .chain(scalars().map(|a| G2Projective::GENERATOR * a))
+        {
+            let affine = point.to_affine();
+            assert_eq!(
+                G2Affine::from_compressed(&affine.to_compressed()),
+                Ok(affine)
+            );
+            assert_eq!(
+                G2Affine::from_compressed(&(-affine).to_compressed()),
+                Ok(-affine)
+            );
+        }
+    }
+
+    #[test]
+    fn rejects_invalid_encodings() {
+        let mut both_flags = G1Affine::GENERATOR.to_compressed();
+        both_flags[47] |= 0xc0;
+        assert!(G1Affine::from_compressed(&both_flags).is_err());
+
+        let mut identity = G1Affine::IDENTITY.to_compressed();
+        identity[0] = 1;
+        assert!(G1Affine::from_compressed(&identity).is_err());
+
+        let mut non_canonical = [0u8; 48];
+        non_canonical[..47].fill(0xff);
+        non_canonical[47] = 0x3f;
+        assert!(G1Affine::from_compressed(&non_canonical).is_err());
+
+        // (-1, 0) is a point of order two on G1.
+        let order_two = G1Affine {
+            x: -Fp::ONE,
+            y: Fp::ZERO,
+            infinity: false,
+        };
+        assert!(order_two.is_on_curve());
+        assert!(G1Affine::from_compressed(&order_two.to_compressed()).is_err());
+
+        let mut both_flags = G2Affine::GENERATOR.to_compressed();
+        both_flags[95] |= 0xc0;
+        assert!(G2Affine::from_compressed(&both_flags).is_err());
+
+        // A canonical c0 followed by a c1 above the modulus.
+        let mut non_canonical = [0u8; 96];
+        non_canonical[48..95].fill(0xff);
+        non_canonical[95] = 0x3f;
+        assert!(G2Affine::from_compressed(&non_canonical).is_err());
+
+        let xs = (0..64u64).map(|i| Fp2::new(Fp::from(i), Fp::ONE));
+        let rhs = |x: Fp2| x.square() * x + G2Config::COEFF_B;
+
+        // The compressed encoding only depends on x and the sign of y, so any
+        // y will do for an x that is not on the curve.
+        let off_curve = G2Affine {
+            x: xs.clone().find(|x| rhs(*x).sqrt().is_none()).unwrap(),
+            y: Fp2::ZERO,
+            infinity: false,
+        };
+        assert!(G2Affine::from_compressed(&off_curve.to_compressed()).is_err());
+
+        // The cofactor of G2 is large, so this point is almost surely outside
+        // the subgroup, which the assertion checks.
+        let x = xs.clone().find(|x| rhs(*x).sqrt().is_some()).unwrap();
+        let outside_subgroup = G2Affine {
+            x,
+            y: rhs(x).sqrt().unwrap(),
+            infinity: false,
+        };
+        assert!(outside_subgroup.is_on_curve());
+        assert!(!outside_subgroup.is_torsion_free());
+        assert!(G2Affine::from_compressed(&outside_subgroup.to_compressed()).is_err());
+    }
+
+    #[test]
+    fn pairing_is_bilinear() {
+        let p = G1Projective::GENERATOR;
+        let q = G2Projective::GENERATOR;
+        let e = pairing(&p.to_affine(), &q.to_affine());
+        assert!(!e.is_identity());
+
+        for a in scalars().take(2) {
+            let e_a = pairing(&(p * a).to_affine(), &q.to_affine());
+            assert_eq!(e_a, pairing(&p.to_affine(), &(q * a).to_affine()));
+            assert_ne!(e_a, e);
+            assert_eq!(
+                pairing(&(p * (a + Fq::ONE)).to_affine(), &q.to_affine()),
+                e_a * e
+            );
+        }
+    }
+
+    #[test]
+    fn multi_pairing_products() {
+        let p = G1Projective::GENERATOR.to_affine();
+        let q = G2Prepared::from(G2Affine::GENERATOR);
+        let e = multi_pairing(&[(&p, &q)]);
+
+        assert_eq!(multi_pairing(&[]), Gt::IDENTITY);
+        assert_eq!(multi_pairing(&[(&p, &q), (&-p, &q)]), Gt::IDENTITY);
+        assert_eq!(multi_pairing(&[(&p, &q), (&p, &q)]), e * e);
+        assert_eq!(e * e.inverse(), Gt::IDENTITY);
+
+        let identity = G2Prepared::from(G2Affine::IDENTITY);
+        assert_eq!(multi_pairing(&[(&p, &identity)]), Gt::IDENTITY);
+        assert_eq!(multi_pairing(&[(&G1Affine::IDENTITY, &q)]), Gt::IDENTITY);
+        assert_eq!(multi_pairing(&[(&p, &q), (&G1Affine::IDENTITY, &q)]), e);
+    }
+}
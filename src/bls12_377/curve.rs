@@ -0,0 +1,517 @@
+use core::{
+    fmt::Debug,
+    iter::Sum,
+    marker::PhantomData,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::{fields::fp2::Fp2, EncodingError, Fp, Fq};
+
+/// The arithmetic needed from the base field of a curve.
+pub trait BaseField:
+    Copy
+    + Debug
+    + Eq
+    + Ord
+    + ConditionallySelectable
+    + ConstantTimeEq
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Neg<Output = Self>
+{
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn square(&self) -> Self;
+    fn inverse(&self) -> Option<Self>;
+    fn sqrt(&self) -> Option<Self>;
+    fn write_le_bytes(&self, out: &mut [u8]);
+    fn from_le_bytes_checked(bytes: &[u8]) -> Result<Self, EncodingError>;
+}
+
+impl BaseField for Fp {
+    const ZERO: Self = Fp::ZERO;
+    const ONE: Self = Fp::ONE;
+
+    fn square(&self) -> Self {
+        Fp::square(self)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Fp::inverse(self)
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        Fp::sqrt(self)
+    }
+
+    fn write_le_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.to_bytes());
+    }
+
+    fn from_le_bytes_checked(bytes: &[u8]) -> Result<Self, EncodingError> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| EncodingError::InvalidSliceLength)?;
+        Fp::from_bytes_checked(bytes)
+    }
+}
+
+impl BaseField for Fp2 {
+    const ZERO: Self = Fp2::ZERO;
+    const ONE: Self = Fp2::ONE;
+
+    fn square(&self) -> Self {
+        Fp2::square(self)
+    }
+
+    fn inverse(&self) -> Option<Self> {
+        Fp2::inverse(self)
+    }
+
+    fn sqrt(&self) -> Option<Self> {
+        Fp2::sqrt(self)
+    }
+
+    fn write_le_bytes(&self, out: &mut [u8]) {
+        out.copy_from_slice(&self.to_bytes());
+    }
+
+    fn from_le_bytes_checked(bytes: &[u8]) -> Result<Self, EncodingError> {
+        let bytes = bytes
+            .try_into()
+            .map_err(|_| EncodingError::InvalidSliceLength)?;
+        Fp2::from_bytes_checked(bytes)
+    }
+}
+
+/// The parameters of a short Weierstrass curve `y^2 = x^3 + b`.
+pub trait CurveConfig: Copy + Debug + 'static {
+    type BaseField: BaseField;
+
+    const COEFF_B: Self::BaseField;
+    const GENERATOR_X: Self::BaseField;
+    const GENERATOR_Y: Self::BaseField;
+}
+
+/// A point in affine coordinates.
+#[derive(Copy, Clone, Debug)]
+pub struct Affine<C: CurveConfig> {
+    pub(crate) x: C::BaseField,
+    pub(crate) y: C::BaseField,
+    pub(crate) infinity: bool,
+}
+
+/// A point in homogeneous projective coordinates `(X : Y : Z)`, representing
+/// the affine point `(X / Z, Y / Z)`, with the identity at `(0 : 1 : 0)`.
+#[derive(Copy, Clone, Debug)]
+pub struct Projective<C: CurveConfig> {
+    x: C::BaseField,
+    y: C::BaseField,
+    z: C::BaseField,
+    _config: PhantomData<C>,
+}
+
+/// Set in the last byte of a compressed point if `y` is the larger of `±y`.
+const Y_IS_NEGATIVE_FLAG: u8 = 1 << 7;
+/// Set in the last byte of a compressed point if it is the identity.
+const INFINITY_FLAG: u8 = 1 << 6;
+
+impl<C: CurveConfig> Affine<C> {
+    pub const IDENTITY: Self = Self {
+        x: C::BaseField::ZERO,
+        y: C::BaseField::ONE,
+        infinity: true,
+    };
+
+    pub const GENERATOR: Self = Self {
+        x: C::GENERATOR_X,
+        y: C::GENERATOR_Y,
+        infinity: false,
+    };
+
+    pub fn is_identity(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn is_on_curve(&self) -> bool {
+        self.infinity || self.y.square() == self.x.square() * self.x + C::COEFF_B
+    }
+
+    /// Check that this point lies in the prime-order subgroup.
+    pub fn is_torsion_free(&self) -> bool {
+        // The addition formulas are only complete on the prime-order subgroup.
+        // Outside it they can produce `(0 : 0 : 0)`, which is then preserved
+        // by every later operation, so it must not be mistaken for the identity.
+        let product = Projective::from(*self).mul_by_le_limbs(&Fq::MODULUS_LIMBS);
+        product.z == C::BaseField::ZERO && product.y != C::BaseField::ZERO
+    }
+
+    /// Write the compressed encoding used by `ark-bls12-377`: the
+    /// little-endian encoding of `x`, with flags in the top bits of the last
+    /// byte.
+    pub(crate) fn write_compressed(&self, out: &mut [u8]) {
+        if self.infinity {
+            out.fill(0);
+            out[out.len() - 1] |= INFINITY_FLAG;
+            return;
+        }
+        self.x.write_le_bytes(out);
+        if self.y > -self.y {
+            out[out.len() - 1] |= Y_IS_NEGATIVE_FLAG;
+        }
+    }
+
+    /// Parse the encoding written by `write_compressed`, checking that the
+    /// point is on the curve and in the prime-order subgroup.
+    pub(crate) fn read_compressed(bytes: &[u8]) -> Result<Self, EncodingError> {
+        let (x, flags) = read_with_flags::<C::BaseField>(bytes)?;
+
+        match flags {
+            INFINITY_FLAG if x == C::BaseField::ZERO => Ok(Self::IDENTITY),
+            0 | Y_IS_NEGATIVE_FLAG => {
+                let mut y = (x.square() * x + C::COEFF_B)
+                    .sqrt()
+                    .ok_or(EncodingError::InvalidEncoding)?;
+                if (y > -y) != (flags == Y_IS_NEGATIVE_FLAG) {
+                    y = -y;
+                }
+                let point = Self {
+                    x,
+                    y,
+                    infinity: false,
+                };
+                if point.is_torsion_free() {
+                    Ok(point)
+                } else {
+                    Err(EncodingError::InvalidEncoding)
+                }
+            }
+            _ => Err(EncodingError::InvalidEncoding),
+        }
+    }
+
+    /// Write the uncompressed encoding used by `ark-bls12-377`: the
+    /// little-endian encodings of `x` and `y`, with the same flags as the
+    /// compressed encoding in the last byte.
+    pub(crate) fn write_uncompressed(&self, out: &mut [u8]) {
+        if self.infinity {
+            out.fill(0);
+            out[out.len() - 1] |= INFINITY_FLAG;
+            return;
+        }
+        let (x_out, y_out) = out.split_at_mut(out.len() / 2);
+        self.x.write_le_bytes(x_out);
+        self.y.write_le_bytes(y_out);
+        if self.y > -self.y {
+            out[out.len() - 1] |= Y_IS_NEGATIVE_FLAG;
+        }
+    }
+
+    /// Parse the encoding written by `write_uncompressed`, checking that the
+    /// point is on the curve and in the prime-order subgroup.
+    ///
+    /// Unlike arkworks, this also rejects encodings whose flags do not match
+    /// the coordinates.
+    pub(crate) fn read_uncompressed(bytes: &[u8]) -> Result<Self, EncodingError> {
+        let (x_bytes, y_bytes) = bytes.split_at(bytes.len() / 2);
+        let x = C::BaseField::from_le_bytes_checked(x_bytes)?;
+        let (y, flags) = read_with_flags::<C::BaseField>(y_bytes)?;
+
+        match flags {
+            INFINITY_FLAG if x == C::BaseField::ZERO && y == C::BaseField::ZERO => {
+                Ok(Self::IDENTITY)
+            }
+            0 | Y_IS_NEGATIVE_FLAG if (y > -y) == (flags == Y_IS_NEGATIVE_FLAG) => {
+                let point = Self {
+                    x,
+                    y,
+                    infinity: false,
+                };
+                if point.is_on_curve() && point.is_torsion_free() {
+                    Ok(point)
+                } else {
+                    Err(EncodingError::InvalidEncoding)
+                }
+            }
+            _ => Err(EncodingError::InvalidEncoding),
+        }
+    }
+}
+
+/// Parse a field element whose encoding carries flags in the top bits of its
+/// last byte, returning the element and the flags.
+fn read_with_flags<F: BaseField>(bytes: &[u8]) -> Result<(F, u8), EncodingError> {
+    let flags = bytes[bytes.len() - 1] & (Y_IS_NEGATIVE_FLAG | INFINITY_FLAG);
+    let mut buf = [0u8; 96];
+    let buf = &mut buf[..bytes.len()];
+    buf.copy_from_slice(bytes);
+    buf[bytes.len() - 1] &= !flags;
+    Ok((F::from_le_bytes_checked(buf)?, flags))
+}
+
+impl<C: CurveConfig> PartialEq for Affine<C> {
+    fn eq(&self, other: &Self) -> bool {
+        match (self.infinity, other.infinity) {
+            (true, true) => true,
+            (false, false) => self.x == other.x && self.y == other.y,
+            _ => false,
+        }
+    }
+}
+
+impl<C: CurveConfig> Eq for Affine<C> {}
+
+impl<C: CurveConfig> Neg for Affine<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            y: if self.infinity { self.y } else { -self.y },
+            ..self
+        }
+    }
+}
+
+impl<C: CurveConfig> Default for Affine<C> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<C: CurveConfig> Projective<C> {
+    pub const IDENTITY: Self = Self {
+        x: C::BaseField::ZERO,
+        y: C::BaseField::ONE,
+        z: C::BaseField::ZERO,
+        _config: PhantomData,
+    };
+
+    pub const GENERATOR: Self = Self {
+        x: C::GENERATOR_X,
+        y: C::GENERATOR_Y,
+        z: C::BaseField::ONE,
+        _config: PhantomData,
+    };
+
+    pub fn is_identity(&self) -> bool {
+        self.z == C::BaseField::ZERO
+    }
+
+    pub fn to_affine(&self) -> Affine<C> {
+        match self.z.inverse() {
+            Some(z_inv) => Affine {
+                x: self.x * z_inv,
+                y: self.y * z_inv,
+                infinity: false,
+            },
+            None => Affine::IDENTITY,
+        }
+    }
+
+    fn mul_by_3b(x: C::BaseField) -> C::BaseField {
+        let b3 = C::COEFF_B + C::COEFF_B + C::COEFF_B;
+        x * b3
+    }
+
+    pub fn double(&self) -> Self {
+        // Algorithm 9 of https://eprint.iacr.org/2015/1060, with a = 0.
+        let t0 = self.y.square();
+        let z3 = t0 + t0;
+        let z3 = z3 + z3;
+        let z3 = z3 + z3;
+        let t1 = self.y * self.z;
+        let t2 = Self::mul_by_3b(self.z.square());
+        let x3 = t2 * z3;
+        let y3 = t0 + t2;
+        let z3 = t1 * z3;
+        let t1 = t2 + t2;
+        let t2 = t1 + t2;
+        let t0 = t0 - t2;
+        let y3 = t0 * y3;
+        let y3 = x3 + y3;
+        let t1 = self.x * self.y;
+        let x3 = t0 * t1;
+        let x3 = x3 + x3;
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+            _config: PhantomData,
+        }
+    }
+
+    /// Multiply by a scalar given as public little-endian limbs.
+    ///
+    /// This branches on the bits of the exponent, so it must not be used with
+    /// secret scalars.
+    pub(crate) fn mul_by_le_limbs(&self, limbs: &[u64]) -> Self {
+        let mut acc = Self::IDENTITY;
+        for limb in limbs.iter().rev() {
+            for i in (0..64).rev() {
+                acc = acc.double();
+                if (limb >> i) & 1 == 1 {
+                    acc += *self;
+                }
+            }
+        }
+        acc
+    }
+}
+
+impl<C: CurveConfig> Add for Projective<C> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        // Algorithm 7 of https://eprint.iacr.org/2015/1060, with a = 0. These
+        // formulas are complete on the prime-order subgroup, so they also
+        // handle doubling and the identity.
+        let t0 = self.x * other.x;
+        let t1 = self.y * other.y;
+        let t2 = self.z * other.z;
+        let t3 = self.x + self.y;
+        let t4 = other.x + other.y;
+        let t3 = t3 * t4;
+        let t4 = t0 + t1;
+        let t3 = t3 - t4;
+        let t4 = self.y + self.z;
+        let x3 = other.y + other.z;
+        let t4 = t4 * x3;
+        let x3 = t1 + t2;
+        let t4 = t4 - x3;
+        let x3 = self.x + self.z;
+        let y3 = other.x + other.z;
+        let x3 = x3 * y3;
+        let y3 = t0 + t2;
+        let y3 = x3 - y3;
+        let x3 = t0 + t0;
+        let t0 = x3 + t0;
+        let t2 = Self::mul_by_3b(t2);
+        let z3 = t1 + t2;
+        let t1 = t1 - t2;
+        let y3 = Self::mul_by_3b(y3);
+        let x3 = t4 * y3;
+        let t2 = t3 * t1;
+        let x3 = t2 - x3;
+        let y3 = y3 * t0;
+        let t1 = t1 * z3;
+        let y3 = t1 + y3;
+        let t0 = t0 * t3;
+        let z3 = z3 * t4;
+        let z3 = z3 + t0;
+
+        Self {
+            x: x3,
+            y: y3,
+            z: z3,
+            _config: PhantomData,
+        }
+    }
+}
+
+impl<C: CurveConfig> Neg for Projective<C> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self { y: -self.y, ..self }
+    }
+}
+
+impl<C: CurveConfig> Sub for Projective<C> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self + (-other)
+    }
+}
+
+impl<C: CurveConfig> AddAssign for Projective<C> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<C: CurveConfig> SubAssign for Projective<C> {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl<C: CurveConfig> Mul<Fq> for Projective<C> {
+    type Output = Self;
+
+    /// Multiply by a scalar in constant time.
+    fn mul(self, scalar: Fq) -> Self {
+        let mut acc = Self::IDENTITY;
+        for byte in scalar.to_bytes().iter().rev() {
+            for i in (0..8).rev() {
+                acc = acc.double();
+                let bit = Choice::from((byte >> i) & 1);
+                acc = Self::conditional_select(&acc, &(acc + self), bit);
+            }
+        }
+        acc
+    }
+}
+
+impl<C: CurveConfig> MulAssign<Fq> for Projective<C> {
+    fn mul_assign(&mut self, scalar: Fq) {
+        *self = *self * scalar;
+    }
+}
+
+impl<C: CurveConfig> Sum for Projective<C> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::IDENTITY, Add::add)
+    }
+}
+
+impl<C: CurveConfig> PartialEq for Projective<C> {
+    fn eq(&self, other: &Self) -> bool {
+        self.x * other.z == other.x * self.z && self.y * other.z == other.y * self.z
+    }
+}
+
+impl<C: CurveConfig> Eq for Projective<C> {}
+
+impl<C: CurveConfig> ConditionallySelectable for Projective<C> {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self {
+            x: C::BaseField::conditional_select(&a.x, &b.x, choice),
+            y: C::BaseField::conditional_select(&a.y, &b.y, choice),
+            z: C::BaseField::conditional_select(&a.z, &b.z, choice),
+            _config: PhantomData,
+        }
+    }
+}
+
+impl<C: CurveConfig> Default for Projective<C> {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl<C: CurveConfig> From<Affine<C>> for Projective<C> {
+    fn from(point: Affine<C>) -> Self {
+        if point.infinity {
+            Self::IDENTITY
+        } else {
+            Self {
+                x: point.x,
+                y: point.y,
+                z: C::BaseField::ONE,
+                _config: PhantomData,
+            }
+        }
+    }
+}
+
+impl<C: CurveConfig> From<Projective<C>> for Affine<C> {
+    fn from(point: Projective<C>) -> Self {
+        point.to_affine()
+    }
+}
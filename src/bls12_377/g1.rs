@@ -0,0 +1,62 @@
+use crate::{EncodingError, Fp};
+
+use super::curve::{Affine, CurveConfig, Projective};
+
+/// The configuration of the BLS12-377 G1 curve `y^2 = x^3 + 1` over `Fp`.
+#[derive(Copy, Clone, Debug)]
+pub struct G1Config;
+
+impl CurveConfig for G1Config {
+    type BaseField = Fp;
+
+    const COEFF_B: Fp = Fp::ONE;
+
+    const GENERATOR_X: Fp = Fp::from_montgomery_limbs([
+        2742467569752756724,
+        14217256487979144792,
+        6635299530028159197,
+        8509097278468658840,
+        14518893593143693938,
+        46181716169194829,
+    ]);
+
+    const GENERATOR_Y: Fp = Fp::from_montgomery_limbs([
+        9336971515457667571,
+        28021381849722296,
+        18085035374859187530,
+        14013031479170682136,
+        3369780711397861396,
+        35370409237953649,
+    ]);
+}
+
+pub type G1Affine = Affine<G1Config>;
+pub type G1Projective = Projective<G1Config>;
+
+impl G1Affine {
+    /// Encode this point in the 48-byte compressed format of `ark-bls12-377`.
+    pub fn to_compressed(&self) -> [u8; 48] {
+        let mut bytes = [0u8; 48];
+        self.write_compressed(&mut bytes);
+        bytes
+    }
+
+    /// Decode a point from the 48-byte compressed format of `ark-bls12-377`,
+    /// rejecting points outside the prime-order subgroup.
+    pub fn from_compressed(bytes: &[u8; 48]) -> Result<Self, EncodingError> {
+        Self::read_compressed(bytes)
+    }
+
+    /// Encode this point in the 96-byte uncompressed format of `ark-bls12-377`.
+    pub fn to_uncompressed(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        self.write_uncompressed(&mut bytes);
+        bytes
+    }
+
+    /// Decode a point from the 96-byte uncompressed format of `ark-bls12-377`,
+    /// rejecting points outside the prime-order subgroup.
+    pub fn from_uncompressed(bytes: &[u8; 96]) -> Result<Self, EncodingError> {
+        Self::read_uncompressed(bytes)
+    }
+}
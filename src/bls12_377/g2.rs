@@ -0,0 +1,93 @@
+use crate::{fields::fp2::Fp2, EncodingError, Fp};
+
+use super::curve::{Affine, CurveConfig, Projective};
+
+/// The configuration of the BLS12-377 G2 curve `y^2 = x^3 + b'` over `Fp2`,
+/// a D-type sextic twist of G1 with `b' = 1 / u`.
+#[derive(Copy, Clone, Debug)]
+pub struct G2Config;
+
+impl CurveConfig for G2Config {
+    type BaseField = Fp2;
+
+    const COEFF_B: Fp2 = Fp2::new(
+        Fp::ZERO,
+        Fp::from_montgomery_limbs([
+            9255502405446297221,
+            10229180150694123945,
+            9215585410771530959,
+            13357015519562362907,
+            5437107869987383107,
+            16259554076827459,
+        ]),
+    );
+
+    const GENERATOR_X: Fp2 = Fp2::new(
+        Fp::from_montgomery_limbs([
+            7534593107747697243,
+            7390176809662624395,
+            16990527120569264207,
+            2168572232730518502,
+            9443417493680878057,
+            109821976444144002,
+        ]),
+        Fp::from_montgomery_limbs([
+            6846220294590070585,
+            17925825951095956135,
+            15355657819052935248,
+            16808496983586309946,
+            18438381910454061441,
+            78904498268135389,
+        ]),
+    );
+
+    const GENERATOR_Y: Fp2 = Fp2::new(
+        Fp::from_montgomery_limbs([
+            15398259615690998543,
+            413927750809907693,
+            6945668964135547374,
+            3622202639115414553,
+            11542235856284301842,
+            111174645670174930,
+        ]),
+        Fp::from_montgomery_limbs([
+            6296061721506977525,
+            16832990956758385678,
+            2538166719760928425,
+            9449086974571632418,
+            3122185334549858583,
+            25052933797626130,
+        ]),
+    );
+}
+
+pub type G2Affine = Affine<G2Config>;
+pub type G2Projective = Projective<G2Config>;
+
+impl G2Affine {
+    /// Encode this point in the 96-byte compressed format of `ark-bls12-377`.
+    pub fn to_compressed(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        self.write_compressed(&mut bytes);
+        bytes
+    }
+
+    /// Decode a point from the 96-byte compressed format of `ark-bls12-377`,
+    /// rejecting points outside the prime-order subgroup.
+    pub fn from_compressed(bytes: &[u8; 96]) -> Result<Self, EncodingError> {
+        Self::read_compressed(bytes)
+    }
+
+    /// Encode this point in the 192-byte uncompressed format of `ark-bls12-377`.
+    pub fn to_uncompressed(&self) -> [u8; 192] {
+        let mut bytes = [0u8; 192];
+        self.write_uncompressed(&mut bytes);
+        bytes
+    }
+
+    /// Decode a point from the 192-byte uncompressed format of `ark-bls12-377`,
+    /// rejecting points outside the prime-order subgroup.
+    pub fn from_uncompressed(bytes: &[u8; 192]) -> Result<Self, EncodingError> {
+        Self::read_uncompressed(bytes)
+    }
+}
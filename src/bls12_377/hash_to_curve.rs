@@ -0,0 +1,2438 @@
+use digest::{crypto_common::BlockSizeUser, Digest};
+
+use crate::{fields::fp2::Fp2, hash_to_field::hash_to_fp2, Fp};
+
+use super::g2::{G2Affine, G2Projective};
+
+const fn fp2(c0: [u64; 6], c1: [u64; 6]) -> Fp2 {
+    Fp2::new(Fp::from_montgomery_limbs(c0), Fp::from_montgomery_limbs(c1))
+}
+
+/// The coefficient `A'` of the curve `E': y^2 = x^3 + A' x + B'`, which is
+/// isogenous to G2 and has `A' B' != 0`, as the simplified SWU map requires.
+const ISO_A: Fp2 = fp2(
+    [
+        4274545572028848265,
+        14157081418478689358,
+        13123833976752631407,
+        4466041663276938746,
+        9062541850312583986,
+        90030181981586611,
+    ],
+    [
+        4627353644986202063,
+        14941155654691983603,
+        14266958733709189881,
+        10264689865410103271,
+        10052798319587953375,
+        111844286035220969,
+    ],
+);
+
+/// The coefficient `B'` of `E'`.
+const ISO_B: Fp2 = fp2(
+    [
+        10237434857876739089,
+        8476639787604822147,
+        6641637803208190023,
+        1721529389316620686,
+        8656544759275761743,
+        38999476160258021,
+    ],
+    [
+        2360755569119276357,
+        10390833517265838837,
+        12467133771585386911,
+        8219721226907645480,
+        3130947551623757939,
+        83517800164149569,
+    ],
+);
+
+/// The non-square `Z = 12 + u` used by the simplified SWU map.
+const Z: Fp2 = fp2(
+    [
+        10560307807486212317,
+        9936456306313395274,
+        2092561269709285211,
+        8738829082964617622,
+        5243865315912343348,
+        114311569748804731,
+    ],
+    [
+        202099033278250856,
+        5854854902718660529,
+        11492539364873682930,
+        8885205928937022213,
+        5545221690922665192,
+        39800542322357402,
+    ],
+);
+
+/// The coefficients of the isogeny map from `E'` to G2, from the constant
+/// term up, as in arkworks.
+const X_NUM: [Fp2; 24] = [
+    fp2(
+        [
+            3551783286045471771,
+            15672698349814166255,
+            7201714524012399751,
+            9685135133462022557,
+            11459791422433132438,
+            34279211894444158,
+        ],
+        [
+            6733784119909728882,
+            8027365598504339614,
+            9826395261252013434,
+            17766961605401961078,
+            16718790361550578585,
+            8827310132881948,
+        ],
+    ),
+    fp2(
+        [
+            10039326048219096853,
+            8025585753053690704,
+            15692757884719051200,
+            14081267914923412694,
+            12483400733579637594,
+            41501995942887693,
+        ],
+        [
+            3285050121746765179,
+            16424976010740556635,
+            13525960835401060630,
+            8230390587856081588,
+            332312595686915068,
+            58693565636984574,
+        ],
+    ),
+    fp2(
+        [
+            17569899329826403508,
+            9637548884483664645,
+            11559603533194429416,
+            509473447889017775,
+            16843450937194425803,
+            11820413515158522,
+        ],
+        [
+            18082143759519379917,
+            7350976797508953919,
+            8718515184478260262,
+            11424641547646470649,
+            12610734036362352161,
+            109931236078585740,
+        ],
+    ),
+    fp2(
+        [
+            14344095877598929896,
+            14770204065590636976,
+            13870643972022067464,
+            14327299415926938990,
+            2100218127689809229,
+            111985606372347998,
+        ],
+        [
+            18166912950538149334,
+            7903342274102247275,
+            11235613814926762637,
+            6048582781848067329,
+            9865957781737849443,
+            72257053590751229,
+        ],
+    ),
+    fp2(
+        [
+            9289693438943775070,
+            611273684478921638,
+            3161020385710416475,
+            2286858861996231773,
+            14631078872284738786,
+            101751379483637100,
+        ],
+        [
+            218371821390517888,
+            3239557307852648611,
+            7956123978234311251,
+            4433191957359481551,
+            7602230667186897987,
+            36183482319693410,
+        ],
+    ),
+    fp2(
+        [
+            7524026848147356443,
+            15899508093607509001,
+            9269755160329214834,
+            12526651159477248728,
+            3756002781809695765,
+            101164747683440023,
+        ],
+        [
+            6220885675233469595,
+            1070578225745722143,
+            9028649589122227273,
+            241265531361573072,
+            17722097355684345537,
+            59426317598728635,
+        ],
+    ),
+    fp2(
+        [
+            1147337684740884862,
+            11995598217907408439,
+            6334092051568104396,
+            4490620767408292574,
+            16484486820571077628,
+            32056264099725884,
+        ],
+        [
+            1626504798254052117,
+            15843967556282260399,
+            3222912561813979091,
+            1092398256542226755,
+            15613117580878270463,
+            35759440311789519,
+        ],
+    ),
+    fp2(
+        [
+            9456818710433496904,
+            8738575976265225627,
+            17481785322204872502,
+            10252666647792359788,
+            13007033241788380867,
+            54062177864773120,
+        ],
+        [
+            7857657292788377886,
+            18159261954362575139,
+            6957043223229770024,
+            101887390030524213,
+            7411469664095682342,
+            17462521019270966,
+        ],
+    ),
+    fp2(
+        [
+            5935437080311313022,
+            4869272223104979307,
+            14892623520420170949,
+            6274040211921387638,
+            190450687675494048,
+            100326942952217018,
+        ],
+        [
+            4080141112519796596,
+            14389619698824585061,
+            15572076811388758383,
+            5573262239497616930,
+            5714216936392065098,
+            10910945353280475,
+        ],
+    ),
+    fp2(
+        [
+            12050383421129197508,
+            7679000367015579641,
+            4181895471589351098,
+            4488864303009131705,
+            1755297417051974713,
+            33780283701258651,
+        ],
+        [
+            11393221592097145268,
+            10835918896706663346,
+            4746287363995169177,
+            6169630568067228482,
+            11259555703140136465,
+            52771734216395635,
+        ],
+    ),
+    fp2(
+        [
+            4577617767559140265,
+            568504000295320317,
+            4739467389388831592,
+            9537638546299567945,
+            11669151734363428413,
+            119097732794758712,
+        ],
+        [
+            5691235310984308451,
+            13601281267571106288,
+            7819292534586961743,
+            11652278682059157182,
+            2231701566242469994,
+            110966193728844398,
+        ],
+    ),
+    fp2(
+        [
+            8957158313166077105,
+            5669155713645675267,
+            17333896861619738764,
+            4892240835897020648,
+            8492829473755858526,
+            70767002641220602,
+        ],
+        [
+            8355305174234995113,
+            16726408794609999189,
+            6819868380250046496,
+            9302398329327482182,
+            3371252083110614225,
+            32903157732774708,
+        ],
+    ),
+    fp2(
+        [
+            3562726361589542410,
+            15642076536163677636,
+            3828009540728039550,
+            5513538145598570884,
+            1202255355797680138,
+            19209968154720986,
+        ],
+        [
+            2433342667414904247,
+            14430646666116795259,
+            7881503288178112304,
+            10086912537277758921,
+            14321155618236575013,
+            84224128485096326,
+        ],
+    ),
+    fp2(
+        [
+            7543845460144717594,
+            14101022520017632284,
+            6419288061290617259,
+            11326768478076341964,
+            18432026940412127132,
+            85359846879027793,
+        ],
+        [
+            17142157839755119212,
+            5159976764710014065,
+            6393633352893279233,
+            8672509349035392886,
+            7859431465567482570,
+            62163334379266093,
+        ],
+    ),
+    fp2(
+        [
+            2954881934412060022,
+            10898916794193723070,
+            10915833338735408379,
+            703491535562714344,
+            14589964434689495439,
+            90051679935520087,
+        ],
+        [
+            18417998714611085978,
+            11039054240270137506,
+            9972891257774348246,
+            13263552626589315210,
+            4522668809484918556,
+            63885995997825101,
+        ],
+    ),
+    fp2(
+        [
+            9864134077459603571,
+            12525567282624341376,
+            5205345924937688700,
+            12649124091757575439,
+            14636003906379491067,
+            96025183135833306,
+        ],
+        [
+            10316403063861314867,
+            10750711173072110600,
+            4513675589861596212,
+            2635673197095740125,
+            16720951430549947037,
+            31390298180493148,
+        ],
+    ),
+    fp2(
+        [
+            3003952238586646516,
+            3250841642932517220,
+            7061834734009117554,
+            16259415476002355236,
+            1626926148029432162,
+            77189086665928784,
+        ],
+        [
+            14450051585993059472,
+            11823315281584113748,
+            6929304997500454604,
+            15344892292748160673,
+            15787768514545706202,
+            69813057053620435,
+        ],
+    ),
+    fp2(
+        [
+            4228110392233474072,
+            18010581486575392207,
+            13699345898545483049,
+            17704744337446085874,
+            5487913134051621310,
+            112205105149111207,
+        ],
+        [
+            14824585485470590037,
+            7637124239293942071,
+            1041102438278370701,
+            4207950142422409777,
+            9639439542389544094,
+            106832755917361143,
+        ],
+    ),
+    fp2(
+        [
+            10744145886747796703,
+            8741979218876153119,
+            4537642647264646058,
+            9645243825719833866,
+            16643801652280184094,
+            57528843479739428,
+        ],
+        [
+            5785935421797206370,
+            847985697064985249,
+            11478142027129258160,
+            12741057482356268413,
+            10148591557028647803,
+            106797667835022069,
+        ],
+    ),
+    fp2(
+        [
+            6454832842704992412,
+            9977685523482313420,
+            413111938383295655,
+            13594952949000658414,
+            17807487788385728582,
+            108984464456649540,
+        ],
+        [
+            17048359867690489456,
+            2859252502285541521,
+            10818623701995947153,
+            7550564651763690537,
+            459333235977057482,
+            37663478480017988,
+        ],
+    ),
+    fp2(
+        [
+            8658783637463168087,
+            13532094408327228336,
+            12875492560745818922,
+            6809856611120372218,
+            2853890452345629690,
+            87034840621524077,
+        ],
+        [
+            16301307707120111515,
+            9104654412985804807,
+            12475785366789695268,
+            4024450921325678435,
+            5626908908910088752,
+            56687303925216568,
+        ],
+    ),
+    fp2(
+        [
+            9729408098285946449,
+            14379177692720606450,
+            15077053755146607368,
+            1299576162980711320,
+            13990524355831736308,
+            88070216003513722,
+        ],
+        [
+            5766436769337206504,
+            1093294963802231624,
+            1118315683159380206,
+            16238633443172785277,
+            5463682062430385614,
+            116508026946667814,
+        ],
+    ),
+    fp2(
+        [
+            16584249698770136319,
+            8326278446225484560,
+            3131917383401484830,
+            4756633391835977312,
+            7642636045510739113,
+            6547192373501023,
+        ],
+        [
+            10803045440816594384,
+            6133821182275761752,
+            7762705812675926549,
+            15035799326051880159,
+            10201360843527298396,
+            76693252438359195,
+        ],
+    ),
+    fp2(
+        [
+            3621702609341817994,
+            1179514540952803843,
+            9695126383219869545,
+            4861853798003230532,
+            15648444733987506481,
+            103088924877589738,
+        ],
+        [0, 0, 0, 0, 0, 0],
+    ),
+];
+
+const X_DEN: [Fp2; 23] = [
+    fp2(
+        [
+            1063048776114699222,
+            13419136991291290443,
+            17670140655952814712,
+            17007170270485437006,
+            2714055472280753035,
+            14919040757258909,
+        ],
+        [
+            16666868668043867029,
+            10413023948165423527,
+            2513282340300795191,
+            5135056029366772344,
+            18074639060500180041,
+            66260525510488187,
+        ],
+    ),
+    fp2(
+        [
+            10940415603117103310,
+            2845175453419864190,
+            16971099885047235913,
+            4517542951822462583,
+            12015180195047358700,
+            101962474939260879,
+        ],
+        [
+            1812172074217037271,
+            9040376510937171745,
+            1548369375982775200,
+            5323713323104515663,
+            11625954855074087334,
+            4220230277540083,
+        ],
+    ),
+    fp2(
+        [
+            8797262280793960976,
+            17802450974932240081,
+            10157003283304584770,
+            593225980605733121,
+            14184353532500093055,
+            108979035901112461,
+        ],
+        [
+            15554837140147424903,
+            1512405638789788986,
+            3262333938884419786,
+            4638305243452530609,
+            139542405126620667,
+            54024907639584882,
+        ],
+    ),
+    fp2(
+        [
+            11424716963860540790,
+            13456329776215064369,
+            1929697779149270213,
+            6306593112502705131,
+            13859057472975507250,
+            41785102954052035,
+        ],
+        [
+            7602541488494927932,
+            4897344463908346766,
+            17005994653424089316,
+            17216735712046963938,
+            3756018940504096168,
+            5402674048264985,
+        ],
+    ),
+    fp2(
+        [
+            2416535722849524790,
+            4831233808757576698,
+            17297774284520319797,
+            6772473332127607735,
+            18174962172090050489,
+            58994994344686536,
+        ],
+        [
+            15610410799617712282,
+            9543692017702705957,
+            8970286522052539731,
+            12714219237879632746,
+            2449975609997710437,
+            10121059733973512,
+        ],
+    ),
+    fp2(
+        [
+            15388608950644940213,
+            15178518612850901922,
+            4663566445208062329,
+            3999751164791233677,
+            7358895960008222342,
+            33044898311505685,
+        ],
+        [
+            2356569524239497521,
+            4634121806982763800,
+            13294827248503187097,
+            18379906191200424608,
+            13949207972645393879,
+            37350653623873291,
+        ],
+    ),
+    fp2(
+        [
+            4970487283568561993,
+            8451944303783250587,
+            10744185545939488302,
+            297910826254460501,
+            5663064319185246782,
+            55864728573479562,
+        ],
+        [
+            13676619861655804765,
+            9740179349809417716,
+            1447466405783296044,
+            7262347140551810932,
+            4460517809397706328,
+            48696693541469882,
+        ],
+    ),
+    fp2(
+        [
+            4218670501850515232,
+            1300837062036343562,
+            6322288902222626865,
+            2517640049285419442,
+            15933997662514683752,
+            35742655751559900,
+        ],
+        [
+            8671399843829082486,
+            10727571055999201132,
+            18066412728811459184,
+            2077243532292929295,
+            18402906919639961112,
+            11053564656556137,
+        ],
+    ),
+    fp2(
+        [
+            13549293427847064273,
+            13454861990004702789,
+            12625716182603551974,
+            16289223817658875114,
+            709786698748164395,
+            79373748066056979,
+        ],
+        [
+            9755261456181601166,
+            8062867867952015070,
+            11667298511884909423,
+            12783693965971962594,
+            5335701901003645771,
+            118924945769569072,
+        ],
+    ),
+    fp2(
+        [
+            5024330816113629597,
+            9162185537450801251,
+            14637535063833510048,
+            5042964231794706299,
+            10987285991634226322,
+            46336617111585333,
+        ],
+        [
+            17451698037621354790,
+            10238258568486351103,
+            10306732172443683782,
+            16783673474705190959,
+            2366024509224094980,
+            27102949281362657,
+        ],
+    ),
+    fp2(
+        [
+            1779719694787807439,
+            13682444363499832102,
+            319805876265464201,
+            10878143779945294209,
+            5260570358490406259,
+            111384964548942529,
+        ],
+        [
+            10364815279125342799,
+            5955681787042171859,
+            16259776976357711022,
+            698420282628335564,
+            14458917752432688041,
+            9951241155641633,
+        ],
+    ),
+    fp2(
+        [
+            9860169129499874274,
+            4710132861939245083,
+            16729683163954203081,
+            1163919786098698325,
+            16999533791398931846,
+            45007426249965870,
+        ],
+        [
+            1554156753268998897,
+            13001788039766734041,
+            13083055690099472212,
+            15457335577166095794,
+            9225717367159961098,
+            40091861438122274,
+        ],
+    ),
+    fp2(
+        [
+            15170394446649694794,
+            515688257282406708,
+            7546053921359572147,
+            2040402108618036352,
+            14910633907967741865,
+            51620635462170312,
+        ],
+        [
+            14097816726424606264,
+            12505549891408832791,
+            13993079436736795338,
+            10172463092702817360,
+            608991345474995671,
+            70034669163571313,
+        ],
+    ),
+    fp2(
+        [
+            4643108199199003526,
+            15736620484932148216,
+            15681664113334307244,
+            17299685843716562967,
+            13906356132799386736,
+            93346316071232156,
+        ],
+        [
+            4605618257264761423,
+            18021103018327472901,
+            16108946406338519358,
+            9045268489748282166,
+            5059321869053749600,
+            110536556169650965,
+        ],
+    ),
+    fp2(
+        [
+            11517108407780568371,
+            7978619811691229504,
+            9264608640258152436,
+            8451802924690124465,
+            4675285626878377699,
+            109463398958344863,
+        ],
+        [
+            3597960821476001565,
+            3759680844169152876,
+            14302414818654496990,
+            17433096654117785124,
+            17967041042193057544,
+            85366167313641495,
+        ],
+    ),
+    fp2(
+        [
+            9939822412604413185,
+            13452127979624463736,
+            5130248585009642508,
+            3885932386715663181,
+            5051687816649505884,
+            77901780572240613,
+        ],
+        [
+            519763984047258436,
+            9600915485628319569,
+            15140529797299450996,
+            9971542775239334883,
+            8807015558507490608,
+            948222705021672,
+        ],
+    ),
+    fp2(
+        [
+            2827339587428175511,
+            6895834130469522434,
+            13331059522798479027,
+            13955187059915735579,
+            14378880524037078149,
+            37849146151734053,
+        ],
+        [
+            5659129353366385472,
+            1315670479643534676,
+            5263307416551198333,
+            6925817959331280727,
+            2077163856293267360,
+            78059303625906606,
+        ],
+    ),
+    fp2(
+        [
+            12063007979636530410,
+            11856928215255593909,
+            11875394835884852884,
+            7779285203935234969,
+            6621016507099994054,
+            58081312732574672,
+        ],
+        [
+            16883486087598545310,
+            13020319043706888256,
+            13790070886127375971,
+            1584348143126996741,
+            14269751476430075034,
+            39520851290632316,
+        ],
+    ),
+    fp2(
+        [
+            10496651096160116529,
+            17447330314090714314,
+            7901980568892205616,
+            12523643767284114259,
+            14047697587913173436,
+            75967257029771325,
+        ],
+        [
+            928850053641029162,
+            14269106311960337185,
+            16222409674828338261,
+            5805191570224440459,
+            18405225593952050465,
+            81611267238126250,
+        ],
+    ),
+    fp2(
+        [
+            11963245856892367752,
+            10425756598646938656,
+            18335238837348744770,
+            15086772981218061512,
+            16514870314421827966,
+            31505685309500160,
+        ],
+        [
+            12503518650914401765,
+            9253053408485518576,
+            7574198415595259890,
+            9578781703355827570,
+            4997060354564847196,
+            4129967821132897,
+        ],
+    ),
+    fp2(
+        [
+            2031421031641935199,
+            11357963626711833272,
+            3216176135850906142,
+            18356175605205399499,
+            14930266308542898024,
+            45166487627178747,
+        ],
+        [
+            4224679465464892278,
+            5162816687557914011,
+            9625001313214472999,
+            9646089844316603379,
+            3042432097393437616,
+            116654205222221531,
+        ],
+    ),
+    fp2(
+        [
+            707631102761073363,
+            4675040476129639901,
+            7917747947488915690,
+            10109499984363985170,
+            8993410474198507338,
+            72712009800189820,
+        ],
+        [
+            13149412355826661761,
+            13738163990683470943,
+            2035497293527985654,
+            18119267602401018212,
+            2631647798265895027,
+            2795783216290358,
+        ],
+    ),
+    fp2(
+        [
+            202099033278250856,
+            5854854902718660529,
+            11492539364873682930,
+            8885205928937022213,
+            5545221690922665192,
+            39800542322357402,
+        ],
+        [0, 0, 0, 0, 0, 0],
+    ),
+];
+
+const Y_NUM: [Fp2; 34] = [
+    fp2(
+        [
+            17926225976816550695,
+            14023720841551579195,
+            6357178813752170559,
+            1429363592569423041,
+            10398153225153858948,
+            33711798988254397,
+        ],
+        [
+            2451854115792992988,
+            8983506616720050336,
+            2910564589437158732,
+            14075622914381394491,
+            9210830493684175792,
+            3356683084380210,
+        ],
+    ),
+    fp2(
+        [
+            5363401642911389912,
+            1675875184098052872,
+            15320530934239994732,
+            16287584047609698426,
+            10234580471621189795,
+            40152057551620421,
+        ],
+        [
+            2205557896318444105,
+            4065007897235623968,
+            4049591920488634456,
+            16004168804099107709,
+            13290522222905374988,
+            70089339901218536,
+        ],
+    ),
+    fp2(
+        [
+            1887285394871164447,
+            7142690945990485012,
+            11190732658400833066,
+            6881717282640585612,
+            12116088968278807379,
+            103071704289665226,
+        ],
+        [
+            15968649428224979513,
+            622837296484333259,
+            15527184444320989860,
+            2221190225062639140,
+            16647213690505955661,
+            110449865605275384,
+        ],
+    ),
+    fp2(
+        [
+            8732294600695513394,
+            7924365878303510912,
+            12588020556237861366,
+            10068029016225118132,
+            785053188876688927,
+            120347068207419939,
+        ],
+        [
+            14514622768737464893,
+            5553264648606662629,
+            12707457796658055665,
+            6066303778837734141,
+            8279024849745683367,
+            3564406469625657,
+        ],
+    ),
+    fp2(
+        [
+            10035254538193759291,
+            9758866910217654439,
+            2724217928072676653,
+            3087232989313988901,
+            14980280964270815877,
+            34590022796322467,
+        ],
+        [
+            15702474365778911803,
+            7256912742043165018,
+            1566344877835261304,
+            4035729625101537726,
+            16346625826270990512,
+            84087754076446931,
+        ],
+    ),
+    fp2(
+        [
+            8353555275402615800,
+            6017666668033757053,
+            13076202439893933084,
+            1913164921831891521,
+            13296563472388407395,
+            43485447354470561,
+        ],
+        [
+            12571296156797641372,
+            13988198528186614994,
+            5672291450663514913,
+            7033607850615758657,
+            16846880911077910300,
+            44471894806759326,
+        ],
+    ),
+    fp2(
+        [
+            1319289899879823718,
+            1591088360172441240,
+            7805677496653365276,
+            5569897284498525554,
+            5635591756513279404,
+            74901024143303203,
+        ],
+        [
+            10251017843654697682,
+            10395107644570958416,
+            3071938417266745181,
+            16127666859700570668,
+            5111665418914357408,
+            2168588371926498,
+        ],
+    ),
+    fp2(
+        [
+            7692831217085618076,
+            6702179445852008930,
+            11308520252707392151,
+            12038365346701529390,
+            7201289518723110646,
+            24757234241788495,
+        ],
+        [
+            17948859968330001914,
+            10144279887227452625,
+            5167544611537672341,
+            4094514978127885079,
+            17912079766649616973,
+            45691468580901020,
+        ],
+    ),
+    fp2(
+        [
+            3911440855998194160,
+            13198838254872822648,
+            13301641437413064797,
+            11598620320741753383,
+            6260523097595092689,
+            78284230929812985,
+        ],
+        [
+            7876366075466990529,
+            13523468790275433139,
+            982512625724968021,
+            2122595334378906057,
+            14803785928219626498,
+            71415583741911053,
+        ],
+    ),
+    fp2(
+        [
+            14923926944529083273,
+            3172840012894527710,
+            8293434476071337387,
+            16713256045335958267,
+            14057165773167995662,
+            57626475506313562,
+        ],
+        [
+            1362417042314121750,
+            3304417561071796103,
+            17626406775105189491,
+            11748219015558348173,
+            14151421547261463616,
+            82460535821434588,
+        ],
+    ),
+    fp2(
+        [
+            3641967463568066103,
+            8959416625663108732,
+            5147508997828480363,
+            16811352377666989046,
+            1441182181356889676,
+            52012247235371457,
+        ],
+        [
+            17686146190561162997,
+            11457769513035421935,
+            11629039572857129752,
+            16013548565551254584,
+            3489389447040062088,
+            108111902919606578,
+        ],
+    ),
+    fp2(
+        [
+            3810472718885394006,
+            2981020169366520013,
+            2816385682378197235,
+            153866317221550159,
+            11847618666936524337,
+            45551005605881971,
+        ],
+        [
+            3468095351662354134,
+            4642197759139584052,
+            4233923780028877603,
+            17495173774535575422,
+            6042084483130094534,
+            67695926977382460,
+        ],
+    ),
+    fp2(
+        [
+            1324953063484934482,
+            15924484690572898301,
+            11397027945012096698,
+            2650928770069584769,
+            15779309286157100138,
+            107280353846472153,
+        ],
+        [
+            7825813464319090460,
+            9031312139767459777,
+            142189155675192148,
+            6582610291000324889,
+            17102396003811978688,
+            22084094988625284,
+        ],
+    ),
+    fp2(
+        [
+            5210064249141309135,
+            1705543797894948713,
+            2628665872375881350,
+            13536211673960271710,
+            18420806283098729881,
+            115824256193502087,
+        ],
+        [
+            15082907751180860242,
+            6568697878451242210,
+            16244491021091296231,
+            17790448340613041754,
+            7733097786923840729,
+            14504831353209381,
+        ],
+    ),
+    fp2(
+        [
+            1708526764431582271,
+            13958279355051552323,
+            14990211071762970223,
+            7884242526085975541,
+            16332397605884981291,
+            80533848446738849,
+        ],
+        [
+            14979957683309612745,
+            6077136825354362762,
+            8344075647158254715,
+            10162044015152162839,
+            5892600246835629906,
+            87516868768986919,
+        ],
+    ),
+    fp2(
+        [
+            13823384149985104375,
+            400062563487168190,
+            14946453239900597346,
+            3063239780002983931,
+            12307299790663558215,
+            82575345472847758,
+        ],
+        [
+            4955387593349539956,
+            11580263215419679285,
+            13810204272372323220,
+            15118104627613044122,
+            5709821153764726112,
+            97792497530186865,
+        ],
+    ),
+    fp2(
+        [
+            8029106480647627463,
+            9339835209992361362,
+            2257747803917390435,
+            13841766612482081060,
+            11846105036367819521,
+            10985089103756089,
+        ],
+        [
+            12896564185217823813,
+            11446288085903243988,
+            7990576940036837900,
+            3323202217483830000,
+            8230734762253073878,
+            36590510406546600,
+        ],
+    ),
+    fp2(
+        [
+            9258620980666570289,
+            14799769271847025124,
+            3337623733744503313,
+            7847288847664005088,
+            4443979768963902018,
+            60018570132140318,
+        ],
+        [
+            2675246192261018596,
+            15150250319428322656,
+            8044595947793549351,
+            17819017498503740634,
+            8168003399719773701,
+            50802205070212383,
+        ],
+    ),
+    fp2(
+        [
+            6416675551757596528,
+            11137110755893373387,
+            12196234615749865580,
+            10261229930898283794,
+            16443034629854739148,
+            92762661836396101,
+        ],
+        [
+            2617757450501858457,
+            13481841765161333192,
+            14399351126797435540,
+            14845648777279341476,
+            16479218442290092360,
+            58694504406226241,
+        ],
+    ),
+    fp2(
+        [
+            2498292483400110656,
+            17577335584861186499,
+            17782334663901894811,
+            2829649086165738601,
+            1498570879458573752,
+            54126946483698861,
+        ],
+        [
+            13664305368410221366,
+            15519373467383236285,
+            6930373704919785768,
+            5035259077705758702,
+            15118622066815350587,
+            76218461077450180,
+        ],
+    ),
+    fp2(
+        [
+            2359464294290896887,
+            15126005627433822176,
+            8745471049496239338,
+            16249169944251666409,
+            5887779353961924474,
+            9756279022859315,
+        ],
+        [
+            4564346538121733135,
+            7083224900235365477,
+            12708889282498498077,
+            7850548098138279688,
+            14178174888234998222,
+            79663491154927524,
+        ],
+    ),
+    fp2(
+        [
+            2363371748841007386,
+            8201543480932182326,
+            3606722496785934427,
+            11335897361905574349,
+            7238564335142183540,
+            28370763379089425,
+        ],
+        [
+            17773120842166679454,
+            6968817275085296949,
+            589078002303466881,
+            11901496965136831973,
+            17508389603594408067,
+            105726710734816064,
+        ],
+    ),
+    fp2(
+        [
+            7295712044514929446,
+            1419749005841863626,
+            15921158613862149232,
+            9464988326326595083,
+            4254037448365833139,
+            69144288462579473,
+        ],
+        [
+            2330521880128496868,
+            6942731841460529291,
+            16753201799444057524,
+            5125438220420299042,
+            743707329901356982,
+            86418732453789215,
+        ],
+    ),
+    fp2(
+        [
+            10073672469351267894,
+            7530218602938011234,
+            12150950127635720924,
+            14699101300080070173,
+            3657462378365608060,
+            50677269975209252,
+        ],
+        [
+            16217326474471800173,
+            12182932688121705224,
+            9523557196391803719,
+            13559107473982584173,
+            14264388955497449506,
+            89155196038187526,
+        ],
+    ),
+    fp2(
+        [
+            8500524953883552338,
+            13471246252006481381,
+            399342016929624192,
+            15321780621361720165,
+            1735807610194144505,
+            81584295376527434,
+        ],
+        [
+            3028140852425956403,
+            9738549527114127103,
+            13145547273810927,
+            15439064114192138046,
+            15332022320720552951,
+            84308032823666865,
+        ],
+    ),
+    fp2(
+        [
+            8795699974103596314,
+            642987394252844125,
+            12559698238980671421,
+            15439596853334509309,
+            8192837603484177265,
+            86858193154220713,
+        ],
+        [
+            13665577425741482529,
+            6924526015867702055,
+            8773433633434605845,
+            4385776193759960181,
+            14751123844375383386,
+            84259094466106596,
+        ],
+    ),
+    fp2(
+        [
+            6308743764871173820,
+            14529376135552483358,
+            3992864522868188832,
+            16018150786687814926,
+            14942376479240309869,
+            30721880050281254,
+        ],
+        [
+            8640235552523037016,
+            9835096537876469025,
+            789797926152341591,
+            17554386444425767744,
+            2184317346571194421,
+            105569965705365467,
+        ],
+    ),
+    fp2(
+        [
+            10895141504918788686,
+            5671269070898752172,
+            9721970862384110947,
+            6958416614840799556,
+            18087813302866953828,
+            120526621462965167,
+        ],
+        [
+            4966785024859028542,
+            18182687130036955400,
+            10092534947477547130,
+            11367367723010839926,
+            7417818378683193783,
+            94369912047147779,
+        ],
+    ),
+    fp2(
+        [
+            5709922971951703245,
+            10382852257937442526,
+            3453842328747730539,
+            16133368957829378910,
+            8271517063962590774,
+            9539719803485949,
+        ],
+        [
+            1704900793320796784,
+            8995901783485359023,
+            9994857694012530400,
+            7408202244508772902,
+            18055801701001909838,
+            109149704128086904,
+        ],
+    ),
+    fp2(
+        [
+            8357460663115985094,
+            5765296628618444602,
+            6421674075164890879,
+            1651079036919805888,
+            1287520506307076832,
+            98975482931648514,
+        ],
+        [
+            8078944849704442787,
+            4038716179863104913,
+            8864619430523621449,
+            11983583689047803099,
+            2541042365160408900,
+            28281336919305267,
+        ],
+    ),
+    fp2(
+        [
+            13472601104608613638,
+            521231156844377864,
+            4649313698209759510,
+            5796371833735044995,
+            8684999192663632207,
+            51033152603009675,
+        ],
+        [
+            3409558318672010377,
+            10145443448574304058,
+            3995874611835909177,
+            15021984820089990008,
+            15242143999191686238,
+            73656911605031936,
+        ],
+    ),
+    fp2(
+        [
+            9770462122700591808,
+            17547477902406858790,
+            14010124614813851222,
+            4165622522809648336,
+            16982670942141592785,
+            28856170353703120,
+        ],
+        [
+            15290128055618535720,
+            17454664547186830394,
+            2442682438806360163,
+            18129275600450347134,
+            4187898517238597724,
+            22281223126461925,
+        ],
+    ),
+    fp2(
+        [
+            12998303454550796060,
+            5752293956714303316,
+            6752814987597331313,
+            3166668351495264669,
+            9929418606104908953,
+            29385282975911842,
+        ],
+        [
+            8756413506556179286,
+            143408982899135549,
+            2705277708630136288,
+            5154523209628123430,
+            6318572504564936509,
+            15532021916109921,
+        ],
+    ),
+    fp2(
+        [
+            18250508560718013179,
+            7486164413457419330,
+            8732750857092323232,
+            457033717118918321,
+            12167888022606617966,
+            20277559260742340,
+        ],
+        [0, 0, 0, 0, 0, 0],
+    ),
+];
+
+const Y_DEN: [Fp2; 34] = [
+    fp2(
+        [
+            2775408832476871526,
+            9008699192344519496,
+            1102884431771657931,
+            11532306452895462867,
+            5856674524343862704,
+            99430919144638985,
+        ],
+        [
+            15551301223123894338,
+            11614654532478001117,
+            17328204268627498271,
+            15544698294678786409,
+            1484472732893154418,
+            94605104690421825,
+        ],
+    ),
+    fp2(
+        [
+            15538486723908094425,
+            10978766348102143722,
+            15056964343960721360,
+            8732109610906448146,
+            8620899699042055528,
+            1351681984895437,
+        ],
+        [
+            3175104032835843731,
+            9314571151837830613,
+            14059118678096680542,
+            2500500275627160283,
+            18190796245603721369,
+            49058560079762280,
+        ],
+    ),
+    fp2(
+        [
+            2177050294381443304,
+            18124514285214412099,
+            9381797198372716589,
+            8046190315005422985,
+            9931273159159670369,
+            8036418831901820,
+        ],
+        [
+            1549742439238596983,
+            15619417821567290543,
+            9431498181016104480,
+            2850048082163302555,
+            17545915875775834651,
+            72687252788290726,
+        ],
+    ),
+    fp2(
+        [
+            16572119218800158686,
+            13113809656757589233,
+            12872678318679566545,
+            3255385198038960565,
+            17070551903094984362,
+            19742086936927308,
+        ],
+        [
+            12635717383520653743,
+            12204869003999147728,
+            8256611698395114158,
+            12382254302890131233,
+            603685913325391887,
+            60927550204070150,
+        ],
+    ),
+    fp2(
+        [
+            15497795759006280655,
+            16847324196958114585,
+            8218328297664216257,
+            15353718428023978640,
+            6357440186573265200,
+            109435672289072692,
+        ],
+        [
+            17126430308003109280,
+            8510055147921463837,
+            2424009275722614739,
+            9284383313721206596,
+            12867548350984534889,
+            46750493171897961,
+        ],
+    ),
+    fp2(
+        [
+            6993808529266055670,
+            10024278910497662817,
+            6638820395273369627,
+            17550512930522052164,
+            11971278025880956390,
+            4218694435301565,
+        ],
+        [
+            4117072311900938869,
+            8052759146924035127,
+            1883914237959498468,
+            843437547616490150,
+            1332392274725871932,
+            100467415201018114,
+        ],
+    ),
+    fp2(
+        [
+            10031273074038578964,
+            6635740043384123318,
+            2357760728051263554,
+            12405057037224522557,
+            2967360385882286162,
+            53130165628915609,
+        ],
+        [
+            9340276074750471546,
+            8779557686584984578,
+            12118682911992514942,
+            8792287004994786286,
+            11729922744948342197,
+            97115621599174349,
+        ],
+    ),
+    fp2(
+        [
+            8243258075199662251,
+            789646305137795307,
+            9501755430432007632,
+            15339245131080115010,
+            3036491630055907252,
+            4720358405852701,
+        ],
+        [
+            6272873484523042114,
+            18180794113363272037,
+            10135093694274252446,
+            13006427779172872075,
+            12612518697452888675,
+            4088970499189038,
+        ],
+    ),
+    fp2(
+        [
+            12559327402496405011,
+            13162954855686166920,
+            2622361684062280170,
+            16985128559432625018,
+            10671934355632922492,
+            22106668982430516,
+        ],
+        [
+            10342048742210368049,
+            4070662490021521195,
+            9050590024293164750,
+            6778744574336170333,
+            16385669267342466637,
+            110676722503289309,
+        ],
+    ),
+    fp2(
+        [
+            8257142434361782318,
+            11625188549524762434,
+            4162174183904813140,
+            15534648919830235837,
+            15345786270187761745,
+            49274740272907617,
+        ],
+        [
+            17452699565973497082,
+            1589725784862892127,
+            17590303103109791779,
+            8233530623701537904,
+            717929863606521126,
+            42679722481449639,
+        ],
+    ),
+    fp2(
+        [
+            17135289411523873787,
+            11725292909156152853,
+            16347014946116696110,
+            13323937426977246609,
+            8703182288833044255,
+            99079409785168386,
+        ],
+        [
+            9130446173903866415,
+            12010725389601806440,
+            9146179391205715125,
+            12338366065383252573,
+            9136251064810045627,
+            19315728226188373,
+        ],
+    ),
+    fp2(
+        [
+            13754907156191096138,
+            22846764546901886,
+            362622052532339515,
+            17013010775786408901,
+            13860043181928645305,
+            41171875858186406,
+        ],
+        [
+            16141340011263075417,
+            18360728019638818576,
+            16264128300543356196,
+            12473458415555386384,
+            18336837302801391285,
+            63727493440743041,
+        ],
+    ),
+    fp2(
+        [
+            3658804449581456923,
+            1347747705877184398,
+            3978302900333357541,
+            5081865331785059868,
+            1329643100050532471,
+            64102076251113639,
+        ],
+        [
+            6980160750786278877,
+            11782099681251246419,
+            5400025369843657828,
+            9848695260591786723,
+            7987487093370334558,
+            27115517650078156,
+        ],
+    ),
+    fp2(
+        [
+            408044901411159465,
+            18068234941175928745,
+            2992890619264445487,
+            8810813216534328625,
+            4005157550725594837,
+            26065038549738560,
+        ],
+        [
+            10975300981822504330,
+            15715038812214651197,
+            7602692888794350386,
+            12917547374269268270,
+            422938878523833779,
+            36305463980543648,
+        ],
+    ),
+    fp2(
+        [
+            10382180122959422967,
+            15807168734599653808,
+            18372044240449882372,
+            17642941170379477011,
+            10340644338271517361,
+            36063527972088465,
+        ],
+        [
+            17969075460977832752,
+            9177853432360197657,
+            2917850475625504299,
+            7079850467696515295,
+            787036529903063845,
+            107077168732108508,
+        ],
+    ),
+    fp2(
+        [
+            6502963492633373835,
+            9890663567118850708,
+            12445720805224431135,
+            6172862156806685987,
+            16576315346783950860,
+            5856337016358393,
+        ],
+        [
+            4476660199518722374,
+            3771101137683024451,
+            489075951782192448,
+            6489873046594013732,
+            280944977367484653,
+            69709094171715534,
+        ],
+    ),
+    fp2(
+        [
+            5629815848909521275,
+            5316587566230943622,
+            7982019375390215350,
+            15543803108203970386,
+            5941855484214597918,
+            5142365312974746,
+        ],
+        [
+            3442509802156923890,
+            6170315030905476396,
+            2122926024914179804,
+            17368567939581660282,
+            12200117156652989113,
+            7492215668086454,
+        ],
+    ),
+    fp2(
+        [
+            15017092692882720799,
+            2306085001940265068,
+            6863750251390498522,
+            772425548835188093,
+            7866794496176459304,
+            119478713276832042,
+        ],
+        [
+            539473290914229032,
+            10680532665300974652,
+            2401493774971219183,
+            771791041055281045,
+            17266300769954562072,
+            46203199265737132,
+        ],
+    ),
+    fp2(
+        [
+            12080185159744170157,
+            6121786867780957883,
+            13378966825252880343,
+            16978399570563241468,
+            13189121794372052505,
+            31306179382417939,
+        ],
+        [
+            6033582013845463960,
+            10201637616554513673,
+            3729832524646428556,
+            2564427389283182369,
+            662893124657004215,
+            101996692818942248,
+        ],
+    ),
+    fp2(
+        [
+            14864583376459179927,
+            16267452113314442715,
+            14135040057928255187,
+            712462212063179204,
+            12089188474131830930,
+            36841165809084721,
+        ],
+        [
+            13089068048776542239,
+            1568107234484844315,
+            4262841373517201534,
+            572974140393742986,
+            13114372614372436015,
+            221271375458892,
+        ],
+    ),
+    fp2(
+        [
+            15352358256255559128,
+            14776149476170502093,
+            13834021062045579807,
+            16108249515581661111,
+            7563707564996631205,
+            86582638494403858,
+        ],
+        [
+            16813570010660423536,
+            3065722476932153407,
+            8938713923763210470,
+            2255995557158728394,
+            8016474455897300271,
+            84052925693202818,
+        ],
+    ),
+    fp2(
+        [
+            15615825726181497195,
+            5658715128964929728,
+            13877560309241249627,
+            15409847795806183219,
+            382506108092986341,
+            33112756833083889,
+        ],
+        [
+            4921115267284233431,
+            2093038862301396370,
+            11859221373400371788,
+            14840702906540650688,
+            3187022540654844811,
+            12940014803022939,
+        ],
+    ),
+    fp2(
+        [
+            10653597201928750109,
+            13006094245282229535,
+            13295987796930539448,
+            16777781019019743602,
+            516306931828031556,
+            114717773318043797,
+        ],
+        [
+            235309942988776203,
+            14860563794932685398,
+            4559874204394395321,
+            16639699308077583607,
+            9227151754747780541,
+            15908917446924874,
+        ],
+    ),
+    fp2(
+        [
+            17337820088369903748,
+            8586614709689721762,
+            1507576901396006569,
+            12700849903501888588,
+            17971589925911161601,
+            108264042385722694,
+        ],
+        [
+            16945398252920060844,
+            12661842631743740793,
+            3757011696927843364,
+            15267617233875676717,
+            4747339870779259550,
+            34661428542359791,
+        ],
+    ),
+    fp2(
+        [
+            16466466374773138846,
+            14279763770124955377,
+            2253148650834359538,
+            7990858085823320889,
+            7124676177871440280,
+            94151727797031734,
+        ],
+        [
+            15321139925728056718,
+            15122461222407649886,
+            17533799169860262777,
+            16804941505997951982,
+            1600681788854461369,
+            44486672743339990,
+        ],
+    ),
+    fp2(
+        [
+            8867142699562737491,
+            18199028041645162481,
+            3517239929170429351,
+            775467166380995197,
+            5868381756625215392,
+            6197719424154602,
+        ],
+        [
+            9737125075096738524,
+            9629792455358261596,
+            11564279494282885105,
+            5089237230157463720,
+            17189088638807565425,
+            106712839297101083,
+        ],
+    ),
+    fp2(
+        [
+            10270843696704620770,
+            13223598872017291793,
+            9010388952516938805,
+            15912131807459901749,
+            6846913031975448418,
+            86431140578472953,
+        ],
+        [
+            15439335117817990542,
+            18208862128889198852,
+            1680174721576182500,
+            13180416124629130962,
+            7418066627384568449,
+            3653571306187636,
+        ],
+    ),
+    fp2(
+        [
+            1131060365675191416,
+            10374247235014096023,
+            5274169106847399340,
+            9643591707517384578,
+            8354961201546942085,
+            16860653124975415,
+        ],
+        [
+            7591061314547569259,
+            3687653994280978709,
+            14906524746128876973,
+            7376139658567228596,
+            3304769414542873433,
+            39389809689964413,
+        ],
+    ),
+    fp2(
+        [
+            15061500520004024506,
+            13781868913987378153,
+            1311923405823490306,
+            13614698003779137726,
+            2734567429318555742,
+            41565578486180068,
+        ],
+        [
+            2264606496492496938,
+            7463181801259856780,
+            4974231603582406689,
+            12631973597673125932,
+            13768142234619696863,
+            69859376569568629,
+        ],
+    ),
+    fp2(
+        [
+            12977806950220507699,
+            9598682745345891240,
+            12727041082031765282,
+            13876739454320532788,
+            16731345162096153922,
+            52377912619410421,
+        ],
+        [
+            9749487977108607359,
+            9452745135768007871,
+            17734899000824388188,
+            6032906020241168466,
+            3891046964558379200,
+            42965172988847872,
+        ],
+    ),
+    fp2(
+        [
+            15134142718037669741,
+            2650609953202022541,
+            18444582952265559358,
+            10072051383647105272,
+            1614233522361858494,
+            6353896085976033,
+        ],
+        [
+            1244819074686382314,
+            16827930012669440062,
+            7409202602064068605,
+            7833164804348414003,
+            14093967246945573989,
+            91886691830462491,
+        ],
+    ),
+    fp2(
+        [
+            3481568382353274479,
+            1454015438048276221,
+            18321294391133087854,
+            11721714833000613979,
+            10732204074831352562,
+            71241856315356756,
+        ],
+        [
+            2091942538387325776,
+            9875355486415603002,
+            12623384858601965052,
+            7559030465493899268,
+            7674739998354242578,
+            75767137192853634,
+        ],
+    ),
+    fp2(
+        [
+            5491757234451068988,
+            6182298996664147147,
+            1538132785577256287,
+            4999224323735886804,
+            6348107227722746450,
+            48518858347037381,
+        ],
+        [
+            15293807953430533698,
+            2990763629845967503,
+            13391735075948095730,
+            9673810945847290845,
+            11089480180973857099,
+            64742831177682886,
+        ],
+    ),
+    fp2(
+        [
+            202099033278250856,
+            5854854902718660529,
+            11492539364873682930,
+            8885205928937022213,
+            5545221690922665192,
+            39800542322357402,
+        ],
+        [0, 0, 0, 0, 0, 0],
+    ),
+];
+
+/// The effective cofactor of G2, in little-endian limbs.
+///
+/// Multiplying by this scalar maps E'(Fp2) onto the prime-order subgroup, and
+/// agrees with the endomorphism-based cofactor clearing of arkworks.
+const H_EFF: [u64; 10] = [
+    0x1e34800000000000,
+    0xcf664765b0000003,
+    0x8e8e73ad8a538800,
+    0x78ba279637388559,
+    0xb85860aaaad29276,
+    0xf7ee7c4b03103b45,
+    0x8f6ade35a5c7d769,
+    0xa951764c46f4edd2,
+    0x53648d3d9502abfb,
+    0x1f60243677e306,
+];
+
+/// The sign of `x`, as `sgn0` in Section 4.1 of RFC 9380.
+fn sgn0(x: &Fp2) -> bool {
+    let sign_0 = x.c0.to_bytes()[0] & 1 == 1;
+    let zero_0 = x.c0 == Fp::ZERO;
+    let sign_1 = x.c1.to_bytes()[0] & 1 == 1;
+    sign_0 || (zero_0 && sign_1)
+}
+
+/// Map `u` to a point of `E'` with the simplified SWU map of Section 6.6.2 of
+/// RFC 9380.
+fn map_to_isogenous_curve(u: &Fp2) -> (Fp2, Fp2) {
+    let g = |x: &Fp2| (x.square() + ISO_A) * *x + ISO_B;
+
+    let z_u2 = Z * u.square();
+    let tv1 = z_u2.square() + z_u2;
+    let a_inv = ISO_A.inverse().expect("A' is nonzero");
+    let x1 = match tv1.inverse() {
+        Some(tv1_inv) => -ISO_B * a_inv * (Fp2::ONE + tv1_inv),
+        // The exceptional case, where u is zero or Z u^2 = -1.
+        None => ISO_B * a_inv * Z.inverse().expect("Z is nonzero"),
+    };
+
+    // Exactly one of g(x1) and g(Z u^2 x1) = Z^3 u^6 g(x1) is square, since Z
+    // is not.
+    let (x, mut y) = match g(&x1).sqrt() {
+        Some(y1) => (x1, y1),
+        None => {
+            let x2 = z_u2 * x1;
+            let y2 = g(&x2).sqrt().expect("g(x2) is square if g(x1) is not");
+            (x2, y2)
+        }
+    };
+    if sgn0(u) != sgn0(&y) {
+        y = -y;
+    }
+    (x, y)
+}
+
+/// Evaluate the polynomial with coefficients `coeffs` at `x`.
+fn evaluate(coeffs: &[Fp2], x: &Fp2) -> Fp2 {
+    coeffs
+        .iter()
+        .rev()
+        .fold(Fp2::ZERO, |acc, coeff| acc * *x + *coeff)
+}
+
+/// Map the point `(x, y)` of `E'` to G2.
+fn isogeny_map(x: &Fp2, y: &Fp2) -> G2Affine {
+    // The denominators vanish exactly at the kernel of the isogeny, which maps
+    // to the identity.
+    match (evaluate(&X_DEN, x).inverse(), evaluate(&Y_DEN, x).inverse()) {
+        (Some(x_den_inv), Some(y_den_inv)) => G2Affine {
+            x: evaluate(&X_NUM, x) * x_den_inv,
+            y: *y * evaluate(&Y_NUM, x) * y_den_inv,
+            infinity: false,
+        },
+        _ => G2Affine::IDENTITY,
+    }
+}
+
+impl G2Projective {
+    /// Hash `msg` to a point of G2.
+    ///
+    /// This follows the random-oracle construction of RFC 9380: `msg` is hashed
+    /// to two `Fp2` elements using `expand_message_xmd` with the hash function
+    /// `H` and the domain separation tag `dst`, each is mapped to the curve
+    /// with the simplified SWU map and an isogeny, and the cofactor is cleared
+    /// from their sum. With SHA-256, the suite ID is
+    /// `BLS12377G2_XMD:SHA-256_SSWU_RO_`, and the output matches the G2
+    /// hasher of `ark-bls12-377`.
+    ///
+    /// This runs in variable time, so `msg` should not be secret.
+    pub fn hash_to_group<H: Digest + BlockSizeUser>(dst: &[u8], msg: &[u8]) -> Self {
+        let [u0, u1] = hash_to_fp2::<H>(dst, msg);
+        let (x0, y0) = map_to_isogenous_curve(&u0);
+        let (x1, y1) = map_to_isogenous_curve(&u1);
+        let q = Self::from(isogeny_map(&x0, &y0)) + Self::from(isogeny_map(&x1, &y1));
+        // The points q0, q1 and their sum lie on the G2 curve E over Fp2, not
+        // only in its prime-order subgroup. The G2 cofactor is odd, so E(Fp2)
+        // has odd order and no points of order two, which is all the complete
+        // addition formulas need to be exact on the whole of E(Fp2).
+        q.mul_by_le_limbs(&H_EFF)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sha2::Sha256;
+
+    struct Vector<'a> {
+        msg: &'a [u8],
+        u: [&'a str; 2],
+        q0: (&'a str, &'a str),
+        q1: (&'a str, &'a str),
+        p: (&'a str, &'a str),
+    }
+
+    fn parse_fp(hex: &str) -> Fp {
+        let mut bytes = [0u8; 48];
+        hex::decode_to_slice(hex.trim_start_matches("0x"), &mut bytes).unwrap();
+        bytes.reverse();
+        Fp::from_bytes_checked(&bytes).unwrap()
+    }
+
+    fn parse_fp2(hex: &str) -> Fp2 {
+        let (c0, c1) = hex.split_once(',').unwrap();
+        Fp2::new(parse_fp(c0), parse_fp(c1))
+    }
+
+    fn parse_point((x, y): (&str, &str)) -> G2Affine {
+        let point = G2Affine {
+            x: parse_fp2(x),
+            y: parse_fp2(y),
+            infinity: false,
+        };
+        assert!(point.is_on_curve());
+        point
+    }
+
+    #[test]
+    fn sgn0_of_extension_elements() {
+        assert!(!sgn0(&Fp2::ZERO));
+        assert!(sgn0(&Fp2::ONE));
+        assert!(!sgn0(&-Fp2::ONE));
+        assert!(sgn0(&Fp2::new(Fp::ZERO, Fp::ONE)));
+        assert!(!sgn0(&Fp2::new(Fp::from(2u64), Fp::ONE)));
+    }
+
+    // The vectors for this suite in `src/curves/tests/
+    // BLS12377G2_XMD-SHA-256_SSWU_RO_.json` of `ark-bls12-377` 0.4.0, in the
+    // format of Appendix J of RFC 9380.
+    #[test]
+    fn hash_to_g2_test_vectors() {
+        let dst = b"QUUX-V01-CS02-with-BLS12377G2_XMD:SHA-256_SSWU_RO_";
+
+        let mut q128 = [b'q'; 133];
+        q128[..5].copy_from_slice(b"q128_");
+        let mut a512 = [b'a'; 517];
+        a512[..5].copy_from_slice(b"a512_");
+
+        let vectors = [
+        Vector {
+            msg: b"",
+            u: [
+                "0x00bad459056ed98adf92ddc87a4b9970b8ce50e5c1d811f72a5631f0ee41eeba11bdc1fcbf135ab259f7be9dbf44c3f4,\
+                 0x018d496adfe469a57596f015eebd869b7bed74083c573bb4b9d40a471e082517e5b744dac07eb67dac975bb8411766c6",
+                "0x0022395aab1038ade247f4b17deb81f4b00cabc04ad532fd3ac580ddbfcb44b3cb9d1d1976b09de603a0a228e713ae7d,\
+                 0x01a366c20f9c2c1bc6b766e25856a85967a104d616680f4a79d97745c224fdadff08940f11ae0b26ea96f656f28b5e78",
+            ],
+            q0: (
+                "0x0040618d422085e035e06f0333349c4630b7e47d96c45b803db208243cfe176cc9b89710c0ac9b6c25387772b0253de9,\
+                 0x016e1c02056c1c0d179c0225b37845f1ea7fdeede136d731b2365248003829bee421b65f64b43c579ad9b30469d6cdd1",
+                "0x0011e6e3019b9c453e3b930a091fb514b082e0353dbd58185d8e9211ec33d036c7c1d535ba020ad2b5cf584aab23fcf0,\
+                 0x014063792b5a789670e1f75fd996a22fa9174888dd315fdb8b4597bdd7572edbb145817b933a0f79c467dabf473ed98c",
+            ),
+            q1: (
+                "0x00d0ec3127b58d178301f9f63be6932d2bf84a71fc79e50004efe7d13ab9ebd80cb7a958a369a1061e5aeb5365d657d2,\
+                 0x00a69c72d1855528334e08529d9d304d39326e5670943f2fa3d57f32b4e1ce215a7df744bff01574e5ef35826e61cdef",
+                "0x0158d9d3ea783058becfbde70cf6215c53a9afd702308c551c5d8b02e00240e20ff93d2d4515d3033b92d44b76f063fa,\
+                 0x0111257e46569bdb27e1c54c51b57a0aef5c5b287e28205b6b08bb585087774993895c2886dcc500c98fc3af92f114dd",
+            ),
+            p: (
+                "0x012988d49df0158335f268551a0121a3fd5509580e675ed2e26f66ffb8ec1089b9db4a69bd19db25f7cae34619b8542a,\
+                 0x0060eecba902692a7f95900c6501ea3f6e6f52b2e951586f60c9f31585c4fb63cb5486d155df4bc394a872f6e0bc3eea",
+                "0x00e07c09af8c992a920bdfcdba4db43b542c5799258f2a01897d5a0c621db77c29f02ca2afa99d78dad2abdd4e180d89,\
+                 0x00020a02b4d45959b67af782b737915298c203dada50f9d4941ada19ea7e986e91a83cb33d01af449dc540244b418561",
+            ),
+        },
+        Vector {
+            msg: b"abc",
+            u: [
+                "0x014a48b15756981016043ea1be11e30a728877c090f3beb8bd56cdadeb98792c0f47246c99c92c6a6d9b4f2ccef6d09f,\
+                 0x01445002d867fb15a2d50311d23ace363b27befbe88d5feecababd66082e6b056d306f7f9dec8d3f4dfb1b2314963cec",
+                "0x001559a97f7639b2b5122e90b51a35c6714903b15cdab56bbe16eac4f8a0bd1514b412867f46f6948362c6f29d88c9fb,\
+                 0x015556fa42d21cb9c6af6de63f6fbfcd6cae7c788e1b74c978f9c2ad1aae724912ff47bd4bab7d77c2af68b9c45b8c49",
+            ],
+            q0: (
+                "0x00af89668bbc75ea1818bde76c0d126f516356b5b4da3f06603a0c4af5c68e44e8984ce185b756287b9aead31fc54c4c,\
+                 0x0013b1d55b0cf613f54d056541daea78edc693750cb992564a9a7861e66f26b3cd4da71a30295dea0e40f0809847a030",
+                "0x0127448b51269ac893ef00a585646b14371c0af69cafafaf3176e663f4e033b29a85e332116d8d9a3bfc8d1873730866,\
+                 0x009a3aad06b68199b2e66fc1dafe20dcc5af8dd740be98fe7db529b424f098ae27759d7613f1942e9b1602664868c17b",
+            ),
+            q1: (
+                "0x009fc985f056445b2f6156a8021faff087f9b8d4690653346ca74b4420589fdbe511491fec0c535b52d521c1bb4be400,\
+                 0x00f920679bd77a0d50d624513cf2296e4565f57dc3d7a578ddb19bfa60f05de1d5954ce247979fbc6501af72cd62e334",
+                "0x0164c6cd007aba3e295df8310a2da71ef40022cec7a2e51dcab9f3850795ac823c7bb65082a88199d7c378b0ac4e9257,\
+                 0x0028633591401d7da706fcb4a4da6f8999a7aff7b167bb96fd92511dca61f62f4b4e0be3213f0d68841a110b39c5a685",
+            ),
+            p: (
+                "0x001346f07170e2ed45d08def787101795af173163239e7a1ee3297fd4e2b4fb6d76380058612e745a62a6fa6186744b7,\
+                 0x00977c6b055e2b80e68afb986a05870294628464393619a2d92a698abcbc8927fe9729b4b72daac63e7f0c76ef711992",
+                "0x016896c5571627fb322a371c57528f98131c51b1dc4ceb2be384610dc3f1a224236febd2c501bcea4d387a3c0c7b4e1c,\
+                 0x007a86495cf20600a5d066b91a726df0b7d67f8758d7ed3fedb797d8772805c52eba9a7bd4661d37932f855b05f19892",
+            ),
+        },
+        Vector {
+            msg: b"abcdef0123456789",
+            u: [
+                "0x014058fb0e66728eb352ca096e0ff6534512d1b8da4391b192da08e1d45d86064c92f01568889b13318cc3ad8144190a,\
+                 0x004f706e3d9a2a46f32f2953cb7a305d092ffb332f085d6ff4ec063d24686c8917a9036e0699c7dcf48f884f1e47ab2e",
+                "0x00cf38b24a429230e04edbaf3b759aacfd37dc8dcc9b297b65b1b9705858f4d3bffee8b10324be05d7e4a18bcc4cd863,\
+                 0x00c478b8e924194155ae97dd29ed2374cf8e207049e32bfb1519ead43702d49f22208ce12b058e7ff31ae7866f05959e",
+            ],
+            q0: (
+                "0x013c8c7d3aa93cb082f1b83edfd76a44a17aba8253a4b0a183b679b9e4d85e1e9653145b4947320187e99825d1846149,\
+                 0x014e9afc495733168d15ebddd8d5177563e2b33a584a9993cef5536a1bba557590f37fc79651825cdf7bb6516ab54a88",
+                "0x008a53b0d7d5a96b9fb7a15c60374028f1ab06c6545c69cfbb6ad7320e3933c64bb13b5139009ae7fb4dc0d29a1eacbd,\
+                 0x0081a61008d4e7204c4a0268c1b4a369d142f4cfbfc8ee794d9653d89dc42d9b27e208ae0f11167d7eadfb56c7d4d1da",
+            ),
+            q1: (
+                "0x0062e543539c61cbfeb512fd1ec5f58bedec3ecc8bfec05de8043bfa92fec6fd7a671c9d3c9b0f86278ec6fbf4ae02a6,\
+                 0x00f9402ce08a6d603d17d45dbaeda0babc9188a7837dba10c4aaad3e4dba9b5208b8df01d0635c30cb913ab16d9d23dc",
+                "0x001c23bb7a1789b4afb3d896e497e8c9d5d4018721ac1303836265bd3d54e7eaa5f63ba45fa8e3776642fa27f049d792,\
+                 0x00cd3bf5161c9ac44a3a490beb0e12c91de09706c4c86b369da263ee63560e84c30ba0ebd72536fa7f874539f2d590b1",
+            ),
+            p: (
+                "0x00dceaed928808d01aff4fc8c762d8cda12cc7ba1f6e721887606ee40ed0df1186f8cde71550636425de7b5ec0137fca,\
+                 0x0166a7280c74d1bb3f0fd8c48aae30b855ba1a59a1dc309fb743f44b958ee721f1c273ee9ccc17e5ae931d566cf93671",
+                "0x003125c58959ccbbca6e456a531833720d87dd039068ec1c4dbeab97230db643c71fb47e6be54eda3a470f452f3d6b1c,\
+                 0x00d070fa5ebd18f6af02f65102b74cc379af99ab9d2b797a316c736bda39fb209bdaee9b59877cd0925c9d0949d91b0a",
+            ),
+        },
+        Vector {
+            msg: &q128,
+            u: [
+                "0x017afe7f987942b49e66831d61b785992f3172b2387f55e97863062e81734b0001bd64ed508d6721956741688dc9af0b,\
+                 0x00631bcf5d854071890838210f50dfa1359da5ff4694255b7cd6ed630e4c6dfca746c69a9c5f3d76ee3cceae20bb355b",
+                "0x00b1fc859bd156b84a8b95d503db388a604c7a05ead79f8cd08cb21b677f3bd77143d8334ff51ddbc77ebf670b7839fa,\
+                 0x00df8ed5ad61dced6490d9b58585fa3803f04f0b7d4efee366296339e634201c6f8924c8c3794c45685f49c6f974cf7e",
+            ],
+            q0: (
+                "0x012f22361e00eb23ffe9082f1b4bf4822553cd42c8367588e5329fa166efdcf09c53e0e0b5f06e2a610f42c6b278050c,\
+                 0x01aade240952b9e4db24fd88e0b0552904b3cb8bab3e4cfbaf2a5c35da48127ef75d1348d76225e8fb896c62766902b5",
+                "0x00f205ed12fd5bdeac9a95349e8383ab854dd59690992b6240357652246388e3224ddfa5ce06de195dfe4e9dc2c5a901,\
+                 0x01203782b3fa65de7ab229eafae08b4ea9bae437e195491b03fab30ddce83881a0b940b33f0f3e7bd7feca2c76e139d6",
+            ),
+            q1: (
+                "0x0171cca78371628178ecc12072e263eda9725524b5de48e340ccc8514897834b81cf36466ef7e2473f88aca8830fd0c7,\
+                 0x017273b33c0259339fac166bf927a66da0a6b40b751048a0093bb4e5d37c35f2a7318e393d4aaef61435ad4471603662",
+                "0x008fe7352facf00e07a74b1315db5b308827577c53a950607fe25974039598f017ed7cc054c1d279dd4957a19435d16d,\
+                 0x0083382ba5e521808254bf1e058dd663336bf591c564d15a0df71791c4124c2ab38baee90d1d4435f4d6c3967df0f9ef",
+            ),
+            p: (
+                "0x00cdb3038598c178025dbaf99dcd440d99c2b38d5b8041893d67002e7c6bab93beaff51439845d06c63f6ddd7c5c401e,\
+                 0x011d2a48f51437628dd6508f6bbb306da621acadf14fbe9e8f47ddf1915beea1e4e3286319c172a32742d7faa45a5b7f",
+                "0x00cac5a0278869557095a63c6a7203468a71d58ad123aaa82f72381cd94250c01479ea8cbe643a8341678679305bc01d,\
+                 0x01a91041b5c1406e643b44d4564babe6f2bdf5fa3c1620419fb6cdb4ba294f1494a33fc829784cc14cbc8066c5310a87",
+            ),
+        },
+        Vector {
+            msg: &a512,
+            u: [
+                "0x011c761e1ea0285445d16b6982d2c58b8ac14ac32dcecd59a76d652c86adacb8643fabc49f6340a9bbb85220ae3272db,\
+                 0x00a340146d9af76164b2aac1026bb445b0f4a9d8d65725c2b26051c905b40146548056e0828590573efb151312ed97de",
+                "0x000edd1a63bc707aef87eed2bd7bf933247a697404d4ed1abebf8db4670cf14c9ebf6b138c642be96bc7fa9616284e7a,\
+                 0x00521fae4b02b3a025c08bbeb2c4783786f82816b7f4c8d496f4715906a3b1085cd48d9d2b58588c138ae4757e220a91",
+            ],
+            q0: (
+                "0x01891e447beb5b70294a6f5f7e0230faed4b3119353bf55ad2afa86cc266359350c07d1eb974389a67533c07f15d506b,\
+                 0x0164482864c5fabe4716cc80bfd8776a8037d87dd141058737c5cf407aa39ec76b78564621637edb6c2ef6b8921d72c9",
+                "0x003b6cffeb0e47c6bd294263d38fb8908707502415d850d06b524a61e1de100d8a768996c6cb8c8d67b8e88cd3dcb4d7,\
+                 0x008ac2f1ea197c81aab704952b808f0cb418d63df22b39d5b88c000c40890200934e8695aac7345e3da11493d76c4231",
+            ),
+            q1: (
+                "0x00f507c2c5090a0e1d5c8e8db72e168c461cb99cbab2f9672934a3a2d92b81501553fce7bc3da3d53fc487dfe7512dd0,\
+                 0x004d70e612013e7b66829a9d3e7d0f8a865fa3cddb794ce30e3b145d4287e4eca91977889f0a6a3837089c34c4ca035e",
+                "0x0055eb01ee257e72fc09d0cb36162af8d98bbb9cad244715cfd63dc30e66ff452d6f4519c418654b4110eabcdf701f76,\
+                 0x00a20ce3bdb4d1da6b8047caf4b7c2285265021dff3b1c1a760badef8d5b108aff67a13d964218711613330b3f0c3344",
+            ),
+            p: (
+                "0x00bc60ec05a3e54f000d07e4ef4d86c9f5bbc8d17aae021f547615a1c89a374bb47fdd25aa488ce8ad6e4b45483cf70f,\
+                 0x00dac74d2a7d021f868b1ba53075fbb5d8b44fa709ba1b94d904d18cd79373bf23e277ca808bc70b64bd47fa877e81aa",
+                "0x010dc70c8b009d9013768bef31ebf18db9ae405fbbdebf7cb8ced20d10ef633e66e3c2301e233e375e75c972f9dc11f1,\
+                 0x0040e8b8abcd97f7bb841fb35655830456be9b0e931db9dbd40307ce7380b53351d22557ca29204b55eb7298cc3b1e0a",
+            ),
+        },
+        ];
+
+        for vector in vectors {
+            let u = hash_to_fp2::<Sha256>(dst, vector.msg);
+            assert_eq!(u, vector.u.map(parse_fp2));
+
+            let q0 = map_to_isogenous_curve(&u[0]);
+            let q1 = map_to_isogenous_curve(&u[1]);
+            assert_eq!(isogeny_map(&q0.0, &q0.1), parse_point(vector.q0));
+            assert_eq!(isogeny_map(&q1.0, &q1.1), parse_point(vector.q1));
+
+            let p = G2Projective::hash_to_group::<Sha256>(dst, vector.msg).to_affine();
+            assert_eq!(p, parse_point(vector.p));
+            assert!(p.is_torsion_free());
+        }
+    }
+}
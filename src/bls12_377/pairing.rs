@@ -0,0 +1,262 @@
+use core::ops::{Mul, MulAssign};
+
+use crate::{
+    fields::{fp12::Fp12, fp2::Fp2},
+    Fp,
+};
+
+use super::{
+    curve::CurveConfig,
+    g1::G1Affine,
+    g2::{G2Affine, G2Config},
+};
+
+/// The BLS parameter `x`, which is positive for BLS12-377.
+const X: u64 = 0x8508c00000000001;
+
+/// The number of line coefficients in a [`G2Prepared`]: one doubling step for
+/// each bit of `x` after the leading one, and one addition step for each set
+/// bit after the leading one.
+const NUM_COEFFS: usize = (63 - X.leading_zeros() + X.count_ones() - 1) as usize;
+
+/// Iterate over the bits of `x` from the most significant, skipping the
+/// leading one.
+fn x_bits() -> impl Iterator<Item = bool> {
+    (0..63 - X.leading_zeros()).rev().map(|i| (X >> i) & 1 == 1)
+}
+
+type LineCoeffs = (Fp2, Fp2, Fp2);
+
+/// A G2 point with the line functions of the Miller loop precomputed.
+///
+/// Preparing a point once is worthwhile when it is paired many times, as with
+/// the fixed points of a verification key.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct G2Prepared {
+    coeffs: [LineCoeffs; NUM_COEFFS],
+    infinity: bool,
+}
+
+/// A point of G2 in homogeneous projective coordinates, used while computing
+/// line functions.
+struct G2HomProjective {
+    x: Fp2,
+    y: Fp2,
+    z: Fp2,
+}
+
+impl G2HomProjective {
+    fn double_in_place(&mut self, two_inv: &Fp) -> LineCoeffs {
+        let a = (self.x * self.y).mul_by_fp(two_inv);
+        let b = self.y.square();
+        let c = self.z.square();
+        let e = G2Config::COEFF_B * (c + c + c);
+        let f = e + e + e;
+        let g = (b + f).mul_by_fp(two_inv);
+        let h = (self.y + self.z).square() - (b + c);
+        let i = e - b;
+        let j = self.x.square();
+        let e_square = e.square();
+
+        self.x = a * (b - f);
+        self.y = g.square() - (e_square + e_square + e_square);
+        self.z = b * h;
+        (-h, j + j + j, i)
+    }
+
+    fn add_in_place(&mut self, q: &G2Affine) -> LineCoeffs {
+        let theta = self.y - q.y * self.z;
+        let lambda = self.x - q.x * self.z;
+        let c = theta.square();
+        let d = lambda.square();
+        let e = lambda * d;
+        let f = self.z * c;
+        let g = self.x * d;
+        let h = e + f - (g + g);
+
+        self.x = lambda * h;
+        self.y = theta * (g - h) - e * self.y;
+        self.z *= e;
+        let j = theta * q.x - lambda * q.y;
+        (lambda, -theta, j)
+    }
+}
+
+impl From<G2Affine> for G2Prepared {
+    fn from(q: G2Affine) -> Self {
+        let mut coeffs = [(Fp2::ZERO, Fp2::ZERO, Fp2::ZERO); NUM_COEFFS];
+        if q.is_identity() {
+            return Self {
+                coeffs,
+                infinity: true,
+            };
+        }
+
+        let two_inv = Fp::from(2u64).inverse().expect("2 is nonzero");
+        let mut r = G2HomProjective {
+            x: q.x,
+            y: q.y,
+            z: Fp2::ONE,
+        };
+        let mut steps = coeffs.iter_mut();
+        for bit in x_bits() {
+            *steps.next().expect("enough coefficients") = r.double_in_place(&two_inv);
+            if bit {
+                *steps.next().expect("enough coefficients") = r.add_in_place(&q);
+            }
+        }
+        Self {
+            coeffs,
+            infinity: false,
+        }
+    }
+}
+
+/// The output of the Miller loop, before the final exponentiation.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct MillerLoopResult(Fp12);
+
+/// An element of the target group of the pairing.
+///
+/// The group is written multiplicatively, as in arkworks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Gt(Fp12);
+
+/// Evaluate the line with coefficients `coeffs` at `p`, and multiply it into
+/// `f`.
+fn ell(f: &mut Fp12, coeffs: &LineCoeffs, p: &G1Affine) {
+    let c0 = coeffs.0.mul_by_fp(&p.y);
+    let c1 = coeffs.1.mul_by_fp(&p.x);
+    *f = f.mul_by_034(&c0, &c1, &coeffs.2);
+}
+
+/// Compute the product of the Miller loops of each pair in `terms`.
+///
+/// Pairs containing the identity contribute nothing to the product.
+pub fn multi_miller_loop(terms: &[(&G1Affine, &G2Prepared)]) -> MillerLoopResult {
+    let is_nontrivial = |(p, q): &&(&G1Affine, &G2Prepared)| !p.is_identity() && !q.infinity;
+
+    let mut f = Fp12::ONE;
+    let mut idx = 0;
+    for bit in x_bits() {
+        f = f.square();
+        for (p, q) in terms.iter().filter(is_nontrivial) {
+            ell(&mut f, &q.coeffs[idx], p);
+        }
+        idx += 1;
+        if bit {
+            for (p, q) in terms.iter().filter(is_nontrivial) {
+                ell(&mut f, &q.coeffs[idx], p);
+            }
+            idx += 1;
+        }
+    }
+    MillerLoopResult(f)
+}
+
+impl MillerLoopResult {
+    /// Raise the Miller loop output to the power `(p^12 - 1) / r`, following
+    /// <https://eprint.iacr.org/2020/875>.
+    ///
+    /// This computes the same power as arkworks, so the result agrees with
+    /// `ark_bls12_377::Bls12_377` exactly.
+    pub fn final_exponentiation(&self) -> Gt {
+        // Each line function evaluates to a nonzero value at points of the
+        // prime-order subgroups, so the Miller loop output is invertible.
+        let f = self.0;
+        let f_inv = f.inverse().expect("Miller loop output is nonzero");
+
+        // The easy part: r = f^((p^6 - 1)(p^2 + 1)), which is in the
+        // cyclotomic subgroup, so that inversion is conjugation.
+        let mut r = f.conjugate() * f_inv;
+        r = r.frobenius_map(2) * r;
+
+        // The hard part.
+        let exp_by_x = |f: &Fp12| f.pow_le_limbs(&[X]);
+        let mut y0 = r.square();
+        let mut y1 = exp_by_x(&r);
+        let mut y2 = r.conjugate();
+        y1 *= y2;
+        y2 = exp_by_x(&y1);
+        y1 = y1.conjugate();
+        y1 *= y2;
+        y2 = exp_by_x(&y1);
+        y1 = y1.frobenius_map(1);
+        y1 *= y2;
+        r *= y0;
+        y0 = exp_by_x(&y1);
+        y2 = exp_by_x(&y0);
+        y0 = y1.frobenius_map(2);
+        y1 = y1.conjugate();
+        y1 *= y2;
+        y1 *= y0;
+        r *= y1;
+        Gt(r)
+    }
+}
+
+/// Miller loop outputs multiply like the pairings they stand for, so separate
+/// loops can be combined and share one final exponentiation.
+impl Mul for MillerLoopResult {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+impl MulAssign for MillerLoopResult {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+/// Compute the pairing `e(p, q)`.
+pub fn pairing(p: &G1Affine, q: &G2Affine) -> Gt {
+    multi_pairing(&[(p, &G2Prepared::from(*q))])
+}
+
+/// Compute the product of the pairings of each pair in `terms`, sharing a
+/// single final exponentiation.
+pub fn multi_pairing(terms: &[(&G1Affine, &G2Prepared)]) -> Gt {
+    multi_miller_loop(terms).final_exponentiation()
+}
+
+impl Gt {
+    pub const IDENTITY: Self = Self(Fp12::ONE);
+
+    pub fn is_identity(&self) -> bool {
+        *self == Self::IDENTITY
+    }
+
+    /// The inverse of this element, which is its conjugate.
+    pub fn inverse(&self) -> Self {
+        Self(self.0.conjugate())
+    }
+
+    /// Encode this element in the same format as the `serialize_compressed`
+    /// method of arkworks' `PairingOutput`.
+    pub fn to_bytes(&self) -> [u8; 576] {
+        self.0.to_bytes()
+    }
+}
+
+impl Default for Gt {
+    fn default() -> Self {
+        Self::IDENTITY
+    }
+}
+
+impl Mul for Gt {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        Self(self.0 * other.0)
+    }
+}
+
+impl MulAssign for Gt {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
@@ -0,0 +1,217 @@
+//! Diffie-Hellman key agreement over decaf377.
+//!
+//! Secret keys are nonzero scalars and public keys are their multiples of the
+//! generator. Secret-dependent scalar multiplication and compression run in
+//! constant time, and secret keys and shared secrets are zeroized on drop.
+
+use core::convert::TryFrom;
+
+use rand_core::CryptoRngCore;
+use subtle::ConstantTimeEq;
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{Element, Encoding, EncodingError, Fr, GENERATOR_TABLE};
+
+/// A secret key for key agreement.
+pub struct SecretKey(Fr);
+
+/// A public key for key agreement.
+#[derive(Copy, Clone, Debug)]
+pub struct PublicKey {
+    element: Element,
+    encoding: Encoding,
+}
+
+/// The encoding of a shared Diffie-Hellman point.
+pub struct SharedSecret([u8; 32]);
+
+impl SecretKey {
+    /// Generate a new random secret key.
+    pub fn new<R: CryptoRngCore>(rng: &mut R) -> Self {
+        loop {
+            let sk = Fr::rand(rng);
+            if sk != Fr::ZERO {
+                return Self(sk);
+            }
+        }
+    }
+
+    /// Parse a secret key from its canonical encoding as a nonzero scalar.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, EncodingError> {
+        let sk = Fr::from_bytes_checked(bytes)?;
+        if sk == Fr::ZERO {
+            return Err(EncodingError::InvalidEncoding);
+        }
+        Ok(Self(sk))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.0.to_bytes()
+    }
+
+    pub fn public_key(&self) -> PublicKey {
+        let element = GENERATOR_TABLE.mul(&self.0);
+        PublicKey {
+            element,
+            encoding: element.compress(),
+        }
+    }
+
+    /// Compute the shared secret with `their_public`.
+    ///
+    /// Returns `None` if the shared point is the identity, which happens
+    /// exactly when `their_public` is the identity.
+    pub fn diffie_hellman(&self, their_public: &PublicKey) -> Option<SharedSecret> {
+        let mut shared_point = their_public.element * self.0;
+        let mut shared = shared_point.compress();
+        shared_point.zeroize();
+
+        let is_identity = bool::from(shared.0.ct_eq(&[0u8; 32]));
+        let secret = SharedSecret(shared.0);
+        shared.zeroize();
+        if is_identity {
+            return None;
+        }
+        Some(secret)
+    }
+}
+
+impl core::fmt::Debug for SecretKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SecretKey").finish_non_exhaustive()
+    }
+}
+
+impl Zeroize for SecretKey {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl Drop for SecretKey {
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+impl ZeroizeOnDrop for SecretKey {}
+
+impl PartialEq for PublicKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.encoding == other.encoding
+    }
+}
+
+impl Eq for PublicKey {}
+
+impl PublicKey {
+    /// Parse a public key from the encoding of a group element.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, EncodingError> {
+        Self::try_from(Encoding(*bytes))
+    }
+
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.encoding.0
+    }
+}
+
+impl TryFrom<Encoding> for PublicKey {
+    type Error = EncodingError;
+
+    fn try_from(encoding: Encoding) -> Result<Self, Self::Error> {
+        let element = encoding.vartime_decompress()?;
+        Ok(Self { element, encoding })
+    }
+}
+
+impl From<PublicKey> for Encoding {
+    fn from(public_key: PublicKey) -> Self {
+        public_key.encoding
+    }
+}
+
+impl SharedSecret {
+    pub fn as_bytes(&self) -> &[u8; 32] {
+        &self.0
+    }
+
+    pub fn to_encoding(&self) -> Encoding {
+        Encoding(self.0)
+    }
+}
+
+impl core::fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SharedSecret").finish_non_exhaustive()
+    }
+}
+
+impl Zeroize for SharedSecret {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
+
+impl Drop for SharedSecret {
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+impl ZeroizeOnDrop for SharedSecret {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rand_core::OsRng;
+
+    #[test]
+    fn key_agreement_is_symmetric() {
+        let alice = SecretKey::new(&mut OsRng);
+        let bob = SecretKey::new(&mut OsRng);
+
+        let alice_shared = alice.diffie_hellman(&bob.public_key()).unwrap();
+        let bob_shared = bob.diffie_hellman(&alice.public_key()).unwrap();
+        assert_eq!(alice_shared.as_bytes(), bob_shared.as_bytes());
+
+        let expected =
+            bob.public_key().element * Fr::from_bytes_checked(&alice.to_bytes()).unwrap();
+        assert_eq!(alice_shared.to_encoding(), expected.vartime_compress());
+    }
+
+    #[test]
+    fn rejects_identity_shared_secret() {
+        let identity = PublicKey::try_from(Element::IDENTITY.vartime_compress()).unwrap();
+        assert!(SecretKey::new(&mut OsRng)
+            .diffie_hellman(&identity)
+            .is_none());
+    }
+
+    #[test]
+    fn keys_roundtrip() {
+        let sk = SecretKey::new(&mut OsRng);
+        let pk = sk.public_key();
+
+        let sk2 = SecretKey::from_bytes(&sk.to_bytes()).unwrap();
+        assert_eq!(sk2.public_key(), pk);
+        assert_eq!(PublicKey::from_bytes(&pk.to_bytes()), Ok(pk));
+        assert_eq!(Encoding::from(pk), pk.element.vartime_compress());
+
+        assert!(SecretKey::from_bytes(&[0u8; 32]).is_err());
+        assert!(PublicKey::from_bytes(&[0xff; 32]).is_err());
+    }
+
+    #[test]
+    fn zeroize_clears_secrets() {
+        let mut sk = SecretKey::new(&mut OsRng);
+        let mut shared = sk
+            .diffie_hellman(&SecretKey::new(&mut OsRng).public_key())
+            .unwrap();
+
+        sk.zeroize();
+        shared.zeroize();
+        assert_eq!(sk.to_bytes(), [0u8; 32]);
+        assert_eq!(shared.as_bytes(), &[0u8; 32]);
+    }
+}
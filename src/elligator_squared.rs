@@ -0,0 +1,108 @@
+//! Encoding elements as uniformly random byte strings, using Tibouchi's
+//! [Elligator Squared](https://eprint.iacr.org/2014/043) construction.
+//!
+//! An element `P` is encoded as a pair `(r_1, r_2)` with
+//! `P = encode_to_curve(r_1) + encode_to_curve(r_2)`, chosen uniformly among
+//! all such pairs. Each field element is then written as a uniformly random
+//! 256-bit integer in its residue class, so that the 64 output bytes are
+//! indistinguishable from random.
+
+use rand_core::CryptoRngCore;
+
+use crate::{Element, Fq};
+
+/// The maximum number of preimages of an element under `encode_to_curve`.
+const MAX_PREIMAGES: u32 = 8;
+
+/// Encode `point` as 64 bytes which are indistinguishable from uniformly random.
+pub(crate) fn to_uniform_bytes<R: CryptoRngCore>(point: &Element, rng: &mut R) -> [u8; 64] {
+    loop {
+        let mut bytes = [0u8; 64];
+        rng.fill_bytes(&mut bytes[..32]);
+        let r_1 = Fq::from_le_bytes_mod_order(&bytes[..32]);
+
+        // Pick a uniformly random slot among the preimages of the remaining
+        // point, and start over if it is empty, so that every pair (r_1, r_2)
+        // is equally likely.
+        let remainder = *point - Element::encode_to_curve(&r_1);
+        let slot = (rng.next_u32() % MAX_PREIMAGES) as usize;
+        let Some(r_2) = remainder.elligator_inverse()[slot] else {
+            continue;
+        };
+
+        // Likewise pick r_2 + k * q for a uniformly random k, starting over if
+        // it does not fit in 256 bits.
+        let k = u64::from(rng.next_u32() % 16);
+        let Some(lifted) = lift(&r_2.to_le_limbs(), k) else {
+            continue;
+        };
+        for (chunk, limb) in bytes[32..].chunks_mut(8).zip(lifted) {
+            chunk.copy_from_slice(&limb.to_le_bytes());
+        }
+        return bytes;
+    }
+}
+
+/// Decode 64 bytes produced by [`to_uniform_bytes`].
+///
+/// Every 64-byte string decodes to some element.
+pub(crate) fn from_uniform_bytes(bytes: &[u8; 64]) -> Element {
+    let r_1 = Fq::from_le_bytes_mod_order(&bytes[..32]);
+    let r_2 = Fq::from_le_bytes_mod_order(&bytes[32..]);
+    Element::hash_to_curve(&r_1, &r_2)
+}
+
+/// Compute `x + k * q` as a 256-bit integer, or `None` if it overflows.
+fn lift(x: &[u64; 4], k: u64) -> Option<[u64; 4]> {
+    let mut out = [0u64; 4];
+    let mut carry = 0u128;
+    for i in 0..4 {
+        let sum = u128::from(x[i]) + u128::from(k) * u128::from(Fq::MODULUS_LIMBS[i]) + carry;
+        out[i] = sum as u64;
+        carry = sum >> 64;
+    }
+    (carry == 0).then_some(out)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use proptest::prelude::*;
+    use rand_chacha::ChaChaRng;
+    use rand_core::SeedableRng;
+
+    #[test]
+    fn lift_rejects_overflow() {
+        let x = (-Fq::ONE).to_le_limbs();
+        assert_eq!(lift(&x, 0), Some(x));
+        assert_eq!(lift(&x, 16), None);
+    }
+
+    #[test]
+    fn uniform_bytes_use_all_bits() {
+        let mut rng = ChaChaRng::seed_from_u64(377);
+        let point = Element::GENERATOR;
+        let mut high_bits = [0u8; 2];
+        for _ in 0..32 {
+            let bytes = to_uniform_bytes(&point, &mut rng);
+            assert_eq!(from_uniform_bytes(&bytes), point);
+            high_bits[0] |= bytes[31] >> 5;
+            high_bits[1] |= bytes[63] >> 5;
+        }
+        // Canonical field element encodings always have their top three bits
+        // clear, but uniform bytes should not.
+        assert_eq!(high_bits, [0b111, 0b111]);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(32))]
+        #[test]
+        fn uniform_bytes_round_trip(bytes: [u8; 32], seed: u64) {
+            let point = Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&bytes))
+                + Element::GENERATOR;
+            let mut rng = ChaChaRng::seed_from_u64(seed);
+            let encoded = to_uniform_bytes(&point, &mut rng);
+            assert_eq!(from_uniform_bytes(&encoded), point);
+        }
+    }
+}
@@ -14,3 +14,39 @@ impl core::fmt::Display for EncodingError {
         msg.fmt(f)
     }
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SignatureError {
+    InvalidSigningKey,
+    InvalidVerificationKey,
+    InvalidSignature,
+}
+
+impl core::fmt::Display for SignatureError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::InvalidSigningKey => "Invalid signing key",
+            Self::InvalidVerificationKey => "Invalid verification key",
+            Self::InvalidSignature => "Invalid signature",
+        };
+
+        msg.fmt(f)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProofError {
+    WrongNumberOfPublicInputs,
+    InvalidProof,
+}
+
+impl core::fmt::Display for ProofError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let msg = match self {
+            Self::WrongNumberOfPublicInputs => "Wrong number of public inputs for verifying key",
+            Self::InvalidProof => "Invalid proof",
+        };
+
+        msg.fmt(f)
+    }
+}
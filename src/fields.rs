@@ -16,7 +16,17 @@
 //! The wrapper code is all copy-pasted and should be kept in sync after any edits.
 //! The different backends should have identical external interfaces, so they can be
 //! used with a cfg-able type alias.
+//!
+//! On top of `Fp`, the `fp2`, `fp6` and `fp12` modules build the BLS12-377
+//! extension tower `Fp2 = Fp[u] / (u^2 - β)`, `Fp6 = Fp2[v] / (v^3 - u)` and
+//! `Fp12 = Fp6[w] / (w^2 - v)`. These only use the `Fp` API, so they work with
+//! either backend, and their encodings match those of `ark-bls12-377`.
 
 pub mod fp;
+pub mod fp12;
+pub mod fp2;
+pub mod fp6;
 pub mod fq;
 pub mod fr;
+
+pub use self::{fp12::Fp12, fp2::Fp2, fp6::Fp6};
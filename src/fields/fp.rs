@@ -143,6 +143,64 @@ impl Fp {
         };
         Self::from_le_bytes_mod_order(&bytes)
     }
+
+    /// Raise this element to a power given by little-endian limbs.
+    ///
+    /// This branches only on the bits of the exponent, so it runs in constant
+    /// time whenever the exponent is public.
+    pub(crate) fn pow_le_limbs(&self, limbs: &[u64]) -> Self {
+        let mut acc = Self::ONE;
+        let mut insert = *self;
+        for limb in limbs {
+            for i in 0..64 {
+                if (limb >> i) & 1 == 1 {
+                    acc *= insert;
+                }
+                insert *= insert;
+            }
+        }
+        acc
+    }
+
+    /// Compute a square root of this element, or `None` if it is nonsquare.
+    ///
+    /// This uses the Tonelli-Shanks algorithm, and runs in variable time.
+    pub fn sqrt(&self) -> Option<Self> {
+        if *self == Self::ZERO {
+            return Some(Self::ZERO);
+        }
+
+        // With `self = x`, maintain `root^2 = x * b`, where `b` has order
+        // `2^k` for some `k < v`, and `z` generates the `2^v`-torsion.
+        let w = self.pow_le_limbs(&Fp::TRACE_MINUS_ONE_DIV_TWO_LIMBS);
+        let mut root = *self * w;
+        let mut b = root * w;
+        let mut z = Fp::TWO_ADIC_ROOT_OF_UNITY;
+        let mut v = Fp::TWO_ADICITY;
+
+        while b != Self::ONE {
+            let mut k = 0;
+            let mut b_2k = b;
+            while b_2k != Self::ONE {
+                b_2k = b_2k.square();
+                k += 1;
+                if k == v {
+                    return None;
+                }
+            }
+
+            let mut w = z;
+            for _ in 1..v - k {
+                w = w.square();
+            }
+            z = w.square();
+            b *= z;
+            root *= w;
+            v = k;
+        }
+
+        Some(root)
+    }
 }
 
 #[cfg(test)]
@@ -154,4 +212,27 @@ mod test {
         assert_eq!(Fp::from_bytes_checked(&[0; N_8]), Ok(Fp::ZERO));
         assert!(Fp::from_bytes_checked(&[0xFF; N_8]).is_err());
     }
+
+    #[test]
+    fn test_two_adic_root_of_unity() {
+        let mut x = Fp::TWO_ADIC_ROOT_OF_UNITY;
+        for _ in 1..Fp::TWO_ADICITY {
+            x = x.square();
+        }
+        assert_eq!(x, Fp::MINUS_ONE);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(Fp::ZERO.sqrt(), Some(Fp::ZERO));
+        assert_eq!(Fp::QUADRATIC_NON_RESIDUE.sqrt(), None);
+        for i in 1..100u64 {
+            let x = Fp::from(i) * Fp::FIELD_SIZE_POWER_OF_TWO;
+            assert_eq!(
+                x.square().sqrt().map(|root| root.square()),
+                Some(x.square())
+            );
+            assert_eq!((x.square() * Fp::QUADRATIC_NON_RESIDUE).sqrt(), None);
+        }
+    }
 }
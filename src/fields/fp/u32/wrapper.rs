@@ -1,3 +1,5 @@
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
 use super::{
     super::{B, N_32, N_64, N_8},
     fiat,
@@ -216,3 +218,19 @@ impl Fp {
         Fp(result)
     }
 }
+
+impl ConditionallySelectable for Fp {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut out = [0u32; N];
+        for (out, (a, b)) in out.iter_mut().zip(a.0 .0.iter().zip(b.0 .0.iter())) {
+            *out = u32::conditional_select(a, b, choice);
+        }
+        Self(fiat::FpMontgomeryDomainFieldElement(out))
+    }
+}
+
+impl ConstantTimeEq for Fp {
+    fn ct_eq(&self, other: &Fp) -> Choice {
+        self.0 .0.ct_eq(&other.0 .0)
+    }
+}
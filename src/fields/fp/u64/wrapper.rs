@@ -1,6 +1,7 @@
 use ark_bls12_377::Fq as ArkworksFp;
 use ark_ff::{BigInt, Field, PrimeField};
 use ark_serialize::CanonicalSerialize;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 
 use super::super::{N_64, N_8};
 
@@ -119,3 +120,22 @@ impl Fp {
         Fp(-self.0)
     }
 }
+
+impl ConditionallySelectable for Fp {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        let mut out = [0u64; N];
+        let a_limbs = a.0 .0 .0;
+        let b_limbs = b.0 .0 .0;
+        for i in 0..N {
+            out[i] = u64::conditional_select(&a_limbs[i], &b_limbs[i], choice);
+        }
+        // The limbs are already in Montgomery form, so we must not convert them again.
+        Self::from_montgomery_limbs(out)
+    }
+}
+
+impl ConstantTimeEq for Fp {
+    fn ct_eq(&self, other: &Fp) -> Choice {
+        self.0 .0 .0.ct_eq(&other.0 .0 .0)
+    }
+}
@@ -0,0 +1,348 @@
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::{fp2::Fp2, fp6::Fp6};
+use crate::{EncodingError, Fp};
+
+/// `ξ^((p^i - 1) / 6)` for `i = 0, ..., 11`, used by the Frobenius map.
+pub(crate) const FROBENIUS_COEFF_FP12_C1: [Fp2; 12] = [
+    Fp2::new(Fp::ONE, Fp::ZERO),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            7981638599956744862,
+            11830407261614897732,
+            6308788297503259939,
+            10596665404780565693,
+            11693741422477421038,
+            61545186993886319,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            6382252053795993818,
+            1383562296554596171,
+            11197251941974877903,
+            6684509567199238270,
+            6699184357838251020,
+            19987743694136192,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            10965161018967488287,
+            18251363109856037426,
+            7036083669251591763,
+            16109345360066746489,
+            4679973768683352764,
+            96952949334633821,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            15766275933608376691,
+            15635974902606112666,
+            1934946774703877852,
+            18129354943882397960,
+            15437979634065614942,
+            101285514078273488,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            2983522419010743425,
+            6420955848241139694,
+            727295371748331824,
+            5512679955286180796,
+            11432976419915483342,
+            35407762340747501,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(Fp::MINUS_ONE, Fp::ZERO),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            1604484313133888867,
+            8276860247155279292,
+            14368189973808974556,
+            9733385900839616209,
+            2590275544672608076,
+            59553125712608379,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            3203870859294639911,
+            276961138506029237,
+            9479726329337356593,
+            13645541738420943632,
+            7584832609311778094,
+            101110569012358506,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            17067705967832697058,
+            1855904398914139597,
+            13640894602060642732,
+            4220705945553435413,
+            9604043198466676350,
+            24145363371860877,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            12266591053191808654,
+            4471292606164064357,
+            295287422898805027,
+            2200696361737783943,
+            17292781406793965788,
+            19812798628221209,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            6602600494079890304,
+            13686311660529037330,
+            1502938825854351055,
+            14817371350334001107,
+            2851040547234545772,
+            85690550365747197,
+        ]),
+        Fp::ZERO,
+    ),
+];
+
+/// An element `c0 + c1 * w` of the quadratic extension `Fp6[w] / (w^2 - v)`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fp12 {
+    pub c0: Fp6,
+    pub c1: Fp6,
+}
+
+impl Fp12 {
+    pub const ZERO: Self = Self::new(Fp6::ZERO, Fp6::ZERO);
+    pub const ONE: Self = Self::new(Fp6::ONE, Fp6::ZERO);
+
+    pub const fn new(c0: Fp6, c1: Fp6) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Convert bytes into an `Fp12` element, rejecting non-canonical
+    /// encodings of any coefficient.
+    pub fn from_bytes_checked(bytes: &[u8; 576]) -> Result<Self, EncodingError> {
+        let mut c0 = [0u8; 288];
+        let mut c1 = [0u8; 288];
+        c0.copy_from_slice(&bytes[..288]);
+        c1.copy_from_slice(&bytes[288..]);
+        Ok(Self::new(
+            Fp6::from_bytes_checked(&c0)?,
+            Fp6::from_bytes_checked(&c1)?,
+        ))
+    }
+
+    /// Encode this element as the encodings of `c0` and `c1`, as in arkworks.
+    pub fn to_bytes(&self) -> [u8; 576] {
+        let mut bytes = [0u8; 576];
+        bytes[..288].copy_from_slice(&self.c0.to_bytes());
+        bytes[288..].copy_from_slice(&self.c1.to_bytes());
+        bytes
+    }
+
+    /// Sample a random field element uniformly.
+    pub fn rand<R: CryptoRngCore>(rng: &mut R) -> Self {
+        Self::new(Fp6::rand(rng), Fp6::rand(rng))
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let norm = self.c0.square() - self.c1.square().mul_by_nonresidue();
+        let norm_inv = norm.inverse()?;
+        Some(Self::new(self.c0 * norm_inv, -(self.c1 * norm_inv)))
+    }
+
+    /// The conjugate `c0 - c1 w`, which is the `p^6`-power Frobenius map.
+    ///
+    /// On the cyclotomic subgroup, this is the inverse.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0, -self.c1)
+    }
+
+    /// Raise this element to the power `p^power`.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Self::new(
+            self.c0.frobenius_map(power),
+            self.c1
+                .frobenius_map(power)
+                .mul_by_fp2(&FROBENIUS_COEFF_FP12_C1[power % 12]),
+        )
+    }
+
+    /// Multiply by the sparse element `c0 + (c3 + c4 v) w`.
+    pub(crate) fn mul_by_034(&self, c0: &Fp2, c3: &Fp2, c4: &Fp2) -> Self {
+        let a = self.c0.mul_by_fp2(c0);
+        let b = self.c1.mul_by_01(c3, c4);
+        let e = (self.c0 + self.c1).mul_by_01(&(*c0 + *c3), c4);
+        Self::new(b.mul_by_nonresidue() + a, e - (a + b))
+    }
+
+    /// Raise this element to the power given by little-endian `limbs`.
+    ///
+    /// This runs in variable time with respect to the exponent.
+    pub(crate) fn pow_le_limbs(&self, limbs: &[u64]) -> Self {
+        let mut acc = Self::ONE;
+        for limb in limbs.iter().rev() {
+            for i in (0..64).rev() {
+                acc = acc.square();
+                if (limb >> i) & 1 == 1 {
+                    acc *= *self;
+                }
+            }
+        }
+        acc
+    }
+}
+
+impl Add for Fp12 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.c0 + other.c0, self.c1 + other.c1)
+    }
+}
+
+impl Sub for Fp12 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.c0 - other.c0, self.c1 - other.c1)
+    }
+}
+
+impl Mul for Fp12 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // Karatsuba multiplication.
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        let c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1;
+        Self::new(v0 + v1.mul_by_nonresidue(), c1)
+    }
+}
+
+impl Neg for Fp12 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1)
+    }
+}
+
+impl AddAssign for Fp12 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fp12 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Fp12 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl ConditionallySelectable for Fp12 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            Fp6::conditional_select(&a.c0, &b.c0, choice),
+            Fp6::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
+impl ConstantTimeEq for Fp12 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+impl zeroize::Zeroize for Fp12 {
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn elements() -> impl Iterator<Item = Fp12> {
+        (1..6u64).map(|i| {
+            let fp2 = |j: u64| {
+                Fp2::new(
+                    Fp::from(i * 1000 + j) * Fp::TWO_ADIC_ROOT_OF_UNITY,
+                    Fp::from(i * 2000 + j) * Fp::FIELD_SIZE_POWER_OF_TWO,
+                )
+            };
+            Fp12::new(
+                Fp6::new(fp2(1), fp2(2), fp2(3)),
+                Fp6::new(fp2(4), fp2(5), fp2(6)),
+            )
+        })
+    }
+
+    #[test]
+    fn test_mul_and_inverse() {
+        let w = Fp12::new(Fp6::ZERO, Fp6::ONE);
+        let v = Fp6::new(Fp2::ZERO, Fp2::ONE, Fp2::ZERO);
+        assert_eq!(w * w, Fp12::new(v, Fp6::ZERO));
+        assert_eq!(Fp12::ZERO.inverse(), None);
+        for (x, y) in elements().zip(elements().skip(1)) {
+            let (c0, c3, c4) = (y.c0.c0, y.c1.c0, y.c1.c1);
+            let sparse = Fp12::new(
+                Fp6::new(c0, Fp2::ZERO, Fp2::ZERO),
+                Fp6::new(c3, c4, Fp2::ZERO),
+            );
+            assert_eq!(x * sparse, x.mul_by_034(&c0, &c3, &c4));
+            assert_eq!(x * x.inverse().unwrap(), Fp12::ONE);
+            assert_eq!((x + y) * x, x.square() + y * x);
+            assert_eq!(x - y, -(y - x));
+            assert_eq!(Fp12::from_bytes_checked(&x.to_bytes()), Ok(x));
+        }
+    }
+
+    #[test]
+    fn test_frobenius_map() {
+        for x in elements() {
+            let x_p = x.pow_le_limbs(&Fp::MODULUS_LIMBS);
+            assert_eq!(x.frobenius_map(1), x_p);
+            for power in 2..12 {
+                assert_eq!(
+                    x.frobenius_map(power),
+                    x.frobenius_map(power - 1).frobenius_map(1)
+                );
+            }
+            assert_eq!(x.frobenius_map(6), x.conjugate());
+            assert_eq!(x.frobenius_map(12), x);
+        }
+    }
+}
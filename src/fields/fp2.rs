@@ -0,0 +1,272 @@
+use core::{
+    cmp::Ordering,
+    ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign},
+};
+
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::{EncodingError, Fp};
+
+/// An element `c0 + c1 * u` of the quadratic extension `Fp[u] / (u^2 - β)`,
+/// where `β = Fp::QUADRATIC_NON_RESIDUE`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fp2 {
+    pub c0: Fp,
+    pub c1: Fp,
+}
+
+impl Fp2 {
+    pub const ZERO: Self = Self::new(Fp::ZERO, Fp::ZERO);
+    pub const ONE: Self = Self::new(Fp::ONE, Fp::ZERO);
+
+    pub const fn new(c0: Fp, c1: Fp) -> Self {
+        Self { c0, c1 }
+    }
+
+    /// Convert bytes into an `Fp2` element, rejecting non-canonical encodings
+    /// of either coefficient.
+    pub fn from_bytes_checked(bytes: &[u8; 96]) -> Result<Self, EncodingError> {
+        let mut c0 = [0u8; 48];
+        let mut c1 = [0u8; 48];
+        c0.copy_from_slice(&bytes[..48]);
+        c1.copy_from_slice(&bytes[48..]);
+        Ok(Self::new(
+            Fp::from_bytes_checked(&c0)?,
+            Fp::from_bytes_checked(&c1)?,
+        ))
+    }
+
+    /// Encode this element as the encodings of `c0` and `c1`, as in arkworks.
+    pub fn to_bytes(&self) -> [u8; 96] {
+        let mut bytes = [0u8; 96];
+        bytes[..48].copy_from_slice(&self.c0.to_bytes());
+        bytes[48..].copy_from_slice(&self.c1.to_bytes());
+        bytes
+    }
+
+    /// Sample a random field element uniformly.
+    pub fn rand<R: CryptoRngCore>(rng: &mut R) -> Self {
+        Self::new(Fp::rand(rng), Fp::rand(rng))
+    }
+
+    /// Multiply an `Fp` element by the non-residue `β`.
+    pub(crate) fn mul_fp_by_nonresidue(x: Fp) -> Fp {
+        x * Fp::QUADRATIC_NON_RESIDUE
+    }
+
+    pub fn square(&self) -> Self {
+        // (c0 + c1 u)^2 = (c0^2 + β c1^2) + 2 c0 c1 u
+        let v0 = self.c0 * self.c1;
+        let c0 = (self.c0 + self.c1) * (self.c0 + Self::mul_fp_by_nonresidue(self.c1))
+            - v0
+            - Self::mul_fp_by_nonresidue(v0);
+        Self::new(c0, v0 + v0)
+    }
+
+    /// The norm `c0^2 - β c1^2`, an element of `Fp`.
+    pub fn norm(&self) -> Fp {
+        self.c0.square() - Self::mul_fp_by_nonresidue(self.c1.square())
+    }
+
+    /// The conjugate `c0 - c1 u`, which is also the `p`-power Frobenius map.
+    pub fn conjugate(&self) -> Self {
+        Self::new(self.c0, -self.c1)
+    }
+
+    /// Raise this element to the power `p^power`.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        if power % 2 == 1 {
+            self.conjugate()
+        } else {
+            *self
+        }
+    }
+
+    pub fn mul_by_fp(&self, x: &Fp) -> Self {
+        Self::new(self.c0 * x, self.c1 * x)
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        let norm_inv = self.norm().inverse()?;
+        Some(self.conjugate().mul_by_fp(&norm_inv))
+    }
+
+    /// Compute a square root of this element, or `None` if it is nonsquare.
+    ///
+    /// This runs in variable time.
+    pub fn sqrt(&self) -> Option<Self> {
+        if self.c1 == Fp::ZERO {
+            // Either c0 is a square in Fp, or c0 / β is.
+            return match self.c0.sqrt() {
+                Some(root) => Some(Self::new(root, Fp::ZERO)),
+                None => {
+                    let beta_inv = Fp::QUADRATIC_NON_RESIDUE
+                        .inverse()
+                        .expect("non-residue is nonzero");
+                    let root = (self.c0 * beta_inv).sqrt()?;
+                    Some(Self::new(Fp::ZERO, root))
+                }
+            };
+        }
+
+        // If (a + b u)^2 = c0 + c1 u, then a^2 = (c0 ± sqrt(norm)) / 2 and
+        // b = c1 / 2a.
+        let alpha = self.norm().sqrt()?;
+        let two_inv = Fp::from(2u64).inverse().expect("2 is nonzero");
+        let mut delta = (self.c0 + alpha) * two_inv;
+        let a = match delta.sqrt() {
+            Some(a) => a,
+            None => {
+                delta -= alpha;
+                delta.sqrt()?
+            }
+        };
+        let b = self.c1 * two_inv * a.inverse()?;
+        let root = Self::new(a, b);
+        if root.square() == *self {
+            Some(root)
+        } else {
+            None
+        }
+    }
+}
+
+impl Add for Fp2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.c0 + other.c0, self.c1 + other.c1)
+    }
+}
+
+impl Sub for Fp2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.c0 - other.c0, self.c1 - other.c1)
+    }
+}
+
+impl Mul for Fp2 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // Karatsuba multiplication.
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        let c1 = (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1;
+        Self::new(v0 + Self::mul_fp_by_nonresidue(v1), c1)
+    }
+}
+
+impl Neg for Fp2 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1)
+    }
+}
+
+impl AddAssign for Fp2 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fp2 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Fp2 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+/// Elements are ordered by `c1` first, then by `c0`, matching arkworks.
+impl Ord for Fp2 {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.c1.cmp(&other.c1).then(self.c0.cmp(&other.c0))
+    }
+}
+
+impl PartialOrd for Fp2 {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl ConditionallySelectable for Fp2 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            Fp::conditional_select(&a.c0, &b.c0, choice),
+            Fp::conditional_select(&a.c1, &b.c1, choice),
+        )
+    }
+}
+
+impl ConstantTimeEq for Fp2 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1)
+    }
+}
+
+impl zeroize::Zeroize for Fp2 {
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn elements() -> impl Iterator<Item = Fp2> {
+        (1..20u64).map(|i| {
+            Fp2::new(
+                Fp::from(i) * Fp::FIELD_SIZE_POWER_OF_TWO,
+                Fp::from(i * i + 7) * Fp::TWO_ADIC_ROOT_OF_UNITY,
+            )
+        })
+    }
+
+    #[test]
+    fn test_square_and_inverse() {
+        let u = Fp2::new(Fp::ZERO, Fp::ONE);
+        assert_eq!(u.square(), Fp2::new(Fp::QUADRATIC_NON_RESIDUE, Fp::ZERO));
+        assert_eq!(Fp2::ZERO.inverse(), None);
+        for x in elements() {
+            assert_eq!(x.square(), x * x);
+            assert_eq!(x * x.inverse().unwrap(), Fp2::ONE);
+        }
+    }
+
+    #[test]
+    fn test_bytes_roundtrip() {
+        for x in elements() {
+            assert_eq!(Fp2::from_bytes_checked(&x.to_bytes()), Ok(x));
+        }
+        let mut non_canonical = Fp2::ONE.to_bytes();
+        non_canonical[48..].fill(0xff);
+        assert!(Fp2::from_bytes_checked(&non_canonical).is_err());
+    }
+
+    #[test]
+    fn test_sqrt() {
+        assert_eq!(Fp2::ZERO.sqrt(), Some(Fp2::ZERO));
+        let u = Fp2::new(Fp::ZERO, Fp::ONE);
+        for x in elements().chain([Fp2::new(Fp::from(5u64), Fp::ZERO), u]) {
+            let square = x.square();
+            assert_eq!(square.sqrt().map(|root| root.square()), Some(square));
+        }
+        // An element is a square exactly when its norm is a square in Fp.
+        let non_square = elements()
+            .find(|x| x.norm().sqrt().is_none())
+            .expect("half of all elements are nonsquare");
+        assert_eq!(non_square.sqrt(), None);
+    }
+}
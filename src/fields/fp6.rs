@@ -0,0 +1,335 @@
+use core::ops::{Add, AddAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use super::fp2::Fp2;
+use crate::{EncodingError, Fp};
+
+/// `ξ^((p^i - 1) / 3)` for `i = 0, ..., 5`, used by the Frobenius map.
+pub(crate) const FROBENIUS_COEFF_FP6_C1: [Fp2; 6] = [
+    Fp2::new(Fp::ONE, Fp::ZERO),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            6382252053795993818,
+            1383562296554596171,
+            11197251941974877903,
+            6684509567199238270,
+            6699184357838251020,
+            19987743694136192,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            15766275933608376691,
+            15635974902606112666,
+            1934946774703877852,
+            18129354943882397960,
+            15437979634065614942,
+            101285514078273488,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(Fp::MINUS_ONE, Fp::ZERO),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            3203870859294639911,
+            276961138506029237,
+            9479726329337356593,
+            13645541738420943632,
+            7584832609311778094,
+            101110569012358506,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            12266591053191808654,
+            4471292606164064357,
+            295287422898805027,
+            2200696361737783943,
+            17292781406793965788,
+            19812798628221209,
+        ]),
+        Fp::ZERO,
+    ),
+];
+
+/// `ξ^((2 p^i - 2) / 3)` for `i = 0, ..., 5`, used by the Frobenius map.
+pub(crate) const FROBENIUS_COEFF_FP6_C2: [Fp2; 6] = [
+    Fp2::new(Fp::ONE, Fp::ZERO),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            15766275933608376691,
+            15635974902606112666,
+            1934946774703877852,
+            18129354943882397960,
+            15437979634065614942,
+            101285514078273488,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            3203870859294639911,
+            276961138506029237,
+            9479726329337356593,
+            13645541738420943632,
+            7584832609311778094,
+            101110569012358506,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(Fp::ONE, Fp::ZERO),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            15766275933608376691,
+            15635974902606112666,
+            1934946774703877852,
+            18129354943882397960,
+            15437979634065614942,
+            101285514078273488,
+        ]),
+        Fp::ZERO,
+    ),
+    Fp2::new(
+        Fp::from_montgomery_limbs([
+            3203870859294639911,
+            276961138506029237,
+            9479726329337356593,
+            13645541738420943632,
+            7584832609311778094,
+            101110569012358506,
+        ]),
+        Fp::ZERO,
+    ),
+];
+
+/// An element `c0 + c1 * v + c2 * v^2` of the cubic extension
+/// `Fp2[v] / (v^3 - ξ)`, where `ξ = u`.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Fp6 {
+    pub c0: Fp2,
+    pub c1: Fp2,
+    pub c2: Fp2,
+}
+
+impl Fp6 {
+    pub const ZERO: Self = Self::new(Fp2::ZERO, Fp2::ZERO, Fp2::ZERO);
+    pub const ONE: Self = Self::new(Fp2::ONE, Fp2::ZERO, Fp2::ZERO);
+
+    pub const fn new(c0: Fp2, c1: Fp2, c2: Fp2) -> Self {
+        Self { c0, c1, c2 }
+    }
+
+    /// Convert bytes into an `Fp6` element, rejecting non-canonical encodings
+    /// of any coefficient.
+    pub fn from_bytes_checked(bytes: &[u8; 288]) -> Result<Self, EncodingError> {
+        let mut coeffs = [Fp2::ZERO; 3];
+        for (coeff, chunk) in coeffs.iter_mut().zip(bytes.chunks_exact(96)) {
+            *coeff = Fp2::from_bytes_checked(chunk.try_into().expect("chunk is 96 bytes"))?;
+        }
+        Ok(Self::new(coeffs[0], coeffs[1], coeffs[2]))
+    }
+
+    /// Encode this element as the encodings of `c0`, `c1` and `c2`, as in
+    /// arkworks.
+    pub fn to_bytes(&self) -> [u8; 288] {
+        let mut bytes = [0u8; 288];
+        for (chunk, coeff) in bytes.chunks_exact_mut(96).zip([self.c0, self.c1, self.c2]) {
+            chunk.copy_from_slice(&coeff.to_bytes());
+        }
+        bytes
+    }
+
+    /// Sample a random field element uniformly.
+    pub fn rand<R: CryptoRngCore>(rng: &mut R) -> Self {
+        Self::new(Fp2::rand(rng), Fp2::rand(rng), Fp2::rand(rng))
+    }
+
+    /// Multiply an `Fp2` element by the non-residue `ξ = u`.
+    pub(crate) fn mul_fp2_by_nonresidue(x: Fp2) -> Fp2 {
+        // u * (c0 + c1 u) = β c1 + c0 u
+        Fp2::new(Fp2::mul_fp_by_nonresidue(x.c1), x.c0)
+    }
+
+    /// Multiply by `v`, the non-residue of the quadratic extension above.
+    pub fn mul_by_nonresidue(&self) -> Self {
+        Self::new(Self::mul_fp2_by_nonresidue(self.c2), self.c0, self.c1)
+    }
+
+    pub fn mul_by_fp2(&self, x: &Fp2) -> Self {
+        Self::new(self.c0 * *x, self.c1 * *x, self.c2 * *x)
+    }
+
+    /// Multiply by the sparse element `c0 + c1 * v`.
+    pub(crate) fn mul_by_01(&self, c0: &Fp2, c1: &Fp2) -> Self {
+        let a_a = self.c0 * *c0;
+        let b_b = self.c1 * *c1;
+        let t1 = Self::mul_fp2_by_nonresidue((self.c1 + self.c2) * *c1 - b_b) + a_a;
+        let t2 = (*c0 + *c1) * (self.c0 + self.c1) - a_a - b_b;
+        let t3 = (self.c0 + self.c2) * *c0 - a_a + b_b;
+        Self::new(t1, t2, t3)
+    }
+
+    pub fn square(&self) -> Self {
+        *self * *self
+    }
+
+    pub fn inverse(&self) -> Option<Self> {
+        // The inverse is (t0 + t1 v + t2 v^2) / (c0 t0 + ξ (c2 t1 + c1 t2)).
+        let t0 = self.c0.square() - Self::mul_fp2_by_nonresidue(self.c1 * self.c2);
+        let t1 = Self::mul_fp2_by_nonresidue(self.c2.square()) - self.c0 * self.c1;
+        let t2 = self.c1.square() - self.c0 * self.c2;
+        let norm = self.c0 * t0 + Self::mul_fp2_by_nonresidue(self.c2 * t1 + self.c1 * t2);
+        let norm_inv = norm.inverse()?;
+        Some(Self::new(t0 * norm_inv, t1 * norm_inv, t2 * norm_inv))
+    }
+
+    /// Raise this element to the power `p^power`.
+    pub fn frobenius_map(&self, power: usize) -> Self {
+        Self::new(
+            self.c0.frobenius_map(power),
+            self.c1.frobenius_map(power) * FROBENIUS_COEFF_FP6_C1[power % 6],
+            self.c2.frobenius_map(power) * FROBENIUS_COEFF_FP6_C2[power % 6],
+        )
+    }
+}
+
+impl Add for Fp6 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.c0 + other.c0, self.c1 + other.c1, self.c2 + other.c2)
+    }
+}
+
+impl Sub for Fp6 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.c0 - other.c0, self.c1 - other.c1, self.c2 - other.c2)
+    }
+}
+
+impl Mul for Fp6 {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        // Karatsuba multiplication, as in https://eprint.iacr.org/2006/471.
+        let v0 = self.c0 * other.c0;
+        let v1 = self.c1 * other.c1;
+        let v2 = self.c2 * other.c2;
+        let c0 =
+            Self::mul_fp2_by_nonresidue((self.c1 + self.c2) * (other.c1 + other.c2) - v1 - v2) + v0;
+        let c1 =
+            (self.c0 + self.c1) * (other.c0 + other.c1) - v0 - v1 + Self::mul_fp2_by_nonresidue(v2);
+        let c2 = (self.c0 + self.c2) * (other.c0 + other.c2) - v0 - v2 + v1;
+        Self::new(c0, c1, c2)
+    }
+}
+
+impl Neg for Fp6 {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.c0, -self.c1, -self.c2)
+    }
+}
+
+impl AddAssign for Fp6 {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Fp6 {
+    fn sub_assign(&mut self, other: Self) {
+        *self = *self - other;
+    }
+}
+
+impl MulAssign for Fp6 {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl ConditionallySelectable for Fp6 {
+    fn conditional_select(a: &Self, b: &Self, choice: Choice) -> Self {
+        Self::new(
+            Fp2::conditional_select(&a.c0, &b.c0, choice),
+            Fp2::conditional_select(&a.c1, &b.c1, choice),
+            Fp2::conditional_select(&a.c2, &b.c2, choice),
+        )
+    }
+}
+
+impl ConstantTimeEq for Fp6 {
+    fn ct_eq(&self, other: &Self) -> Choice {
+        self.c0.ct_eq(&other.c0) & self.c1.ct_eq(&other.c1) & self.c2.ct_eq(&other.c2)
+    }
+}
+
+impl zeroize::Zeroize for Fp6 {
+    fn zeroize(&mut self) {
+        self.c0.zeroize();
+        self.c1.zeroize();
+        self.c2.zeroize();
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn elements() -> impl Iterator<Item = Fp6> {
+        (1..10u64).map(|i| {
+            let fp = |j: u64| Fp::from(i * 1000 + j) * Fp::TWO_ADIC_ROOT_OF_UNITY;
+            Fp6::new(
+                Fp2::new(fp(1), fp(2)),
+                Fp2::new(fp(3), fp(4)),
+                Fp2::new(fp(5), fp(6)),
+            )
+        })
+    }
+
+    #[test]
+    fn test_mul_and_inverse() {
+        let v = Fp6::new(Fp2::ZERO, Fp2::ONE, Fp2::ZERO);
+        assert_eq!(
+            v * v * v,
+            Fp6::new(Fp2::new(Fp::ZERO, Fp::ONE), Fp2::ZERO, Fp2::ZERO)
+        );
+        assert_eq!(Fp6::ZERO.inverse(), None);
+        for (x, y) in elements().zip(elements().skip(1)) {
+            assert_eq!(x * v, x.mul_by_nonresidue());
+            assert_eq!(
+                x * Fp6::new(y.c0, y.c1, Fp2::ZERO),
+                x.mul_by_01(&y.c0, &y.c1)
+            );
+            assert_eq!(x * x.inverse().unwrap(), Fp6::ONE);
+            assert_eq!(Fp6::from_bytes_checked(&x.to_bytes()), Ok(x));
+        }
+    }
+
+    #[test]
+    fn test_frobenius_map() {
+        for x in elements() {
+            // The p-power map is the p-th power.
+            let mut x_p = Fp6::ONE;
+            for limb in Fp::MODULUS_LIMBS.iter().rev() {
+                for i in (0..64).rev() {
+                    x_p = x_p.square();
+                    if (limb >> i) & 1 == 1 {
+                        x_p *= x;
+                    }
+                }
+            }
+            assert_eq!(x.frobenius_map(1), x_p);
+            assert_eq!(x.frobenius_map(2), x_p.frobenius_map(1));
+            assert_eq!(x.frobenius_map(6), x);
+        }
+    }
+}
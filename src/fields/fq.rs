@@ -1,11 +1,16 @@
 use cfg_if::cfg_if;
 use rand_core::CryptoRngCore;
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 
 use crate::EncodingError;
 
 #[cfg(feature = "arkworks")]
 pub mod arkworks;
 mod ops;
+mod sqrt;
 mod u32;
 
 // The u64 backend requires arkworks
@@ -40,6 +45,13 @@ impl Fq {
         672640185344086699,
     ];
 
+    const MODULUS_MINUS_TWO_LIMBS: [u64; N_64] = [
+        725501752471715839,
+        6461107452199829505,
+        6968279316240510977,
+        1345280370688173398,
+    ];
+
     pub const MODULUS_BIT_SIZE: u32 = 0xfd;
 
     pub const TRACE_LIMBS: [u64; N_64] = [
@@ -140,6 +152,104 @@ impl Fq {
         }
         res
     }
+
+    /// Invert every nonzero element of `v` in place, using a single field
+    /// inversion (Montgomery's trick). Zero elements are left unchanged.
+    #[cfg(feature = "alloc")]
+    pub fn batch_inverse(v: &mut [Self]) {
+        // prefix[i] holds the product of the first i + 1 nonzero elements.
+        let mut prefix = Vec::with_capacity(v.len());
+        let mut acc = Self::ONE;
+        for x in v.iter().filter(|x| **x != Self::ZERO) {
+            acc *= x;
+            prefix.push(acc);
+        }
+
+        let mut inv = acc
+            .inverse()
+            .expect("product of nonzero elements is nonzero");
+        for x in v.iter_mut().rev().filter(|x| **x != Self::ZERO) {
+            prefix.pop();
+            let x_inv = inv * prefix.last().copied().unwrap_or(Self::ONE);
+            inv *= *x;
+            *x = x_inv;
+        }
+    }
+
+    /// Raise this element to a power given by little-endian limbs.
+    ///
+    /// This branches only on the bits of the exponent, so it runs in constant
+    /// time whenever the exponent is public.
+    pub(crate) fn pow_le_limbs(&self, limbs: &[u64]) -> Self {
+        let mut acc = Self::ONE;
+        let mut insert = *self;
+        for limb in limbs {
+            for i in 0..64 {
+                if (limb >> i) & 1 == 1 {
+                    acc *= insert;
+                }
+                insert *= insert;
+            }
+        }
+        acc
+    }
+
+    /// Constant-time inversion via Fermat's little theorem, mapping zero to zero.
+    pub(crate) fn ct_inverse(&self) -> Self {
+        self.pow_le_limbs(&Self::MODULUS_MINUS_TWO_LIMBS)
+    }
+
+    /// For square elements, calculate their square root, otherwise return an undefined element.
+    ///
+    /// Based on https://www.ietf.org/archive/id/draft-irtf-cfrg-hash-to-curve-10.html#name-constant-time-tonelli-shanks
+    pub(crate) fn ct_sqrt(&self) -> Self {
+        // Constants c1,...,c5 used for square root computation as defined in the above Appendix:
+        // c1 = TWO_ADICITY
+        // c2 is not directly used in the computation, it's used to compute c3
+        // c3 = TRACE_MINUS_ONE_DIV_TWO_LIMBS;
+        // c4 is not directly used in the computation, but should match ZETA-
+        // c5 = c4 ^ c2
+        // c5 = QUADRATIC_NON_RESIDUE_TO_TRACE
+
+        // Step 1: z = x^c3
+        let mut z = self.pow_le_limbs(&Fq::TRACE_MINUS_ONE_DIV_TWO_LIMBS);
+
+        // Step 2: t = z * z * x
+        let mut t = z * z * self;
+
+        // Step 3: z = z * x;
+        z *= self;
+
+        // Step 4:  b = t
+        let mut b = t;
+
+        // Step 5: c = c5
+        let mut c = Fq::QUADRATIC_NON_RESIDUE_TO_TRACE;
+
+        // Step 6: for i in (c1, c1 - 1, ..., 2):
+        for i in (2..=Fq::TWO_ADICITY).rev() {
+            // Step 7: for j in (1, 2, ..., i - 2):
+            for _j in 1..=i - 2 {
+                // Step 8: b = b * b
+                b *= b;
+            }
+
+            // Step 9: z = CMOV(z, z * c, b != 1)
+            z = Fq::conditional_select(&z, &(z * c), !b.ct_eq(&Self::ONE));
+
+            // Step 10: c = c * c
+            c *= c;
+
+            // Step 11: t = CMOV(t, t * c, b != 1)
+            t = Fq::conditional_select(&t, &(t * c), !b.ct_eq(&Self::ONE));
+
+            // Step 12: b = t
+            b = t;
+        }
+
+        // Step 13: return z
+        z
+    }
 }
 
 #[cfg(test)]
@@ -151,4 +261,38 @@ mod test {
         assert_eq!(Fq::from_bytes_checked(&[0; N_8]), Ok(Fq::ZERO));
         assert!(Fq::from_bytes_checked(&[0xFF; N_8]).is_err());
     }
+
+    #[test]
+    fn test_conditional_select() {
+        let a = Fq::from(2u64);
+        let b = Fq::from(3u64);
+        assert_eq!(Fq::conditional_select(&a, &b, 0.into()), a);
+        assert_eq!(Fq::conditional_select(&a, &b, 1.into()), b);
+        assert!(bool::from(a.ct_eq(&a)));
+        assert!(!bool::from(a.ct_eq(&b)));
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_batch_inverse() {
+        let mut v = [Fq::from(2u64), Fq::ZERO, -Fq::from(12345u64), Fq::ONE];
+        let expected = v.map(|x| x.inverse().unwrap_or(Fq::ZERO));
+        Fq::batch_inverse(&mut v);
+        assert_eq!(v, expected);
+    }
+
+    #[test]
+    fn test_ct_inverse() {
+        assert_eq!(Fq::ZERO.ct_inverse(), Fq::ZERO);
+        assert_eq!(Fq::ONE.ct_inverse(), Fq::ONE);
+        let x = Fq::from(12345u64);
+        assert_eq!(x * x.ct_inverse(), Fq::ONE);
+    }
+
+    #[test]
+    fn test_ct_sqrt() {
+        assert_eq!(Fq::ZERO.ct_sqrt(), Fq::ZERO);
+        let x = Fq::from(12345u64);
+        assert_eq!(x.square().ct_sqrt().square(), x.square());
+    }
 }
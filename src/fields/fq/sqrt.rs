@@ -0,0 +1,134 @@
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+use crate::Fq;
+
+/// `ZETA^TRACE`, the constant `c6` of the RFC 9380 `sqrt_ratio` procedure.
+const ZETA_TO_TRACE: Fq = Fq::from_montgomery_limbs([
+    6282505393754313363,
+    14378628227555923904,
+    9804873068900332207,
+    302335131180501866,
+]);
+
+/// `ZETA^((TRACE + 1) / 2)`, the constant `c7` of the RFC 9380 `sqrt_ratio` procedure.
+const ZETA_TO_TRACE_PLUS_ONE_DIV_TWO: Fq = Fq::from_montgomery_limbs([
+    9228542452838733896,
+    1659757420279635529,
+    5601448727074663207,
+    65302780762596835,
+]);
+
+impl Fq {
+    /// Computes the square root of a ratio of field elements in constant time, returning:
+    ///
+    /// - `(true, sqrt(num/den))` if `num` and `den` are both nonzero and `num/den` is square;
+    /// - `(true, 0)` if `num` is zero;
+    /// - `(false, 0)` if `den` is zero;
+    /// - `(false, sqrt(zeta*num/den))` if `num` and `den` are both nonzero and `num/den` is nonsquare;
+    ///
+    /// The square root returned may differ in sign from the one returned by
+    /// the variable-time `sqrt_ratio_zeta`.
+    pub fn sqrt_ratio_zeta_ct(num: &Self, den: &Self) -> (Choice, Self) {
+        // This is the constant-time Tonelli-Shanks variant of `sqrt_ratio` for any field from
+        // https://www.rfc-editor.org/rfc/rfc9380.html#name-sqrt_ratio-for-any-field
+        // with Z = ZETA, c1 = TWO_ADICITY, c3 = TRACE_MINUS_ONE_DIV_TWO, c4 = 2^c1 - 1
+        // and c5 = 2^(c1 - 1). The iteration count depends only on c1.
+
+        // Steps 1-10.
+        let mut tv1 = ZETA_TO_TRACE;
+        let mut tv2 = den.pow_le_limbs(&[(1u64 << Fq::TWO_ADICITY) - 1]);
+        let mut tv3 = tv2.square() * den;
+        let mut tv5 = (*num * tv3).pow_le_limbs(&Fq::TRACE_MINUS_ONE_DIV_TWO_LIMBS) * tv2;
+        tv2 = tv5 * den;
+        tv3 = tv5 * num;
+        let mut tv4 = tv3 * tv2;
+
+        // Steps 11-12: tv5 = tv4^c5, isQR = tv5 == 1
+        tv5 = tv4;
+        for _ in 1..Fq::TWO_ADICITY {
+            tv5 = tv5.square();
+        }
+        let is_qr = tv5.ct_eq(&Fq::ONE);
+
+        // Steps 13-16.
+        tv2 = tv3 * ZETA_TO_TRACE_PLUS_ONE_DIV_TWO;
+        tv5 = tv4 * tv1;
+        tv3 = Fq::conditional_select(&tv2, &tv3, is_qr);
+        tv4 = Fq::conditional_select(&tv5, &tv4, is_qr);
+
+        // Steps 17-26.
+        for i in (2..=Fq::TWO_ADICITY).rev() {
+            tv5 = tv4;
+            for _ in 2..i {
+                tv5 = tv5.square();
+            }
+            let e1 = tv5.ct_eq(&Fq::ONE);
+            tv2 = tv3 * tv1;
+            tv1 = tv1.square();
+            tv5 = tv4 * tv1;
+            tv3 = Fq::conditional_select(&tv2, &tv3, e1);
+            tv4 = Fq::conditional_select(&tv5, &tv4, e1);
+        }
+
+        // The procedure above reports a zero numerator as nonsquare, but we
+        // want `(true, 0)` in that case, and `tv3` is already zero.
+        (is_qr | num.ct_eq(&Fq::ZERO), tv3)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ZETA;
+
+    use proptest::prelude::*;
+
+    fn fq_strategy() -> BoxedStrategy<Fq> {
+        any::<[u8; 32]>()
+            .prop_map(|bytes| Fq::from_le_bytes_mod_order(&bytes[..]))
+            .boxed()
+    }
+
+    #[test]
+    fn zeta_constants() {
+        assert_eq!(ZETA.pow_le_limbs(&Fq::TRACE_LIMBS), ZETA_TO_TRACE);
+        assert_eq!(
+            ZETA.pow_le_limbs(&Fq::TRACE_MINUS_ONE_DIV_TWO_LIMBS) * ZETA,
+            ZETA_TO_TRACE_PLUS_ONE_DIV_TWO
+        );
+    }
+
+    #[test]
+    fn sqrt_ratio_zeta_ct_edge_cases() {
+        let (was_square, root) = Fq::sqrt_ratio_zeta_ct(&Fq::ZERO, &Fq::ONE);
+        assert!(bool::from(was_square));
+        assert_eq!(root, Fq::ZERO);
+
+        let (was_square, root) = Fq::sqrt_ratio_zeta_ct(&Fq::ONE, &Fq::ZERO);
+        assert!(!bool::from(was_square));
+        assert_eq!(root, Fq::ZERO);
+    }
+
+    proptest! {
+        #![proptest_config(ProptestConfig::with_cases(1000))]
+        #[test]
+        fn sqrt_ratio_zeta_ct(u in fq_strategy(), v in fq_strategy()) {
+            let (was_square, sqrt_zeta_uv) = Fq::sqrt_ratio_zeta_ct(&u, &v);
+            let was_square = bool::from(was_square);
+            if u == Fq::ZERO {
+                assert_eq!((was_square, sqrt_zeta_uv), (true, u));
+            } else if v == Fq::ZERO {
+                assert_eq!((was_square, sqrt_zeta_uv), (false, v));
+            } else {
+                let zeta_uv = sqrt_zeta_uv * sqrt_zeta_uv;
+                if was_square {
+                    // check zeta_uv = u/v
+                    assert_eq!(u, v * zeta_uv);
+                } else {
+                    // check zeta_uv = zeta * u / v
+                    assert_eq!(ZETA * u, v * zeta_uv);
+                }
+            }
+        }
+    }
+}
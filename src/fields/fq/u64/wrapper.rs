@@ -140,19 +140,14 @@ impl ConditionallySelectable for Fq {
         for i in 0..4 {
             out[i] = u64::conditional_select(&a_limbs[i], &b_limbs[i], choice);
         }
+        // The limbs are already in Montgomery form, so we must not convert them again.
         let bigint = BigInt::new(out);
-        Self(ArkworksFq::new(bigint))
+        Self(ArkworksFq::new_unchecked(bigint))
     }
 }
 
 impl ConstantTimeEq for Fq {
     fn ct_eq(&self, other: &Fq) -> Choice {
-        let self_limbs = self.0 .0 .0;
-        let other_limbs = other.0 .0 .0;
-        let mut is_equal = true;
-        for i in 0..4 {
-            is_equal &= self_limbs[i] == other_limbs[i];
-        }
-        Choice::from(is_equal as u8)
+        self.0 .0 .0.ct_eq(&other.0 .0 .0)
     }
 }
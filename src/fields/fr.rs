@@ -110,6 +110,31 @@ impl Fr {
         self.to_bytes_le()
     }
 
+    /// Write this scalar in signed radix 16, with digits in `[-8, 8)`.
+    ///
+    /// The output satisfies `self = sum(digits[i] * 16^i)`, which is used for
+    /// fixed-window scalar multiplication.
+    pub(crate) fn to_radix_16(self) -> [i8; 64] {
+        let bytes = self.to_bytes_le();
+        let mut digits = [0i8; 64];
+
+        // Compute unsigned radix-16 digits.
+        for i in 0..32 {
+            digits[2 * i] = (bytes[i] & 15) as i8;
+            digits[2 * i + 1] = ((bytes[i] >> 4) & 15) as i8;
+        }
+
+        // Recenter the digits. Since the scalar is less than 2^252, the top
+        // digit stays at most 1.
+        for i in 0..63 {
+            let carry = (digits[i] + 8) >> 4;
+            digits[i] -= carry << 4;
+            digits[i + 1] += carry;
+        }
+
+        digits
+    }
+
     /// Sample a random field element uniformly.
     pub fn rand<R: CryptoRngCore>(rng: &mut R) -> Self {
         // Sample wide, reduce
@@ -131,4 +156,24 @@ mod test {
         assert_eq!(Fr::from_bytes_checked(&[0; N_8]), Ok(Fr::ZERO));
         assert!(Fr::from_bytes_checked(&[0xFF; N_8]).is_err());
     }
+
+    #[test]
+    fn test_to_radix_16() {
+        for x in [
+            Fr::ZERO,
+            Fr::ONE,
+            -Fr::ONE,
+            Fr::from(0x8888u64),
+            Fr::from(u128::MAX),
+        ] {
+            let digits = x.to_radix_16();
+            let mut acc = Fr::ZERO;
+            for digit in digits.iter().rev() {
+                assert!((-8..8).contains(digit));
+                let d = Fr::from(digit.unsigned_abs());
+                acc = acc * Fr::from(16u8) + if *digit < 0 { -d } else { d };
+            }
+            assert_eq!(acc, x);
+        }
+    }
 }
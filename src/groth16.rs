@@ -0,0 +1,282 @@
+//! Verification of Groth16 proofs over BLS12-377, using the native pairing in
+//! [`crate::bls12_377`] rather than arkworks.
+//!
+//! Verifying keys and proofs are parsed from the `CanonicalSerialize` formats
+//! of `ark-groth16`, so keys from its setup (such as the `*_vk.param` files)
+//! and proofs from its prover can be checked with either field backend.
+
+use alloc::vec::Vec;
+
+use crate::{
+    bls12_377::{multi_pairing, G1Affine, G1Projective, G2Affine, G2Prepared, Gt},
+    EncodingError, Fq, ProofError,
+};
+
+/// A Groth16 verifying key.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VerifyingKey {
+    pub alpha_g1: G1Affine,
+    pub beta_g2: G2Affine,
+    pub gamma_g2: G2Affine,
+    pub delta_g2: G2Affine,
+    /// The bases for the public inputs, starting with the constant term.
+    pub gamma_abc_g1: Vec<G1Affine>,
+}
+
+/// A verifying key with the pairing inputs that do not depend on the proof
+/// precomputed, for checking many proofs against the same key.
+#[derive(Clone, Debug)]
+pub struct PreparedVerifyingKey {
+    gamma_abc_g1: Vec<G1Affine>,
+    alpha_g1_beta_g2: Gt,
+    neg_gamma_g2: G2Prepared,
+    neg_delta_g2: G2Prepared,
+}
+
+/// A Groth16 proof.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Proof {
+    pub a: G1Affine,
+    pub b: G2Affine,
+    pub c: G1Affine,
+}
+
+/// Split off the first `N` bytes of `bytes`.
+fn take<const N: usize>(bytes: &mut &[u8]) -> Result<[u8; N], EncodingError> {
+    if bytes.len() < N {
+        return Err(EncodingError::InvalidSliceLength);
+    }
+    let (head, tail) = bytes.split_at(N);
+    *bytes = tail;
+    Ok(head.try_into().expect("length is checked"))
+}
+
+impl VerifyingKey {
+    /// Parse a verifying key from the uncompressed encoding of an
+    /// `ark_groth16::VerifyingKey`, rejecting points outside the prime-order
+    /// subgroups.
+    pub fn from_uncompressed(mut bytes: &[u8]) -> Result<Self, EncodingError> {
+        let alpha_g1 = G1Affine::from_uncompressed(&take(&mut bytes)?)?;
+        let beta_g2 = G2Affine::from_uncompressed(&take(&mut bytes)?)?;
+        let gamma_g2 = G2Affine::from_uncompressed(&take(&mut bytes)?)?;
+        let delta_g2 = G2Affine::from_uncompressed(&take(&mut bytes)?)?;
+
+        // The bases are encoded as a vector, prefixed with its length.
+        let len = u64::from_le_bytes(take(&mut bytes)?);
+        if len.checked_mul(96) != Some(bytes.len() as u64) {
+            return Err(EncodingError::InvalidSliceLength);
+        }
+        let gamma_abc_g1 = bytes
+            .chunks_exact(96)
+            .map(|chunk| G1Affine::from_uncompressed(chunk.try_into().expect("chunk is 96 bytes")))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Self {
+            alpha_g1,
+            beta_g2,
+            gamma_g2,
+            delta_g2,
+            gamma_abc_g1,
+        })
+    }
+
+    /// Encode this key in the uncompressed format of `ark_groth16::VerifyingKey`.
+    pub fn to_uncompressed(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(96 + 3 * 192 + 8 + 96 * self.gamma_abc_g1.len());
+        bytes.extend_from_slice(&self.alpha_g1.to_uncompressed());
+        bytes.extend_from_slice(&self.beta_g2.to_uncompressed());
+        bytes.extend_from_slice(&self.gamma_g2.to_uncompressed());
+        bytes.extend_from_slice(&self.delta_g2.to_uncompressed());
+        bytes.extend_from_slice(&(self.gamma_abc_g1.len() as u64).to_le_bytes());
+        for base in &self.gamma_abc_g1 {
+            bytes.extend_from_slice(&base.to_uncompressed());
+        }
+        bytes
+    }
+}
+
+impl From<&VerifyingKey> for PreparedVerifyingKey {
+    fn from(vk: &VerifyingKey) -> Self {
+        Self {
+            gamma_abc_g1: vk.gamma_abc_g1.clone(),
+            alpha_g1_beta_g2: multi_pairing(&[(&vk.alpha_g1, &G2Prepared::from(vk.beta_g2))]),
+            neg_gamma_g2: G2Prepared::from(-vk.gamma_g2),
+            neg_delta_g2: G2Prepared::from(-vk.delta_g2),
+        }
+    }
+}
+
+impl PreparedVerifyingKey {
+    /// Check `proof` against `public_inputs`, as [`verify`] does.
+    pub fn verify(&self, public_inputs: &[Fq], proof: &Proof) -> Result<(), ProofError> {
+        let inputs = prepare_inputs(&self.gamma_abc_g1, public_inputs)?;
+        let check = multi_pairing(&[
+            (&proof.a, &G2Prepared::from(proof.b)),
+            (&inputs, &self.neg_gamma_g2),
+            (&proof.c, &self.neg_delta_g2),
+        ]);
+        if check == self.alpha_g1_beta_g2 {
+            Ok(())
+        } else {
+            Err(ProofError::InvalidProof)
+        }
+    }
+}
+
+impl Proof {
+    /// Parse a proof from the 192-byte compressed encoding of an
+    /// `ark_groth16::Proof`.
+    pub fn from_compressed(bytes: &[u8; 192]) -> Result<Self, EncodingError> {
+        let mut bytes = &bytes[..];
+        Ok(Self {
+            a: G1Affine::from_compressed(&take(&mut bytes)?)?,
+            b: G2Affine::from_compressed(&take(&mut bytes)?)?,
+            c: G1Affine::from_compressed(&take(&mut bytes)?)?,
+        })
+    }
+
+    pub fn to_compressed(&self) -> [u8; 192] {
+        let mut bytes = [0u8; 192];
+        bytes[..48].copy_from_slice(&self.a.to_compressed());
+        bytes[48..144].copy_from_slice(&self.b.to_compressed());
+        bytes[144..].copy_from_slice(&self.c.to_compressed());
+        bytes
+    }
+
+    /// Parse a proof from the 384-byte uncompressed encoding of an
+    /// `ark_groth16::Proof`.
+    pub fn from_uncompressed(bytes: &[u8; 384]) -> Result<Self, EncodingError> {
+        let mut bytes = &bytes[..];
+        Ok(Self {
+            a: G1Affine::from_uncompressed(&take(&mut bytes)?)?,
+            b: G2Affine::from_uncompressed(&take(&mut bytes)?)?,
+            c: G1Affine::from_uncompressed(&take(&mut bytes)?)?,
+        })
+    }
+
+    pub fn to_uncompressed(&self) -> [u8; 384] {
+        let mut bytes = [0u8; 384];
+        bytes[..96].copy_from_slice(&self.a.to_uncompressed());
+        bytes[96..288].copy_from_slice(&self.b.to_uncompressed());
+        bytes[288..].copy_from_slice(&self.c.to_uncompressed());
+        bytes
+    }
+}
+
+/// Compute the linear combination of the input bases with the public inputs.
+fn prepare_inputs(gamma_abc_g1: &[G1Affine], public_inputs: &[Fq]) -> Result<G1Affine, ProofError> {
+    if public_inputs.len() + 1 != gamma_abc_g1.len() {
+        return Err(ProofError::WrongNumberOfPublicInputs);
+    }
+    let mut acc = G1Projective::from(gamma_abc_g1[0]);
+    for (input, base) in public_inputs.iter().zip(&gamma_abc_g1[1..]) {
+        acc += G1Projective::from(*base).mul_by_le_limbs(&input.to_le_limbs());
+    }
+    Ok(acc.to_affine())
+}
+
+/// Check that `proof` is a valid proof for `public_inputs` under `vk`.
+///
+/// This checks `e(A, B) = e(α, β) e(Σ x_i γ_abc_i, γ) e(C, δ)` with a single
+/// multi-pairing, accepting exactly the proofs that `ark_groth16` accepts.
+pub fn verify(vk: &VerifyingKey, public_inputs: &[Fq], proof: &Proof) -> Result<(), ProofError> {
+    let inputs = prepare_inputs(&vk.gamma_abc_g1, public_inputs)?;
+    let check = multi_pairing(&[
+        (&proof.a, &G2Prepared::from(proof.b)),
+        (&inputs, &G2Prepared::from(-vk.gamma_g2)),
+        (&proof.c, &G2Prepared::from(-vk.delta_g2)),
+        (&-vk.alpha_g1, &G2Prepared::from(vk.beta_g2)),
+    ]);
+    if check.is_identity() {
+        Ok(())
+    } else {
+        Err(ProofError::InvalidProof)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use crate::bls12_377::G2Projective;
+
+    const VK_PARAMS: [&[u8]; 9] = [
+        include_bytes!("../tests/test_vectors/add_assign_add_vk.param"),
+        include_bytes!("../tests/test_vectors/compression_vk.param"),
+        include_bytes!("../tests/test_vectors/decompression_vk.param"),
+        include_bytes!("../tests/test_vectors/discrete_log_vk.param"),
+        include_bytes!("../tests/test_vectors/elligator_vk.param"),
+        include_bytes!("../tests/test_vectors/fixed_base_vk.param"),
+        include_bytes!("../tests/test_vectors/hash_to_curve_vk.param"),
+        include_bytes!("../tests/test_vectors/negation_vk.param"),
+        include_bytes!("../tests/test_vectors/public_element_input_vk.param"),
+    ];
+
+    fn g1(x: Fq) -> G1Affine {
+        (G1Projective::GENERATOR * x).to_affine()
+    }
+
+    fn g2(x: Fq) -> G2Affine {
+        (G2Projective::GENERATOR * x).to_affine()
+    }
+
+    #[test]
+    fn verifying_keys_roundtrip() {
+        for bytes in VK_PARAMS {
+            let vk = VerifyingKey::from_uncompressed(bytes).unwrap();
+            assert_eq!(vk.to_uncompressed(), bytes);
+
+            assert!(VerifyingKey::from_uncompressed(&bytes[..bytes.len() - 1]).is_err());
+            let mut extended = bytes.to_vec();
+            extended.push(0);
+            assert!(VerifyingKey::from_uncompressed(&extended).is_err());
+        }
+    }
+
+    #[test]
+    fn verify_with_trapdoor_proofs() {
+        // With the trapdoor, a proof for any inputs can be made by choosing
+        // `A` and `B` freely and solving for `C`.
+        let [alpha, beta, gamma, delta, k0, k1, k2, r, s] =
+            [3u64, 5, 7, 11, 13, 17, 19, 23, 29].map(|x| Fq::from(x) * Fq::from(x + 1000).square());
+        let vk = VerifyingKey {
+            alpha_g1: g1(alpha),
+            beta_g2: g2(beta),
+            gamma_g2: g2(gamma),
+            delta_g2: g2(delta),
+            gamma_abc_g1: [k0, k1, k2].map(g1).to_vec(),
+        };
+        let inputs = [Fq::from(42u64), -Fq::ONE];
+        let input_term = k0 + inputs[0] * k1 + inputs[1] * k2;
+        let c = (r * s - alpha * beta - input_term * gamma) * delta.inverse().unwrap();
+        let proof = Proof {
+            a: g1(r),
+            b: g2(s),
+            c: g1(c),
+        };
+
+        let pvk = PreparedVerifyingKey::from(&vk);
+        assert_eq!(verify(&vk, &inputs, &proof), Ok(()));
+        assert_eq!(pvk.verify(&inputs, &proof), Ok(()));
+
+        let wrong_inputs = [Fq::from(43u64), -Fq::ONE];
+        assert_eq!(
+            verify(&vk, &wrong_inputs, &proof),
+            Err(ProofError::InvalidProof)
+        );
+        assert_eq!(
+            pvk.verify(&wrong_inputs, &proof),
+            Err(ProofError::InvalidProof)
+        );
+        assert_eq!(
+            verify(&vk, &inputs[..1], &proof),
+            Err(ProofError::WrongNumberOfPublicInputs)
+        );
+
+        assert_eq!(Proof::from_compressed(&proof.to_compressed()), Ok(proof));
+        assert_eq!(
+            Proof::from_uncompressed(&proof.to_uncompressed()),
+            Ok(proof)
+        );
+    }
+}
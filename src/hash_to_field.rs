@@ -0,0 +1,238 @@
+//! Hashing byte strings to field elements, following [RFC 9380].
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380.html
+
+use digest::{crypto_common::BlockSizeUser, Digest, Output};
+
+use crate::{fields::fp2::Fp2, Fp, Fq};
+
+/// The number of uniform bytes used to derive each field element,
+/// `L = ceil((ceil(log2(q)) + k) / 8)` for the security parameter `k = 128`.
+const L: usize = 48;
+
+/// The number of uniform bytes used to derive each `Fp` coefficient of an
+/// `Fp2` element, with the same security parameter.
+const FP_L: usize = 64;
+
+/// Hash `msg` to two field elements, as `hash_to_field(msg, 2)` in Section 5.2
+/// of RFC 9380 with `expand_message_xmd`.
+pub(crate) fn hash_to_field<H: Digest + BlockSizeUser>(dst: &[u8], msg: &[u8]) -> [Fq; 2] {
+    let mut uniform_bytes = [0u8; 2 * L];
+    expand_message_xmd::<H>(msg, dst, &mut uniform_bytes);
+
+    let mut out = [Fq::ZERO; 2];
+    for (u, chunk) in out.iter_mut().zip(uniform_bytes.chunks_exact(L)) {
+        // OS2IP reads the bytes as a big-endian integer.
+        let mut le_bytes = [0u8; L];
+        le_bytes.copy_from_slice(chunk);
+        le_bytes.reverse();
+        *u = Fq::from_le_bytes_mod_order(&le_bytes);
+    }
+    out
+}
+
+/// Hash `msg` to two `Fp2` elements, as `hash_to_field(msg, 2)` in Section 5.2
+/// of RFC 9380 with `expand_message_xmd` and extension degree `m = 2`.
+pub(crate) fn hash_to_fp2<H: Digest + BlockSizeUser>(dst: &[u8], msg: &[u8]) -> [Fp2; 2] {
+    let mut uniform_bytes = [0u8; 4 * FP_L];
+    expand_message_xmd::<H>(msg, dst, &mut uniform_bytes);
+
+    let mut coeffs = [Fp::ZERO; 4];
+    for (c, chunk) in coeffs.iter_mut().zip(uniform_bytes.chunks_exact(FP_L)) {
+        let mut le_bytes = [0u8; FP_L];
+        le_bytes.copy_from_slice(chunk);
+        le_bytes.reverse();
+        *c = Fp::from_le_bytes_mod_order(&le_bytes);
+    }
+    [
+        Fp2::new(coeffs[0], coeffs[1]),
+        Fp2::new(coeffs[2], coeffs[3]),
+    ]
+}
+
+/// Fill `out` with `expand_message_xmd(msg, DST, out.len())`, as defined in
+/// Section 5.3.1 of RFC 9380.
+///
+/// Domain separation tags longer than 255 bytes are hashed first, as in
+/// Section 5.3.3.
+///
+/// # Panics
+///
+/// Panics if `out` is longer than 65535 bytes or 255 digest outputs.
+pub(crate) fn expand_message_xmd<H: Digest + BlockSizeUser>(
+    msg: &[u8],
+    dst: &[u8],
+    out: &mut [u8],
+) {
+    let b_in_bytes = <H as Digest>::output_size();
+    let len_in_bytes = out.len();
+    assert!(
+        len_in_bytes <= u16::MAX as usize && len_in_bytes.div_ceil(b_in_bytes) <= 255,
+        "requested output is too long"
+    );
+
+    let oversize_dst: Output<H>;
+    let dst = if dst.len() > 255 {
+        oversize_dst = H::new()
+            .chain_update(b"H2C-OVERSIZE-DST-")
+            .chain_update(dst)
+            .finalize();
+        &oversize_dst[..]
+    } else {
+        dst
+    };
+    let dst_len = [dst.len() as u8];
+
+    // b_0 = H(Z_pad || msg || l_i_b_str || I2OSP(0, 1) || DST_prime)
+    let mut h = H::new();
+    let zeros = [0u8; 32];
+    let mut z_pad_len = H::block_size();
+    while z_pad_len > 0 {
+        let n = z_pad_len.min(zeros.len());
+        h.update(&zeros[..n]);
+        z_pad_len -= n;
+    }
+    let b_0 = h
+        .chain_update(msg)
+        .chain_update((len_in_bytes as u16).to_be_bytes())
+        .chain_update([0u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    // b_1 = H(b_0 || I2OSP(1, 1) || DST_prime)
+    let mut b_i = H::new()
+        .chain_update(&b_0)
+        .chain_update([1u8])
+        .chain_update(dst)
+        .chain_update(dst_len)
+        .finalize();
+
+    for (i, chunk) in out.chunks_mut(b_in_bytes).enumerate() {
+        if i > 0 {
+            // b_i = H(strxor(b_0, b_(i - 1)) || I2OSP(i, 1) || DST_prime)
+            let xor: Output<H> = b_0.iter().zip(b_i.iter()).map(|(a, b)| a ^ b).collect();
+            b_i = H::new()
+                .chain_update(xor)
+                .chain_update([(i + 1) as u8])
+                .chain_update(dst)
+                .chain_update(dst_len)
+                .finalize();
+        }
+        chunk.copy_from_slice(&b_i[..chunk.len()]);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use sha2::{Sha256, Sha512};
+
+    fn check_vectors<H: Digest + BlockSizeUser>(dst: &[u8], vectors: &[(&[u8], &str)]) {
+        for (msg, expected) in vectors {
+            let mut expected_bytes = [0u8; 128];
+            let expected_bytes = &mut expected_bytes[..expected.len() / 2];
+            hex::decode_to_slice(expected, expected_bytes).unwrap();
+            let mut out = [0u8; 128];
+            let out = &mut out[..expected_bytes.len()];
+            expand_message_xmd::<H>(msg, dst, out);
+            assert_eq!(out, expected_bytes);
+        }
+    }
+
+    // Test vectors from Appendix K.1 of RFC 9380.
+    #[test]
+    fn expand_message_xmd_sha256() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA256-128";
+        let vectors: [(&[u8], &str); 6] = [
+            (
+                b"",
+                "68a985b87eb6b46952128911f2a4412bbc302a9d759667f87f7a21d803f07235",
+            ),
+            (
+                b"abc",
+                "d8ccab23b5985ccea865c6c97b6e5b8350e794e603b4b97902f53a8a0d605615",
+            ),
+            (
+                b"abcdef0123456789",
+                "eff31487c770a893cfb36f912fbfcbff40d5661771ca4b2cb4eafe524333f5c1",
+            ),
+            (
+                b"",
+                concat!(
+                    "af84c27ccfd45d41914fdff5df25293e221afc53d8ad2ac06d5e3e29485dadbe",
+                    "e0d121587713a3e0dd4d5e69e93eb7cd4f5df4cd103e188cf60cb02edc3edf18",
+                    "eda8576c412b18ffb658e3dd6ec849469b979d444cf7b26911a08e63cf31f9dc",
+                    "c541708d3491184472c2c29bb749d4286b004ceb5ee6b9a7fa5b646c993f0ced",
+                ),
+            ),
+            (
+                b"abc",
+                concat!(
+                    "abba86a6129e366fc877aab32fc4ffc70120d8996c88aee2fe4b32d6c7b6437a",
+                    "647e6c3163d40b76a73cf6a5674ef1d890f95b664ee0afa5359a5c4e07985635",
+                    "bbecbac65d747d3d2da7ec2b8221b17b0ca9dc8a1ac1c07ea6a1e60583e2cb00",
+                    "058e77b7b72a298425cd1b941ad4ec65e8afc50303a22c0f99b0509b4c895f40",
+                ),
+            ),
+            (
+                b"abcdef0123456789",
+                concat!(
+                    "ef904a29bffc4cf9ee82832451c946ac3c8f8058ae97d8d629831a74c6572bd9",
+                    "ebd0df635cd1f208e2038e760c4994984ce73f0d55ea9f22af83ba4734569d4b",
+                    "c95e18350f740c07eef653cbb9f87910d833751825f0ebefa1abe5420bb52be1",
+                    "4cf489b37fe1a72f7de2d10be453b2c9d9eb20c7e3f6edc5a60629178d9478df",
+                ),
+            ),
+        ];
+        check_vectors::<Sha256>(dst, &vectors);
+    }
+
+    // Test vectors from Appendix K.3 of RFC 9380.
+    #[test]
+    fn expand_message_xmd_sha512() {
+        let dst = b"QUUX-V01-CS02-with-expander-SHA512-256";
+        let vectors: [(&[u8], &str); 6] = [
+            (
+                b"",
+                "6b9a7312411d92f921c6f68ca0b6380730a1a4d982c507211a90964c394179ba",
+            ),
+            (
+                b"abc",
+                "0da749f12fbe5483eb066a5f595055679b976e93abe9be6f0f6318bce7aca8dc",
+            ),
+            (
+                b"abcdef0123456789",
+                "087e45a86e2939ee8b91100af1583c4938e0f5fc6c9db4b107b83346bc967f58",
+            ),
+            (
+                b"",
+                concat!(
+                    "41b037d1734a5f8df225dd8c7de38f851efdb45c372887be655212d07251b921",
+                    "b052b62eaed99b46f72f2ef4cc96bfaf254ebbbec091e1a3b9e4fb5e5b619d2e",
+                    "0c5414800a1d882b62bb5cd1778f098b8eb6cb399d5d9d18f5d5842cf5d13d7e",
+                    "b00a7cff859b605da678b318bd0e65ebff70bec88c753b159a805d2c89c55961",
+                ),
+            ),
+            (
+                b"abc",
+                concat!(
+                    "7f1dddd13c08b543f2e2037b14cefb255b44c83cc397c1786d975653e36a6b11",
+                    "bdd7732d8b38adb4a0edc26a0cef4bb45217135456e58fbca1703cd6032cb134",
+                    "7ee720b87972d63fbf232587043ed2901bce7f22610c0419751c065922b48843",
+                    "1851041310ad659e4b23520e1772ab29dcdeb2002222a363f0c2b1c972b3efe1",
+                ),
+            ),
+            (
+                b"abcdef0123456789",
+                concat!(
+                    "3f721f208e6199fe903545abc26c837ce59ac6fa45733f1baaf0222f8b7acb04",
+                    "24814fcb5eecf6c1d38f06e9d0a6ccfbf85ae612ab8735dfdf9ce84c372a77c8",
+                    "f9e1c1e952c3a61b7567dd0693016af51d2745822663d0c2367e3f4f0bed827f",
+                    "eecc2aaf98c949b5ed0d35c3f1023d64ad1407924288d366ea159f46287e61ac",
+                ),
+            ),
+        ];
+        check_vectors::<Sha512>(dst, &vectors);
+    }
+}
@@ -2,14 +2,38 @@
 //! `decaf377` [instantiates Decaf over the BLS12-377 scalar
 //! field](https://penumbra.zone/crypto/primitives/decaf377.html).
 //!
+//! Field elements, `Element` and `Encoding` implement `Zeroize`, but since
+//! they are `Copy` they cannot implement `Drop`, and hence not
+//! `ZeroizeOnDrop`: values derived from secrets must be zeroized explicitly,
+//! including any copies.
+//!
 use cfg_if::cfg_if;
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 pub mod fields;
 pub use fields::{fp::Fp, fq::Fq, fr::Fr};
+mod elligator_squared;
+mod hash_to_field;
+mod msm;
 mod sign;
+mod window;
+
+pub mod bls;
+pub mod bls12_377;
+pub mod dh;
+#[cfg(feature = "alloc")]
+pub mod groth16;
+#[cfg(feature = "alloc")]
+pub mod pedersen;
+pub mod schnorr;
+
+mod basepoint_table;
+pub use basepoint_table::{BasepointTable, GENERATOR_TABLE};
 
 mod error;
-pub use error::EncodingError;
+pub use error::{EncodingError, ProofError, SignatureError};
 
 cfg_if! {
     if #[cfg(feature = "arkworks")] {
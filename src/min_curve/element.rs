@@ -1,8 +1,18 @@
+#![allow(non_snake_case)]
+
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
 use core::ops::{Add, Neg};
-use subtle::{Choice, ConditionallySelectable};
+use digest::{crypto_common::BlockSizeUser, Digest};
+use rand_core::CryptoRngCore;
+use subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use zeroize::Zeroize;
 
 use crate::EncodingError;
-use crate::{min_curve::constants::*, min_curve::encoding::Encoding, sign::Sign, Fq};
+use crate::{
+    elligator_squared, hash_to_field::hash_to_field, min_curve::constants::*,
+    min_curve::encoding::Encoding, sign::Sign, window::LookupTable, Fq, Fr,
+};
 
 /// A point on an Edwards curve.
 ///
@@ -26,6 +36,13 @@ impl AffinePoint {
     };
 }
 
+impl Zeroize for AffinePoint {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+    }
+}
+
 /// An element of the Decaf377 group.
 #[derive(Debug, Clone, Copy)]
 pub struct Element {
@@ -48,6 +65,15 @@ impl ConditionallySelectable for Element {
     }
 }
 
+impl Zeroize for Element {
+    fn zeroize(&mut self) {
+        self.x.zeroize();
+        self.y.zeroize();
+        self.z.zeroize();
+        self.t.zeroize();
+    }
+}
+
 impl Element {
     /// The identity element for the group structure.
     pub const IDENTITY: Self = Self {
@@ -95,6 +121,17 @@ impl Element {
         }
     }
 
+    /// Construct an element from affine coordinates `(x, y)` and `t = x * y`,
+    /// without checking that it is valid.
+    pub(crate) const fn from_affine_unchecked(x: Fq, y: Fq, t: Fq) -> Self {
+        Self {
+            x,
+            y,
+            z: Fq::ONE,
+            t,
+        }
+    }
+
     fn from_affine(x: Fq, y: Fq) -> Self {
         let z = Fq::ONE;
         let t = x * y;
@@ -160,6 +197,45 @@ impl Element {
         Self::scalar_mul_both::<true>(self, le_bits)
     }
 
+    /// Multiply by a scalar in constant time, using a signed radix-16 fixed
+    /// window.
+    pub(crate) fn windowed_mul(&self, scalar: &Fr) -> Self {
+        let table = LookupTable::new(Self::IDENTITY, *self);
+        let digits = scalar.to_radix_16();
+
+        let mut acc = table.select(digits[63]);
+        for digit in digits[..63].iter().rev() {
+            acc = acc.mul_by_pow_2(4);
+            acc += table.select(*digit);
+        }
+        acc
+    }
+
+    /// Given a slice of public scalars and a slice of public points, compute
+    /// $$
+    /// Q = \[c\_1\] P\_1 + \cdots + \[c\_n\] P\_n,
+    /// $$
+    /// using variable-time operations.
+    ///
+    /// This uses Straus' method for small inputs and Pippenger's method for
+    /// large ones, and does not allocate.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `scalars` and `points` have different lengths.
+    pub fn vartime_multiscalar_mul(scalars: &[Fr], points: &[Element]) -> Element {
+        crate::msm::vartime_multiscalar_mul(scalars, points)
+    }
+
+    /// Compute `2^k * self` by repeated doubling.
+    pub(crate) fn mul_by_pow_2(&self, k: u32) -> Self {
+        let mut acc = *self;
+        for _ in 0..k {
+            acc = acc.double();
+        }
+        acc
+    }
+
     pub fn vartime_compress_to_field(&self) -> Fq {
         let A_MINUS_D = COEFF_A - COEFF_D;
 
@@ -186,8 +262,130 @@ impl Element {
         Encoding(bytes)
     }
 
+    /// Compress a batch of elements, equivalent to calling `vartime_compress`
+    /// on each element.
+    ///
+    /// The `Z` coordinates are inverted together with a single field
+    /// inversion. Each point still needs its own inverse square root, which
+    /// cannot be shared with other points.
+    #[cfg(feature = "alloc")]
+    pub fn batch_compress(elements: &[Element]) -> Vec<Encoding> {
+        let mut z_invs: Vec<Fq> = elements.iter().map(|p| p.z).collect();
+        Fq::batch_inverse(&mut z_invs);
+
+        elements
+            .iter()
+            .zip(z_invs)
+            .map(|(p, z_inv)| {
+                let x = p.x * z_inv;
+                let y = p.y * z_inv;
+                Element::from_affine_unchecked(x, y, x * y).vartime_compress()
+            })
+            .collect()
+    }
+
+    /// Compress the doubles of a batch of elements, equivalent to calling
+    /// `vartime_compress` on `P + P` for each element `P`.
+    ///
+    /// For a doubled point `2Q`, the ratio whose square root the encoding
+    /// needs is a square of a rational function of the coordinates of `Q`,
+    /// so unlike [`Element::batch_compress`] the whole batch costs a single
+    /// field inversion. To compress a batch of points `x_i * B` this way,
+    /// pass in the points `(x_i / 2) * B`.
+    #[cfg(feature = "alloc")]
+    pub fn batch_vartime_double_and_compress(elements: &[Element]) -> Vec<Encoding> {
+        let A_MINUS_D = COEFF_A - COEFF_D;
+        let A_MINUS_D_INV = A_MINUS_D.inverse().expect("a - d is nonzero");
+        let TWO = Fq::ONE + Fq::ONE;
+
+        // The doubling 2Q = (e/g, h/f) in affine coordinates, with the
+        // denominators f and g nonzero since d is nonsquare.
+        let efgh: Vec<[Fq; 4]> = elements
+            .iter()
+            .map(|q| {
+                let xx = q.x.square();
+                let yy = q.y.square();
+                let g = COEFF_A * xx + yy;
+                [
+                    TWO * q.x * q.y,
+                    TWO * q.z.square() - g,
+                    g,
+                    yy - COEFF_A * xx,
+                ]
+            })
+            .collect();
+        let mut invs: Vec<Fq> = efgh.iter().map(|[e, f, g, _]| *e * *f * *g).collect();
+        Fq::batch_inverse(&mut invs);
+
+        efgh.iter()
+            .zip(invs)
+            .map(|([e, f, g, h], inv)| {
+                // Since (a - d)(1 - y^2) = ((a - d) e / f)^2, the inverse square
+                // root of step 2 is v = f g^2 / ((a - d) e^3), up to a sign that
+                // the absolute values below remove. If e = 0 then Q is 4-torsion
+                // and inv = 0, which gives the identity encoding.
+                let e_inv = inv * *f * *g;
+                let x = *e * *e * *f * inv;
+                let y = *h * *e * *g * inv;
+                let t = x * y;
+                let v = *f * g.square() * e_inv.square() * e_inv * A_MINUS_D_INV;
+
+                // 1.
+                let u_1 = (x + t) * (x - t);
+
+                // 3.
+                let u_2 = (v * u_1).abs();
+
+                // 4.
+                let u_3 = u_2 - t;
+
+                // 5.
+                let s = (A_MINUS_D * v * u_3 * x).abs();
+
+                Encoding(s.to_bytes_le())
+            })
+            .collect()
+    }
+
+    /// Constant-time equivalent of `vartime_compress_to_field`.
+    pub fn compress_to_field(&self) -> Fq {
+        let A_MINUS_D = COEFF_A - COEFF_D;
+
+        // 1.
+        let u_1 = (self.x + self.t) * (self.x - self.t);
+
+        // 2. the ratio is always square, so we can take the square root of the
+        // inverse directly.
+        let v = (u_1 * A_MINUS_D * self.x.square()).ct_inverse().ct_sqrt();
+
+        // 3.
+        let u_2 = (v * u_1).ct_abs();
+
+        // 4.
+        let u_3 = u_2 * self.z - self.t;
+
+        // 5.
+        (A_MINUS_D * v * u_3 * self.x).ct_abs()
+    }
+
+    /// Constant-time equivalent of `vartime_compress`.
+    pub fn compress(&self) -> Encoding {
+        let s = self.compress_to_field();
+        let bytes = s.to_bytes_le();
+        Encoding(bytes)
+    }
+
     /// Elligator 2 map to decaf377 point
     fn elligator_map(r_0: &Fq) -> Self {
+        Self::elligator_map_both::<false>(r_0)
+    }
+
+    /// Constant-time Elligator 2 map to decaf377 point
+    fn elligator_map_ct(r_0: &Fq) -> Self {
+        Self::elligator_map_both::<true>(r_0)
+    }
+
+    fn elligator_map_both<const CT: bool>(r_0: &Fq) -> Self {
         // Ref: `Decaf_1_1_Point.elligator` (optimized) in `ristretto.sage`
         const A: Fq = COEFF_A;
         const D: Fq = COEFF_D;
@@ -198,17 +396,17 @@ impl Element {
         let num = (r + Fq::ONE) * (A - (Fq::ONE + Fq::ONE) * D);
 
         let x = num * den;
-        let (iss, mut isri) = Fq::non_arkworks_sqrt_ratio_zeta(&Fq::ONE, &x);
-
-        let sgn;
-        let twiddle;
-        if iss {
-            sgn = Fq::ONE;
-            twiddle = Fq::ONE;
+        let (iss, mut isri) = if CT {
+            Fq::sqrt_ratio_zeta_ct(&Fq::ONE, &x)
         } else {
-            sgn = -(Fq::ONE);
-            twiddle = *r_0;
-        }
+            let (iss, isri) = Fq::non_arkworks_sqrt_ratio_zeta(&Fq::ONE, &x);
+            (Choice::from(iss as u8), isri)
+        };
+
+        // Case 1: iss is true, then sgn and twiddle are both 1
+        // Case 2: iss is false, then sgn is -1 and twiddle is r_0
+        let sgn = Fq::conditional_select(&-(Fq::ONE), &Fq::ONE, iss);
+        let twiddle = Fq::conditional_select(r_0, &Fq::ONE, iss);
 
         isri *= twiddle;
 
@@ -216,9 +414,9 @@ impl Element {
         let t =
             -(sgn) * isri * s * (r - Fq::ONE) * (A - (Fq::ONE + Fq::ONE) * D).square() - Fq::ONE;
 
-        if s.is_negative() == iss {
-            s = -s
-        }
+        // if s.is_negative() == iss { s = -s }
+        let cond_negate = !(s.ct_is_negative() ^ iss);
+        s = Fq::conditional_select(&s, &-s, cond_negate);
 
         // Convert point to extended projective (X : Y : Z : T)
         let E = (Fq::ONE + Fq::ONE) * s;
@@ -238,10 +436,137 @@ impl Element {
         &R_1 + &R_2
     }
 
+    /// Hash a message to a uniformly distributed decaf377 `Element`.
+    ///
+    /// This follows the random-oracle construction of RFC 9380: `msg` is hashed
+    /// to two field elements using `expand_message_xmd` with the hash function
+    /// `H` and the domain separation tag `dst`, and these are then mapped to the
+    /// group with `hash_to_curve`. With SHA-512, the suite ID is
+    /// `decaf377_XMD:SHA-512_D377MAP_RO_`.
+    pub fn hash_to_group<H: Digest + BlockSizeUser>(dst: &[u8], msg: &[u8]) -> Element {
+        let [r_1, r_2] = hash_to_field::<H>(dst, msg);
+        Element::hash_to_curve(&r_1, &r_2)
+    }
+
+    /// Constant-time equivalent of `hash_to_curve`.
+    pub fn hash_to_curve_ct(r_1: &Fq, r_2: &Fq) -> Element {
+        let R_1 = Element::elligator_map_ct(r_1);
+        let R_2 = Element::elligator_map_ct(r_2);
+        R_1 + R_2
+    }
+
     /// Maps a field element to a decaf377 `Element` suitable for CDH challenges.
     pub fn encode_to_curve(r: &Fq) -> Element {
         Element::elligator_map(r)
     }
+
+    /// Constant-time equivalent of `encode_to_curve`.
+    pub fn encode_to_curve_ct(r: &Fq) -> Element {
+        Element::elligator_map_ct(r)
+    }
+
+    /// Find all field elements `r` such that `encode_to_curve(r)` is this
+    /// element.
+    ///
+    /// Preimages come in pairs `r, -r`, and there are at most four such pairs.
+    /// The preimages fill the start of the returned array, with the remaining
+    /// entries set to `None`. This runs in variable time.
+    pub fn elligator_inverse(&self) -> [Option<Fq>; 8] {
+        let mut preimages = [None; 8];
+        let mut n = 0;
+        for r in self.elligator_inverse_candidates().into_iter().flatten() {
+            // r = ZETA * r_0^2, so r_0 exists exactly when r / ZETA is square.
+            let (was_square, r_0) = Fq::non_arkworks_sqrt_ratio_zeta(&r, &ZETA);
+            if !was_square || Element::encode_to_curve(&r_0) != *self {
+                continue;
+            }
+            for r_0 in [r_0, -r_0] {
+                if !preimages.contains(&Some(r_0)) {
+                    preimages[n] = Some(r_0);
+                    n += 1;
+                }
+            }
+        }
+        preimages
+    }
+
+    /// Encode this element as 64 bytes which are indistinguishable from
+    /// uniformly random bytes, using Elligator Squared.
+    ///
+    /// The encoding is randomized, and decodes back to this element with
+    /// `from_uniform_bytes`. This runs in variable time.
+    pub fn to_uniform_bytes<R: CryptoRngCore>(&self, rng: &mut R) -> [u8; 64] {
+        elligator_squared::to_uniform_bytes(self, rng)
+    }
+
+    /// Decode 64 bytes produced by `to_uniform_bytes`, as
+    /// `hash_to_curve(r_1, r_2)` where `r_1` and `r_2` are the two 32-byte
+    /// halves reduced modulo the field order.
+    ///
+    /// Every 64-byte string decodes to some element.
+    pub fn from_uniform_bytes(bytes: &[u8; 64]) -> Element {
+        elligator_squared::from_uniform_bytes(bytes)
+    }
+
+    /// Compute the values of `r = ZETA * r_0^2` that could map to this element.
+    ///
+    /// Each candidate must still be checked against the forward map.
+    fn elligator_inverse_candidates(&self) -> [Option<Fq>; 4] {
+        // Ref: `Decaf_1_1_Point.elligatorSpec` in `ristretto.sage`, which
+        // produces a point on the Jacobi quartic with
+        //
+        //   s^2 = (r + 1)(a - 2d) / den, t = -(r - 1)(a - 2d)^2 / den - 1
+        //
+        // when the first is square (and then s is nonnegative), or otherwise
+        //
+        //   s^2 = r(r + 1)(a - 2d) / den, t = r(r - 1)(a - 2d)^2 / den - 1
+        //
+        // with s negative. In both cases k = (t + 1) / (s^2 (a - 2d)) only
+        // depends on r, which lets us solve for it.
+        let A = COEFF_A;
+        let D = COEFF_D;
+        let one = Fq::ONE;
+
+        if self.is_identity() {
+            // The identity is the image of r = -1, and of the two values of r
+            // where den vanishes.
+            let d_minus_a = D - A;
+            return [
+                Some(-one),
+                d_minus_a.inverse().map(|inv| D * inv),
+                D.inverse().map(|inv| d_minus_a * inv),
+                None,
+            ];
+        }
+
+        let mut candidates = [None; 4];
+        let z_inv = self.z.inverse().expect("z is nonzero");
+        let (x, y) = (self.x * z_inv, self.y * z_inv);
+        let c = A - (one + one) * D;
+
+        // The element is represented by both (x, y) and (-x, -y) on the curve.
+        for (i, (x, y)) in [(x, y), (-x, -y)].into_iter().enumerate() {
+            // Each of these has two preimages on the Jacobi quartic, the roots
+            // of x = 2s / (1 + a s^2), and then t = (1 - a s^2) / y.
+            let (is_square, root) = Fq::non_arkworks_sqrt_ratio_zeta(&(one - A * x.square()), &one);
+            if !is_square {
+                continue;
+            }
+            for (j, root) in [root, -root].into_iter().enumerate() {
+                candidates[2 * i + j] = (A * x).inverse().and_then(|inv| {
+                    let s = (one + root) * inv;
+                    let t = (one - A * s.square()) * y.inverse()?;
+                    let k = (t + one) * (s.square() * c).inverse()?;
+                    if s.is_nonnegative() {
+                        Some((one - k) * (one + k).inverse()?)
+                    } else {
+                        Some((one + k) * (one - k).inverse()?)
+                    }
+                });
+            }
+        }
+        candidates
+    }
 }
 
 impl Encoding {
@@ -286,6 +611,79 @@ impl Encoding {
 
         Ok(Element::new(x, y, z, t))
     }
+
+    /// Decompress a batch of encodings, equivalent to calling
+    /// `vartime_decompress` on each of them, but reporting the index of the
+    /// first invalid encoding.
+    ///
+    /// No work is shared between encodings. Decompression already produces
+    /// affine points, so there is no inversion to batch, and the inverse square
+    /// root of step 5 cannot be batched either: it is also the check that
+    /// `u_2` is square, and a product of values can be square when the values
+    /// are not, so each encoding needs its own quadratic residuosity test.
+    #[cfg(feature = "alloc")]
+    pub fn batch_vartime_decompress(
+        encodings: &[Encoding],
+    ) -> Result<Vec<Element>, (usize, EncodingError)> {
+        encodings
+            .iter()
+            .enumerate()
+            .map(|(i, encoding)| encoding.vartime_decompress().map_err(|e| (i, e)))
+            .collect()
+    }
+
+    /// Constant-time equivalent of `vartime_decompress`.
+    ///
+    /// Every validation step is evaluated regardless of whether an earlier one
+    /// failed, and the result is only available through the returned `CtOption`.
+    pub fn decompress_ct(&self) -> CtOption<Element> {
+        // Top three bits of last byte must be zero
+        let top_bits_clear = (self.0[31] >> 5).ct_eq(&0u8);
+
+        // 1/2. Reject unless s is canonically encoded and nonnegative.
+        let s = Fq::from_raw_bytes(&self.0);
+        let is_canonical = s.to_bytes_le().ct_eq(&self.0);
+        let is_nonnegative = s.ct_is_nonnegative();
+
+        // 3. u_1 <- 1 - s^2
+        let ss = s.square();
+        let u_1 = Fq::ONE - ss;
+
+        // 4. u_2 <- u_1^2 - 4d s^2
+        let u_2 = u_1.square() - (Fq::from(4u32) * COEFF_D) * ss;
+
+        // 5. sqrt. The ratio is square exactly when its denominator is a
+        // nonzero square, in which case we can take the root of the inverse.
+        let den = u_2 * u_1.square();
+        let was_square = den
+            .pow_le_limbs(&Fq::MODULUS_MINUS_ONE_DIV_TWO_LIMBS)
+            .ct_eq(&Fq::ONE);
+        let mut v = den.ct_inverse().ct_sqrt();
+
+        // 6. sign check
+        let two_s_u_1 = (Fq::ONE + Fq::ONE) * s * u_1;
+        let check = two_s_u_1 * v;
+        v = Fq::conditional_select(&v, &-v, check.ct_is_negative());
+
+        // 7. coordinates
+        let x = two_s_u_1 * v.square() * u_2;
+        let y = (Fq::ONE + ss) * v * u_1;
+        let z = Fq::ONE;
+        let t = x * y;
+
+        let is_valid = top_bits_clear & is_canonical & is_nonnegative & was_square;
+
+        debug_assert!(
+            !bool::from(is_valid) || Element::new_checked(x, y, z, t).is_some(),
+            "decompression should be on curve"
+        );
+
+        let element = Element { x, y, z, t };
+        CtOption::new(
+            Element::conditional_select(&Element::IDENTITY, &element, is_valid),
+            is_valid,
+        )
+    }
 }
 
 impl Add for Element {
@@ -342,9 +740,23 @@ impl PartialEq for Element {
 #[cfg(test)]
 mod test {
     use super::*;
+    use proptest::prelude::*;
 
     use crate::Fr;
 
+    #[test]
+    fn test_zeroize_clears_coordinates() {
+        let mut element = Element::GENERATOR;
+        element.zeroize();
+        for coordinate in [element.x, element.y, element.z, element.t] {
+            assert_eq!(coordinate, Fq::ZERO);
+        }
+
+        let mut point = AffinePoint::IDENTITY;
+        point.zeroize();
+        assert_eq!((point.x, point.y), (Fq::ZERO, Fq::ZERO));
+    }
+
     #[test]
     fn test_basic_equalities() {
         assert_eq!(Element::GENERATOR, Element::GENERATOR);
@@ -388,6 +800,121 @@ mod test {
         let generator = Element::GENERATOR;
         assert_eq!(generator - generator, Element::IDENTITY);
     }
+
+    #[test]
+    fn test_compress_identity_and_generator() {
+        assert_eq!(Element::IDENTITY.compress().0, [0u8; 32]);
+        assert_eq!(
+            Element::GENERATOR.compress(),
+            Element::GENERATOR.vartime_compress()
+        );
+    }
+
+    #[test]
+    fn test_decompress_ct_generator() {
+        let encoding = Element::GENERATOR.vartime_compress();
+        let decoded: Option<Element> = encoding.decompress_ct().into();
+        assert_eq!(decoded, Some(Element::GENERATOR));
+
+        let mut bad_encoding = encoding;
+        bad_encoding.0[31] |= 0b1000_0000;
+        assert!(bool::from(bad_encoding.decompress_ct().is_none()));
+    }
+
+    proptest! {
+        #[test]
+        fn compress_matches_vartime_compress(bytes: [u8; 32]) {
+            let point = Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&bytes));
+            assert_eq!(point.compress(), point.vartime_compress());
+        }
+
+        #[test]
+        fn encode_to_curve_ct_matches_encode_to_curve(bytes: [u8; 32]) {
+            let r = Fq::from_le_bytes_mod_order(&bytes);
+            assert_eq!(Element::encode_to_curve_ct(&r), Element::encode_to_curve(&r));
+        }
+
+        #[test]
+        fn sqrt_ratio_zeta_ct_matches_non_arkworks(u_bytes: [u8; 32], v_bytes: [u8; 32]) {
+            let u = Fq::from_le_bytes_mod_order(&u_bytes);
+            let v = Fq::from_le_bytes_mod_order(&v_bytes);
+            let (was_square, root) = Fq::non_arkworks_sqrt_ratio_zeta(&u, &v);
+            let (was_square_ct, root_ct) = Fq::sqrt_ratio_zeta_ct(&u, &v);
+            assert_eq!(was_square, bool::from(was_square_ct));
+            assert_eq!(root.square(), root_ct.square());
+        }
+
+        #[test]
+        fn windowed_mul_matches_scalar_mul_vartime(p_bytes: [u8; 32], s_bytes: [u8; 32]) {
+            let point = Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&p_bytes));
+            let scalar = Fr::from_le_bytes_mod_order(&s_bytes);
+            assert_eq!(point * scalar, point.scalar_mul_vartime(&scalar.to_le_limbs()));
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn batch_compress_matches_vartime_compress(a: [u8; 32], b: [u8; 32]) {
+            let points = [
+                Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&a)).double(),
+                Element::IDENTITY,
+                Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&b)),
+            ];
+            let expected: alloc::vec::Vec<Encoding> =
+                points.iter().map(|p| p.vartime_compress()).collect();
+            assert_eq!(Element::batch_compress(&points), expected);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn batch_vartime_double_and_compress_matches_vartime_compress(a: [u8; 32], b: [u8; 32]) {
+            let points = [
+                Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&a)).double(),
+                Element::IDENTITY,
+                Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&b)),
+            ];
+            let expected: alloc::vec::Vec<Encoding> =
+                points.iter().map(|p| (*p + *p).vartime_compress()).collect();
+            assert_eq!(Element::batch_vartime_double_and_compress(&points), expected);
+        }
+
+        #[cfg(feature = "alloc")]
+        #[test]
+        fn batch_vartime_decompress_round_trip(a: [u8; 32], b: [u8; 32]) {
+            let points = [
+                Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&a)),
+                Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&b)),
+            ];
+            let mut encodings: alloc::vec::Vec<Encoding> =
+                points.iter().map(|p| p.vartime_compress()).collect();
+            assert_eq!(Encoding::batch_vartime_decompress(&encodings), Ok(points.to_vec()));
+
+            encodings[1].0[31] |= 0b1000_0000;
+            assert_eq!(
+                Encoding::batch_vartime_decompress(&encodings),
+                Err((1, EncodingError::InvalidEncoding))
+            );
+        }
+
+        #[test]
+        fn elligator_inverse_round_trip(bytes: [u8; 32]) {
+            let r_0 = Fq::from_le_bytes_mod_order(&bytes);
+            let point = Element::encode_to_curve(&r_0);
+            let preimages = point.elligator_inverse();
+
+            assert!(preimages.contains(&Some(r_0)));
+            assert!(preimages.contains(&Some(-r_0)));
+            for r in preimages.into_iter().flatten() {
+                assert_eq!(Element::encode_to_curve(&r), point);
+            }
+        }
+
+        #[test]
+        fn decompress_ct_matches_vartime_decompress(bytes: [u8; 32]) {
+            let encoding = Encoding(bytes);
+            let ct: Option<Element> = encoding.decompress_ct().into();
+            assert_eq!(ct, encoding.vartime_decompress().ok());
+        }
+    }
 }
 
 impl From<&Element> for Encoding {
@@ -1,4 +1,12 @@
+use zeroize::Zeroize;
+
 use crate::EncodingError;
 
 #[derive(Copy, Clone, Default, Eq, Ord, PartialOrd, PartialEq, Debug)]
 pub struct Encoding(pub [u8; 32]);
+
+impl Zeroize for Encoding {
+    fn zeroize(&mut self) {
+        self.0.zeroize()
+    }
+}
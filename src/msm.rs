@@ -0,0 +1,167 @@
+//! Variable-time multiscalar multiplication.
+//!
+//! This is written only in terms of group operations on [`Element`], so that
+//! both backends share it. Straus' method works through the inputs in
+//! fixed-size batches on the stack. Pippenger's method sizes its window and
+//! buckets from the input length, so it is only available with `alloc`, and
+//! without it Straus' method handles inputs of any size.
+
+#[cfg(feature = "alloc")]
+use alloc::{vec, vec::Vec};
+
+use crate::{window::LookupTable, Element, Fr};
+
+/// The number of points Straus' method handles at once.
+const STRAUS_BATCH_SIZE: usize = 16;
+
+/// The input size from which Pippenger's method outperforms Straus' method.
+#[cfg(feature = "alloc")]
+const PIPPENGER_THRESHOLD: usize = 128;
+
+pub(crate) fn vartime_multiscalar_mul(scalars: &[Fr], points: &[Element]) -> Element {
+    assert_eq!(
+        scalars.len(),
+        points.len(),
+        "the number of scalars and points must match"
+    );
+
+    #[cfg(feature = "alloc")]
+    if scalars.len() >= PIPPENGER_THRESHOLD {
+        return pippenger(scalars, points);
+    }
+    straus(scalars, points)
+}
+
+/// Interleaved signed radix-16 multiplication, sharing the doublings among a
+/// batch of points.
+fn straus(scalars: &[Fr], points: &[Element]) -> Element {
+    let mut acc = Element::IDENTITY;
+    for (scalars, points) in scalars
+        .chunks(STRAUS_BATCH_SIZE)
+        .zip(points.chunks(STRAUS_BATCH_SIZE))
+    {
+        let mut tables =
+            [LookupTable::new(Element::IDENTITY, Element::IDENTITY); STRAUS_BATCH_SIZE];
+        let mut digits = [[0i8; 64]; STRAUS_BATCH_SIZE];
+        for (i, (scalar, point)) in scalars.iter().zip(points.iter()).enumerate() {
+            tables[i] = LookupTable::new(Element::IDENTITY, *point);
+            digits[i] = scalar.to_radix_16();
+        }
+        let tables = &tables[..points.len()];
+        let digits = &digits[..scalars.len()];
+
+        let mut batch_acc = Element::IDENTITY;
+        for j in (0..64).rev() {
+            batch_acc = batch_acc.mul_by_pow_2(4);
+            for (table, digits) in tables.iter().zip(digits.iter()) {
+                if digits[j] != 0 {
+                    batch_acc += table.vartime_select(digits[j]);
+                }
+            }
+        }
+        acc += batch_acc;
+    }
+    acc
+}
+
+/// Bucket method with unsigned digits of `w` bits.
+#[cfg(feature = "alloc")]
+fn pippenger(scalars: &[Fr], points: &[Element]) -> Element {
+    // Convert each scalar out of Montgomery form once, rather than once per
+    // window.
+    let limbs: Vec<[u64; 4]> = scalars.iter().map(Fr::to_le_limbs).collect();
+
+    // Roughly 6 bits for a few hundred points, growing by one bit each time
+    // the input doubles.
+    let w = (limbs.len().checked_ilog2().unwrap_or(0) as usize).saturating_sub(2);
+    let w = w.clamp(6, 16);
+    let num_windows = (Fr::MODULUS_BIT_SIZE as usize).div_ceil(w);
+    let mut buckets = vec![Element::IDENTITY; (1 << w) - 1];
+
+    let mut acc = Element::IDENTITY;
+    for j in (0..num_windows).rev() {
+        acc = acc.mul_by_pow_2(w as u32);
+
+        buckets.fill(Element::IDENTITY);
+        for (limbs, point) in limbs.iter().zip(points.iter()) {
+            let digit = window_digit(limbs, j * w, w);
+            if digit != 0 {
+                buckets[digit - 1] += *point;
+            }
+        }
+
+        // Compute sum(k * buckets[k - 1]) as a sum of running sums.
+        let mut running_sum = Element::IDENTITY;
+        let mut window_sum = Element::IDENTITY;
+        for bucket in buckets.iter().rev() {
+            running_sum += *bucket;
+            window_sum += running_sum;
+        }
+        acc += window_sum;
+    }
+    acc
+}
+
+/// Extract the `w` bits of `limbs` starting at bit `offset`.
+#[cfg(feature = "alloc")]
+fn window_digit(limbs: &[u64; 4], offset: usize, w: usize) -> usize {
+    let (limb, shift) = (offset / 64, offset % 64);
+    let mut bits = limbs[limb] >> shift;
+    if shift + w > 64 && limb + 1 < limbs.len() {
+        bits |= limbs[limb + 1] << (64 - shift);
+    }
+    (bits & ((1 << w) - 1)) as usize
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn naive_multiscalar_mul(scalars: &[Fr], points: &[Element]) -> Element {
+        scalars
+            .iter()
+            .zip(points.iter())
+            .fold(Element::IDENTITY, |acc, (scalar, point)| {
+                acc + *point * *scalar
+            })
+    }
+
+    /// Enough inputs for Pippenger's method to use an 8-bit window.
+    const MAX_TEST_INPUTS: usize = 1025;
+
+    fn test_inputs(n: usize) -> ([Fr; MAX_TEST_INPUTS], [Element; MAX_TEST_INPUTS]) {
+        let mut scalars = [Fr::ZERO; MAX_TEST_INPUTS];
+        let mut points = [Element::IDENTITY; MAX_TEST_INPUTS];
+        let mut scalar = -Fr::from(0x1234_5678_9abc_def0u64);
+        let mut point = Element::GENERATOR;
+        for i in 0..n {
+            scalars[i] = scalar;
+            points[i] = point;
+            scalar = scalar.square() + Fr::ONE;
+            point = point + point + point + Element::GENERATOR;
+        }
+        (scalars, points)
+    }
+
+    #[test]
+    fn straus_and_pippenger_match_naive() {
+        for n in [0, 1, 2, 17, 129, MAX_TEST_INPUTS] {
+            let (scalars, points) = test_inputs(n);
+            let (scalars, points) = (&scalars[..n], &points[..n]);
+            let expected = naive_multiscalar_mul(scalars, points);
+            assert_eq!(straus(scalars, points), expected);
+            #[cfg(feature = "alloc")]
+            assert_eq!(pippenger(scalars, points), expected);
+            assert_eq!(vartime_multiscalar_mul(scalars, points), expected);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn window_digit_spans_limbs() {
+        let limbs = [0xf000_0000_0000_0000, 0x5, 0, 0];
+        assert_eq!(window_digit(&limbs, 60, 6), 0x1f);
+        assert_eq!(window_digit(&limbs, 64, 8), 0x5);
+        assert_eq!(window_digit(&limbs, 252, 8), 0);
+    }
+}
@@ -0,0 +1,139 @@
+//! Pedersen commitments to scalars and vectors of scalars.
+//!
+//! The generators are derived from a domain separator with
+//! [`Element::hash_to_group`], so nobody knows the discrete logarithm of one
+//! generator with respect to another.
+
+use alloc::vec::Vec;
+
+use digest::{crypto_common::BlockSizeUser, Digest};
+
+use crate::{Element, Fr};
+
+/// Independent generators for committing to up to `n` scalars.
+#[derive(Clone, Debug)]
+pub struct Generators {
+    values: Vec<Element>,
+    blinding: Element,
+}
+
+impl Generators {
+    /// Derive `n` value generators and a blinding generator from
+    /// `domain_separator`, hashing to the group with `H`.
+    pub fn new<H: Digest + BlockSizeUser>(domain_separator: &[u8], n: usize) -> Self {
+        let values = (0..n as u64)
+            .map(|i| {
+                let mut msg = [0u8; 13];
+                msg[..5].copy_from_slice(b"value");
+                msg[5..].copy_from_slice(&i.to_le_bytes());
+                Element::hash_to_group::<H>(domain_separator, &msg)
+            })
+            .collect();
+        let blinding = Element::hash_to_group::<H>(domain_separator, b"blinding");
+        Self { values, blinding }
+    }
+
+    /// The generators the committed values are multiplied by.
+    pub fn value_generators(&self) -> &[Element] {
+        &self.values
+    }
+
+    /// The generator the blinding factor is multiplied by.
+    pub fn blinding_generator(&self) -> Element {
+        self.blinding
+    }
+
+    /// Commit to a single `value`, using the first value generator.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no value generators.
+    pub fn commit(&self, value: Fr, blinding: Fr) -> Element {
+        self.commit_vector(&[value], blinding)
+    }
+
+    /// Commit to `values`, as `sum_i values[i] * G_i + blinding * H`.
+    ///
+    /// This runs in constant time with respect to the values and blinding
+    /// factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are more values than value generators.
+    pub fn commit_vector(&self, values: &[Fr], blinding: Fr) -> Element {
+        assert!(
+            values.len() <= self.values.len(),
+            "cannot commit to {} values with {} generators",
+            values.len(),
+            self.values.len()
+        );
+        values
+            .iter()
+            .zip(self.values.iter())
+            .fold(self.blinding * blinding, |acc, (value, generator)| {
+                acc + *generator * *value
+            })
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use sha2::Sha512;
+
+    fn generators(n: usize) -> Generators {
+        Generators::new::<Sha512>(b"decaf377-pedersen-test", n)
+    }
+
+    #[test]
+    fn generators_are_distinct_and_domain_separated() {
+        let gens = generators(4);
+        let other = Generators::new::<Sha512>(b"decaf377-pedersen-other", 4);
+
+        let mut all = gens.value_generators().to_vec();
+        all.push(gens.blinding_generator());
+        all.extend_from_slice(other.value_generators());
+        all.push(other.blinding_generator());
+        for (i, a) in all.iter().enumerate() {
+            assert_ne!(*a, Element::IDENTITY);
+            for b in &all[i + 1..] {
+                assert_ne!(a, b);
+            }
+        }
+
+        // Deriving more generators extends the existing ones.
+        assert_eq!(
+            &generators(6).value_generators()[..4],
+            gens.value_generators()
+        );
+    }
+
+    #[test]
+    fn commitments_are_additively_homomorphic() {
+        let gens = generators(3);
+        let a = [Fr::from(1u64), Fr::from(2u64), Fr::from(3u64)];
+        let b = [Fr::from(10u64), -Fr::from(20u64), Fr::from(30u64)];
+        let sum = [a[0] + b[0], a[1] + b[1], a[2] + b[2]];
+        let (r, s) = (Fr::from(1234u64), Fr::from(5678u64));
+
+        assert_eq!(
+            gens.commit_vector(&a, r) + gens.commit_vector(&b, s),
+            gens.commit_vector(&sum, r + s)
+        );
+        assert_eq!(
+            gens.commit(a[0], r),
+            gens.value_generators()[0] * a[0] + gens.blinding_generator() * r
+        );
+        assert_eq!(
+            gens.commit_vector(&a[..2], r),
+            gens.commit_vector(&[a[0], a[1], Fr::ZERO], r)
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn commit_vector_rejects_too_many_values() {
+        generators(1).commit_vector(&[Fr::ONE, Fr::ONE], Fr::ONE);
+    }
+}
@@ -0,0 +1,325 @@
+//! Schnorr signatures over decaf377.
+//!
+//! A signature on `msg` under the verification key `A = a * B` is a pair
+//! `(R, s)` with `R = r * B` and `s = r + c * a`, where the challenge
+//! `c = H(R || A || msg)` and `H` is SHA-512 with a domain separator, reduced
+//! modulo the group order. The nonce `r` is derived deterministically from the
+//! signing key and the message, so signing needs no randomness.
+
+#![allow(non_snake_case)]
+
+use rand_core::CryptoRngCore;
+use sha2::{Digest, Sha512};
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+use crate::{Element, Encoding, Fr, SignatureError, GENERATOR_TABLE};
+
+const NONCE_DOMAIN_SEPARATOR: &[u8] = b"decaf377-schnorr-nonce";
+pub(crate) const CHALLENGE_DOMAIN_SEPARATOR: &[u8] = b"decaf377-schnorr-challenge";
+
+/// A key used to create signatures, zeroized on drop.
+pub struct SigningKey {
+    sk: Fr,
+    vk: VerificationKey,
+}
+
+/// A key used to verify signatures.
+#[derive(Copy, Clone, Debug)]
+pub struct VerificationKey {
+    A: Element,
+    A_bytes: Encoding,
+}
+
+/// A 64-byte signature, consisting of the encoding of `R` and the scalar `s`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Signature {
+    R_bytes: Encoding,
+    s: Fr,
+}
+
+impl PartialEq for VerificationKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.A_bytes == other.A_bytes
+    }
+}
+
+impl Eq for VerificationKey {}
+
+impl core::fmt::Debug for SigningKey {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("SigningKey")
+            .field("vk", &self.vk)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Zeroize for SigningKey {
+    fn zeroize(&mut self) {
+        self.sk.zeroize()
+    }
+}
+
+impl Drop for SigningKey {
+    fn drop(&mut self) {
+        self.zeroize()
+    }
+}
+
+impl ZeroizeOnDrop for SigningKey {}
+
+impl SigningKey {
+    /// Generate a new random signing key.
+    pub fn new<R: CryptoRngCore>(rng: &mut R) -> Self {
+        loop {
+            if let Ok(key) = Self::from_scalar(Fr::rand(rng)) {
+                return key;
+            }
+        }
+    }
+
+    /// Parse a signing key from its canonical encoding as a nonzero scalar.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, SignatureError> {
+        let sk = Fr::from_bytes_checked(bytes).map_err(|_| SignatureError::InvalidSigningKey)?;
+        Self::from_scalar(sk)
+    }
+
+    fn from_scalar(sk: Fr) -> Result<Self, SignatureError> {
+        if sk == Fr::ZERO {
+            return Err(SignatureError::InvalidSigningKey);
+        }
+        let A = GENERATOR_TABLE.mul(&sk);
+        let vk = VerificationKey {
+            A,
+            A_bytes: A.vartime_compress(),
+        };
+        Ok(Self { sk, vk })
+    }
+
+    /// Encode this signing key as its scalar.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.sk.to_bytes()
+    }
+
+    /// The verification key for signatures made with this key.
+    pub fn verification_key(&self) -> VerificationKey {
+        self.vk
+    }
+
+    /// Sign `msg`, using a nonce derived from the signing key and `msg`.
+    pub fn sign(&self, msg: &[u8]) -> Signature {
+        let mut sk_bytes = self.sk.to_bytes();
+        let mut r = hash_to_scalar(&[NONCE_DOMAIN_SEPARATOR, &sk_bytes, msg]);
+        sk_bytes.zeroize();
+
+        let R_bytes = GENERATOR_TABLE.mul(&r).vartime_compress();
+        let c = challenge(&R_bytes, &self.vk.A_bytes, msg);
+        let s = r + c * self.sk;
+        r.zeroize();
+
+        Signature { R_bytes, s }
+    }
+}
+
+impl VerificationKey {
+    /// Parse a verification key, rejecting invalid encodings and the identity.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self, SignatureError> {
+        let A_bytes = Encoding(*bytes);
+        let A = A_bytes
+            .vartime_decompress()
+            .map_err(|_| SignatureError::InvalidVerificationKey)?;
+        if A == Element::IDENTITY {
+            return Err(SignatureError::InvalidVerificationKey);
+        }
+        Ok(Self { A, A_bytes })
+    }
+
+    /// Encode this verification key as the encoding of `A`.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.A_bytes.0
+    }
+
+    /// Check that `signature` is a valid signature on `msg` under this key.
+    ///
+    /// This checks that `s * B - c * A` encodes to `R`, so `R` must be encoded
+    /// canonically.
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> Result<(), SignatureError> {
+        let c = challenge(&signature.R_bytes, &self.A_bytes, msg);
+        let R = Element::vartime_multiscalar_mul(&[signature.s, -c], &[Element::GENERATOR, self.A]);
+        if R.vartime_compress() == signature.R_bytes {
+            Ok(())
+        } else {
+            Err(SignatureError::InvalidSignature)
+        }
+    }
+}
+
+impl Signature {
+    /// Parse a signature, rejecting a non-canonical encoding of `s`.
+    ///
+    /// The encoding of `R` is only checked during verification.
+    pub fn from_bytes(bytes: &[u8; 64]) -> Result<Self, SignatureError> {
+        let mut R_bytes = [0u8; 32];
+        let mut s_bytes = [0u8; 32];
+        R_bytes.copy_from_slice(&bytes[..32]);
+        s_bytes.copy_from_slice(&bytes[32..]);
+        let s = Fr::from_bytes_checked(&s_bytes).map_err(|_| SignatureError::InvalidSignature)?;
+        Ok(Self {
+            R_bytes: Encoding(R_bytes),
+            s,
+        })
+    }
+
+    /// Encode this signature as the encoding of `R` followed by `s`.
+    pub fn to_bytes(&self) -> [u8; 64] {
+        let mut bytes = [0u8; 64];
+        bytes[..32].copy_from_slice(&self.R_bytes.0);
+        bytes[32..].copy_from_slice(&self.s.to_bytes());
+        bytes
+    }
+}
+
+/// Verify a batch of `(key, message, signature)` triples at once.
+///
+/// Each equation `s_i * B = R_i + c_i * A_i` is multiplied by a random `z_i`,
+/// and the sum is checked with a single multiscalar multiplication. This
+/// succeeds exactly when every signature would pass [`VerificationKey::verify`],
+/// except with negligible probability, but does not say which one failed.
+#[cfg(feature = "alloc")]
+pub fn verify_batch<R: CryptoRngCore>(
+    items: &[(VerificationKey, &[u8], Signature)],
+    rng: &mut R,
+) -> Result<(), SignatureError> {
+    use alloc::vec::Vec;
+
+    let mut scalars = Vec::with_capacity(2 * items.len() + 1);
+    let mut points = Vec::with_capacity(2 * items.len() + 1);
+    let mut B_coefficient = Fr::ZERO;
+    for (vk, msg, signature) in items {
+        let R = signature
+            .R_bytes
+            .vartime_decompress()
+            .map_err(|_| SignatureError::InvalidSignature)?;
+        let c = challenge(&signature.R_bytes, &vk.A_bytes, msg);
+        let z = Fr::rand(rng);
+
+        B_coefficient += z * signature.s;
+        scalars.push(-z);
+        points.push(R);
+        scalars.push(-(z * c));
+        points.push(vk.A);
+    }
+    scalars.push(B_coefficient);
+    points.push(Element::GENERATOR);
+
+    if Element::vartime_multiscalar_mul(&scalars, &points) == Element::IDENTITY {
+        Ok(())
+    } else {
+        Err(SignatureError::InvalidSignature)
+    }
+}
+
+fn challenge(R_bytes: &Encoding, A_bytes: &Encoding, msg: &[u8]) -> Fr {
+    hash_to_scalar(&[CHALLENGE_DOMAIN_SEPARATOR, &R_bytes.0, &A_bytes.0, msg])
+}
+
+fn hash_to_scalar(inputs: &[&[u8]]) -> Fr {
+    let mut hasher = Sha512::new();
+    for input in inputs {
+        hasher.update(input);
+    }
+    Fr::from_le_bytes_mod_order(&hasher.finalize())
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    use rand_core::OsRng;
+
+    #[test]
+    fn sign_and_verify() {
+        let sk = SigningKey::new(&mut OsRng);
+        let vk = sk.verification_key();
+        let signature = sk.sign(b"hello");
+
+        assert_eq!(vk.verify(b"hello", &signature), Ok(()));
+        assert_eq!(
+            vk.verify(b"goodbye", &signature),
+            Err(SignatureError::InvalidSignature)
+        );
+        let other = SigningKey::new(&mut OsRng).verification_key();
+        assert_eq!(
+            other.verify(b"hello", &signature),
+            Err(SignatureError::InvalidSignature)
+        );
+
+        // Signing is deterministic.
+        assert_eq!(sk.sign(b"hello"), signature);
+    }
+
+    #[test]
+    fn keys_and_signatures_roundtrip() {
+        let sk = SigningKey::new(&mut OsRng);
+        let vk = sk.verification_key();
+        let signature = sk.sign(b"hello");
+
+        let sk2 = SigningKey::from_bytes(&sk.to_bytes()).unwrap();
+        assert_eq!(sk2.verification_key(), vk);
+        assert_eq!(VerificationKey::from_bytes(&vk.to_bytes()), Ok(vk));
+        assert_eq!(Signature::from_bytes(&signature.to_bytes()), Ok(signature));
+    }
+
+    #[test]
+    fn zeroize_clears_signing_key() {
+        let mut sk = SigningKey::new(&mut OsRng);
+        sk.zeroize();
+        assert_eq!(sk.to_bytes(), [0u8; 32]);
+    }
+
+    #[test]
+    fn rejects_degenerate_keys_and_signatures() {
+        assert_eq!(
+            SigningKey::from_bytes(&[0u8; 32]).unwrap_err(),
+            SignatureError::InvalidSigningKey
+        );
+        assert_eq!(
+            SigningKey::from_bytes(&[0xff; 32]).unwrap_err(),
+            SignatureError::InvalidSigningKey
+        );
+        assert_eq!(
+            VerificationKey::from_bytes(&Element::IDENTITY.vartime_compress().0),
+            Err(SignatureError::InvalidVerificationKey)
+        );
+
+        let mut bytes = [0u8; 64];
+        bytes[32..].copy_from_slice(&[0xff; 32]);
+        assert_eq!(
+            Signature::from_bytes(&bytes),
+            Err(SignatureError::InvalidSignature)
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_verification() {
+        use alloc::vec::Vec;
+
+        let msgs: [&[u8]; 4] = [b"", b"a", b"bc", b"def"];
+        let mut items: Vec<(VerificationKey, &[u8], Signature)> = msgs
+            .iter()
+            .map(|msg| {
+                let sk = SigningKey::new(&mut OsRng);
+                (sk.verification_key(), *msg, sk.sign(msg))
+            })
+            .collect();
+
+        assert_eq!(verify_batch(&[], &mut OsRng), Ok(()));
+        assert_eq!(verify_batch(&items, &mut OsRng), Ok(()));
+
+        items[2].1 = b"bd";
+        assert_eq!(
+            verify_batch(&items, &mut OsRng),
+            Err(SignatureError::InvalidSignature)
+        );
+    }
+}
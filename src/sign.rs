@@ -1,3 +1,5 @@
+use subtle::{Choice, ConditionallySelectable};
+
 use crate::Fq;
 
 pub trait Sign: core::ops::Neg<Output = Self> + Sized {
@@ -14,10 +16,31 @@ pub trait Sign: core::ops::Neg<Output = Self> + Sized {
             -self
         }
     }
+
+    /// Constant-time equivalent of `is_nonnegative`.
+    fn ct_is_nonnegative(&self) -> Choice;
+
+    /// Constant-time equivalent of `is_negative`.
+    fn ct_is_negative(&self) -> Choice {
+        !self.ct_is_nonnegative()
+    }
+
+    /// Constant-time equivalent of `abs`.
+    fn ct_abs(self) -> Self
+    where
+        Self: ConditionallySelectable,
+    {
+        let is_negative = self.ct_is_negative();
+        Self::conditional_select(&self, &-self, is_negative)
+    }
 }
 
 impl Sign for Fq {
     fn is_nonnegative(&self) -> bool {
         (self.to_le_limbs()[0] & 1) == 0
     }
+
+    fn ct_is_nonnegative(&self) -> Choice {
+        !Choice::from((self.to_le_limbs()[0] & 1) as u8)
+    }
 }
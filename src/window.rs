@@ -0,0 +1,71 @@
+use core::ops::{Add, Neg};
+
+use subtle::{ConditionallySelectable, ConstantTimeEq};
+
+/// A table of the multiples `[0P, 1P, ..., 8P]` of a point `P`, used for
+/// signed radix-16 scalar multiplication.
+///
+/// Entries are read with [`LookupTable::select`], which touches every entry
+/// and so does not leak the digit through memory access patterns.
+#[derive(Clone, Copy)]
+pub(crate) struct LookupTable<T>(pub(crate) [T; 9]);
+
+impl<T> LookupTable<T>
+where
+    T: Copy + Add<Output = T> + Neg<Output = T> + ConditionallySelectable,
+{
+    /// Build the table of multiples of `point`.
+    pub(crate) fn new(identity: T, point: T) -> Self {
+        let mut table = [identity; 9];
+        for i in 1..9 {
+            table[i] = table[i - 1] + point;
+        }
+        Self(table)
+    }
+
+    /// Compute `x * P` for a digit `-8 <= x <= 8` in constant time.
+    pub(crate) fn select(&self, x: i8) -> T {
+        debug_assert!((-8..=8).contains(&x));
+
+        // Compute |x| without branching on the sign of x.
+        let xmask = (x as i16) >> 7;
+        let xabs = ((x as i16 + xmask) ^ xmask) as u16;
+
+        let mut t = self.0[0];
+        for (j, entry) in self.0.iter().enumerate().skip(1) {
+            t.conditional_assign(entry, xabs.ct_eq(&(j as u16)));
+        }
+
+        let neg_mask = ((xmask & 1) as u8).into();
+        T::conditional_select(&t, &-t, neg_mask)
+    }
+
+    /// Compute `x * P` for a digit `-8 <= x <= 8` in variable time.
+    pub(crate) fn vartime_select(&self, x: i8) -> T {
+        let t = self.0[x.unsigned_abs() as usize];
+        if x < 0 {
+            -t
+        } else {
+            t
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::Element;
+
+    #[test]
+    fn select_matches_small_multiples() {
+        let table = LookupTable::new(Element::IDENTITY, Element::GENERATOR);
+        let mut multiple = Element::IDENTITY;
+        for x in 0i8..=8 {
+            assert_eq!(table.select(x), multiple);
+            assert_eq!(table.select(-x), -multiple);
+            assert_eq!(table.vartime_select(x), multiple);
+            assert_eq!(table.vartime_select(-x), -multiple);
+            multiple += Element::GENERATOR;
+        }
+    }
+}
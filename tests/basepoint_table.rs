@@ -0,0 +1,74 @@
+use std::fmt::Write;
+
+use ark_ec::{AffineRepr, CurveGroup};
+use decaf377::{Element, Fq, Fr, GENERATOR_TABLE};
+use proptest::prelude::*;
+
+fn fr_strategy() -> BoxedStrategy<Fr> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Fr::from_le_bytes_mod_order(&bytes[..]))
+        .boxed()
+}
+
+proptest! {
+    #[test]
+    fn generator_table_mul_matches_scalar_mul(a in fr_strategy()) {
+        assert_eq!(GENERATOR_TABLE.mul(&a), a * Element::GENERATOR);
+        assert_eq!(GENERATOR_TABLE.vartime_mul(&a), a * Element::GENERATOR);
+    }
+}
+
+/// Return the limbs of the Montgomery representation `x * 2^256` of `x`.
+fn montgomery_limbs(x: &Fq) -> [u64; 4] {
+    let bytes = (*x * Fq::FIELD_SIZE_POWER_OF_TWO).to_bytes_le();
+    let mut limbs = [0u64; 4];
+    for (limb, chunk) in limbs.iter_mut().zip(bytes.chunks(8)) {
+        *limb = u64::from_le_bytes(chunk.try_into().expect("chunk has 8 bytes"));
+    }
+    limbs
+}
+
+#[ignore]
+#[test]
+/// Generates `src/basepoint_table/generator.rs` for the conventional generator.
+fn generate_generator_table() {
+    let mut out = String::new();
+    writeln!(
+        out,
+        "// This file is generated by `generate_generator_table` in"
+    )
+    .unwrap();
+    writeln!(
+        out,
+        "// `tests/basepoint_table.rs`; do not edit it by hand."
+    )
+    .unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "#[rustfmt::skip]").unwrap();
+    writeln!(
+        out,
+        "pub(super) static GENERATOR_TABLE_LIMBS: [[[[u64; 4]; 3]; 8]; 32] = ["
+    )
+    .unwrap();
+
+    let mut base = Element::GENERATOR;
+    for _ in 0..32 {
+        writeln!(out, "    [").unwrap();
+        let mut point = base;
+        for _ in 0..8 {
+            let affine = point.into_affine();
+            let (x, y) = affine.xy().expect("multiples of the generator are finite");
+            writeln!(out, "        [").unwrap();
+            for coordinate in [*x, *y, *x * *y] {
+                writeln!(out, "            {:?},", montgomery_limbs(&coordinate)).unwrap();
+            }
+            writeln!(out, "        ],").unwrap();
+            point += base;
+        }
+        writeln!(out, "    ],").unwrap();
+        base = Fr::from(256u64) * base;
+    }
+    writeln!(out, "];").unwrap();
+
+    std::fs::write("src/basepoint_table/generator.rs", out).expect("can write generator table");
+}
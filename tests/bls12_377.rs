@@ -0,0 +1,209 @@
+use ark_bls12_377::{Bls12_377, Fq12 as ArkFq12, Fq2 as ArkFq2};
+use ark_bls12_377::{Fr as ArkFr, G1Affine as ArkG1Affine, G2Affine as ArkG2Affine};
+use ark_ec::{
+    hashing::{curve_maps::wb::WBMap, map_to_curve_hasher::MapToCurveBasedHasher, HashToCurve},
+    pairing::Pairing,
+    short_weierstrass::Projective as ArkProjective,
+    AffineRepr, CurveGroup,
+};
+use ark_ff::{field_hashers::DefaultFieldHasher, Field, PrimeField};
+use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
+use proptest::prelude::*;
+use sha2::Sha256;
+
+use decaf377::{
+    bls,
+    bls12_377::{
+        multi_pairing, pairing, G1Affine, G1Projective, G2Affine, G2Prepared, G2Projective,
+    },
+    fields::{Fp12, Fp2, Fp6},
+    Fp, Fq,
+};
+
+fn fq_strategy() -> BoxedStrategy<Fq> {
+    any::<[u8; 32]>()
+        .prop_map(|bytes| Fq::from_le_bytes_mod_order(&bytes[..]))
+        .boxed()
+}
+
+fn fp2_strategy() -> BoxedStrategy<Fp2> {
+    any::<[[u8; 32]; 2]>()
+        .prop_map(|[c0, c1]| {
+            Fp2::new(
+                Fp::from_le_bytes_mod_order(&c0),
+                Fp::from_le_bytes_mod_order(&c1),
+            )
+        })
+        .boxed()
+}
+
+fn fp12_strategy() -> BoxedStrategy<Fp12> {
+    prop::array::uniform6(fp2_strategy())
+        .prop_map(|c| Fp12::new(Fp6::new(c[0], c[1], c[2]), Fp6::new(c[3], c[4], c[5])))
+        .boxed()
+}
+
+fn ark_bytes<T: CanonicalSerialize>(x: &T) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    x.serialize_compressed(&mut bytes).unwrap();
+    bytes
+}
+
+fn to_ark(scalar: Fq) -> ArkFr {
+    ArkFr::from_le_bytes_mod_order(&scalar.to_bytes())
+}
+
+type ArkG2Hasher = MapToCurveBasedHasher<
+    ArkProjective<ark_bls12_377::g2::Config>,
+    DefaultFieldHasher<Sha256, 128>,
+    WBMap<ark_bls12_377::g2::Config>,
+>;
+
+#[test]
+fn identity_encodings_match_arkworks() {
+    let mut ark_g1 = [0u8; 48];
+    ArkG1Affine::zero()
+        .serialize_compressed(&mut ark_g1[..])
+        .unwrap();
+    assert_eq!(G1Affine::IDENTITY.to_compressed(), ark_g1);
+
+    let mut ark_g2 = [0u8; 96];
+    ArkG2Affine::zero()
+        .serialize_compressed(&mut ark_g2[..])
+        .unwrap();
+    assert_eq!(G2Affine::IDENTITY.to_compressed(), ark_g2);
+}
+
+proptest! {
+    #[test]
+    fn g1_matches_arkworks(a in fq_strategy(), b in fq_strategy()) {
+        let ours = (G1Projective::GENERATOR * a + G1Projective::GENERATOR.double() * b).to_affine();
+        let theirs = (ArkG1Affine::generator() * to_ark(a)
+            + ArkG1Affine::generator() * (to_ark(b) + to_ark(b)))
+        .into_affine();
+
+        let mut theirs_bytes = [0u8; 48];
+        theirs.serialize_compressed(&mut theirs_bytes[..]).unwrap();
+        assert_eq!(ours.to_compressed(), theirs_bytes);
+        assert_eq!(G1Affine::from_compressed(&theirs_bytes), Ok(ours));
+        assert_eq!(
+            ArkG1Affine::deserialize_compressed(&ours.to_compressed()[..]).unwrap(),
+            theirs
+        );
+    }
+
+    #[test]
+    fn g2_matches_arkworks(a in fq_strategy(), b in fq_strategy()) {
+        let ours = (G2Projective::GENERATOR * a - G2Projective::GENERATOR * b).to_affine();
+        let theirs = (ArkG2Affine::generator() * (to_ark(a) - to_ark(b))).into_affine();
+
+        let mut theirs_bytes = [0u8; 96];
+        theirs.serialize_compressed(&mut theirs_bytes[..]).unwrap();
+        assert_eq!(ours.to_compressed(), theirs_bytes);
+        assert_eq!(G2Affine::from_compressed(&theirs_bytes), Ok(ours));
+        assert_eq!(
+            ArkG2Affine::deserialize_compressed(&ours.to_compressed()[..]).unwrap(),
+            theirs
+        );
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(8))]
+    #[test]
+    fn pairing_matches_arkworks(a in fq_strategy(), b in fq_strategy(), c in fq_strategy()) {
+        let p = (G1Projective::GENERATOR * a).to_affine();
+        let q = (G2Projective::GENERATOR * b).to_affine();
+        let r = (G1Projective::GENERATOR * c).to_affine();
+        let ark_p = (ArkG1Affine::generator() * to_ark(a)).into_affine();
+        let ark_q = (ArkG2Affine::generator() * to_ark(b)).into_affine();
+        let ark_r = (ArkG1Affine::generator() * to_ark(c)).into_affine();
+
+        let mut theirs = [0u8; 576];
+        Bls12_377::pairing(ark_p, ark_q)
+            .serialize_compressed(&mut theirs[..])
+            .unwrap();
+        assert_eq!(pairing(&p, &q).to_bytes(), theirs);
+
+        let q_prepared = G2Prepared::from(q);
+        let g2_prepared = G2Prepared::from(G2Affine::GENERATOR);
+        let mut theirs = [0u8; 576];
+        Bls12_377::multi_pairing([ark_p, ark_r], [ark_q, ArkG2Affine::generator()])
+            .serialize_compressed(&mut theirs[..])
+            .unwrap();
+        assert_eq!(
+            multi_pairing(&[(&p, &q_prepared), (&r, &g2_prepared)]).to_bytes(),
+            theirs
+        );
+    }
+
+    #[test]
+    fn hash_to_g2_matches_arkworks(msg in any::<Vec<u8>>()) {
+        let dst = b"decaf377-test-BLS12377G2_XMD:SHA-256_SSWU_RO_";
+        let ours = G2Projective::hash_to_group::<Sha256>(dst, &msg).to_affine();
+        let theirs = ArkG2Hasher::new(dst).unwrap().hash(&msg).unwrap();
+        assert_eq!(ours.to_compressed()[..], ark_bytes(&theirs)[..]);
+    }
+
+    #[test]
+    fn bls_signatures_match_arkworks(sk in fq_strategy(), msg in any::<Vec<u8>>()) {
+        prop_assume!(sk != Fq::ZERO);
+        let sk = bls::SigningKey::from_bytes(&sk.to_bytes()).unwrap();
+        let vk = sk.verification_key();
+        let signature = sk.sign(&msg);
+
+        let ark_sk = ArkFr::from_le_bytes_mod_order(&sk.to_bytes());
+        let ark_vk = ArkG1Affine::generator() * ark_sk;
+        let ark_h = ArkG2Hasher::new(b"BLS_SIG_BLS12377G2_XMD:SHA-256_SSWU_RO_NUL_")
+            .unwrap()
+            .hash(&msg)
+            .unwrap();
+        let ark_signature = (ark_h * ark_sk).into_affine();
+        assert_eq!(vk.to_bytes()[..], ark_bytes(&ark_vk.into_affine())[..]);
+        assert_eq!(signature.to_bytes()[..], ark_bytes(&ark_signature)[..]);
+        assert_eq!(
+            Bls12_377::pairing(ArkG1Affine::generator(), ark_signature),
+            Bls12_377::pairing(ark_vk, ark_h)
+        );
+        assert_eq!(vk.verify(&msg, &signature), Ok(()));
+    }
+}
+
+proptest! {
+    #[test]
+    fn fp2_matches_arkworks(x in fp2_strategy(), y in fp2_strategy()) {
+        let ark_x = ArkFq2::deserialize_compressed(&x.to_bytes()[..]).unwrap();
+        let ark_y = ArkFq2::deserialize_compressed(&y.to_bytes()[..]).unwrap();
+        assert_eq!(ark_bytes(&ark_x), x.to_bytes());
+
+        assert_eq!(ark_bytes(&(ark_x * ark_y)), (x * y).to_bytes());
+        assert_eq!(ark_bytes(&(ark_x - ark_y)), (x - y).to_bytes());
+        assert_eq!(ark_bytes(&ark_x.inverse().unwrap()), x.inverse().unwrap().to_bytes());
+        assert_eq!(ark_bytes(&ark_x.frobenius_map(1)), x.frobenius_map(1).to_bytes());
+        assert_eq!(x.cmp(&y), ark_x.cmp(&ark_y));
+
+        assert_eq!(x.sqrt().is_some(), ark_x.sqrt().is_some());
+        let square = x.square();
+        let root = square.sqrt().unwrap();
+        assert!(root == x || root == -x);
+    }
+
+    #[test]
+    fn fp12_matches_arkworks(x in fp12_strategy(), y in fp12_strategy()) {
+        let ark_x = ArkFq12::deserialize_compressed(&x.to_bytes()[..]).unwrap();
+        let ark_y = ArkFq12::deserialize_compressed(&y.to_bytes()[..]).unwrap();
+        assert_eq!(ark_bytes(&ark_x), x.to_bytes());
+
+        assert_eq!(ark_bytes(&(ark_x * ark_y)), (x * y).to_bytes());
+        assert_eq!(ark_bytes(&(ark_x + ark_y)), (x + y).to_bytes());
+        assert_eq!(ark_bytes(&ark_x.square()), x.square().to_bytes());
+        assert_eq!(ark_bytes(&ark_x.inverse().unwrap()), x.inverse().unwrap().to_bytes());
+        for power in 0..12 {
+            assert_eq!(ark_bytes(&ark_x.frobenius_map(power)), x.frobenius_map(power).to_bytes());
+        }
+        assert_eq!(
+            ark_bytes(&ark_x.c0.frobenius_map(1)),
+            x.c0.frobenius_map(1).to_bytes()
+        );
+    }
+}
@@ -25,6 +25,22 @@ fn identity_encoding_is_zero() {
     assert_eq!(identity, identity2);
 }
 
+#[test]
+fn identity_constant_time_encoding_is_zero() {
+    let identity = Element::default();
+    assert_eq!(identity.compress().0, [0; 32]);
+}
+
+#[test]
+fn batch_vartime_decompress_reports_first_invalid_index() {
+    let valid = Element::GENERATOR.vartime_compress();
+    let invalid = Encoding([0xff; 32]);
+    assert_eq!(
+        Encoding::batch_vartime_decompress(&[valid, invalid, valid, invalid]),
+        Err((1, decaf377::EncodingError::InvalidEncoding))
+    );
+}
+
 #[test]
 fn check_generator() {
     let mut bytes = [0u8; 32];
@@ -105,6 +121,80 @@ proptest! {
         }
     }
 
+    #[test]
+    fn compress_matches_vartime_compress(bytes: [u8; 32]) {
+        let point = Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&bytes));
+        assert_eq!(point.compress(), point.vartime_compress());
+        assert_eq!(point.compress_to_field(), point.vartime_compress_to_field());
+    }
+
+    #[test]
+    fn decompress_ct_matches_vartime_decompress(bytes: [u8; 32]) {
+        let encoding = Encoding(bytes);
+        let ct: Option<Element> = encoding.decompress_ct().into();
+        assert_eq!(ct, encoding.vartime_decompress().ok());
+    }
+
+    #[test]
+    fn decompress_ct_round_trip(bytes: [u8; 32]) {
+        let point = Element::encode_to_curve(&Fq::from_le_bytes_mod_order(&bytes));
+        let decoded: Option<Element> = point.compress().decompress_ct().into();
+        assert_eq!(decoded, Some(point));
+    }
+
+    #[test]
+    fn batch_compress_matches_vartime_compress(
+        inputs in prop::collection::vec(any::<[u8; 32]>(), 0..8),
+    ) {
+        // Scale the points so that they are not in affine form already.
+        let points: Vec<Element> = inputs
+            .iter()
+            .map(|bytes| Fr::from(3u64) * Element::encode_to_curve(&Fq::from_le_bytes_mod_order(bytes)))
+            .chain(core::iter::once(Element::IDENTITY))
+            .collect();
+        let expected: Vec<Encoding> = points.iter().map(|p| p.vartime_compress()).collect();
+        assert_eq!(Element::batch_compress(&points), expected);
+    }
+
+    #[test]
+    fn batch_vartime_double_and_compress_matches_vartime_compress(
+        inputs in prop::collection::vec(any::<[u8; 32]>(), 0..8),
+    ) {
+        // Scale the points so that they are not in affine form already.
+        let points: Vec<Element> = inputs
+            .iter()
+            .map(|bytes| Fr::from(3u64) * Element::encode_to_curve(&Fq::from_le_bytes_mod_order(bytes)))
+            .chain(core::iter::once(Element::IDENTITY))
+            .collect();
+        let expected: Vec<Encoding> = points.iter().map(|p| (p + p).vartime_compress()).collect();
+        assert_eq!(Element::batch_vartime_double_and_compress(&points), expected);
+    }
+
+    #[test]
+    fn batch_vartime_decompress_matches_vartime_decompress(
+        inputs in prop::collection::vec(any::<[u8; 32]>(), 0..8),
+    ) {
+        let encodings: Vec<Encoding> = inputs.into_iter().map(Encoding).collect();
+        let expected = encodings
+            .iter()
+            .enumerate()
+            .map(|(i, e)| e.vartime_decompress().map_err(|err| (i, err)))
+            .collect::<Result<Vec<Element>, _>>();
+        assert_eq!(Encoding::batch_vartime_decompress(&encodings), expected);
+    }
+
+    #[test]
+    fn batch_vartime_decompress_round_trip(
+        inputs in prop::collection::vec(any::<[u8; 32]>(), 0..8),
+    ) {
+        let points: Vec<Element> = inputs
+            .iter()
+            .map(|bytes| Element::encode_to_curve(&Fq::from_le_bytes_mod_order(bytes)))
+            .collect();
+        let encodings: Vec<Encoding> = points.iter().map(|p| p.vartime_compress()).collect();
+        assert_eq!(Encoding::batch_vartime_decompress(&encodings), Ok(points));
+    }
+
     #[test]
     fn fq_encoding_round_trip_if_successful(bytes: [u8; 32]) {
         if let Ok(x) = Fq::from_bytes_checked(&bytes) {
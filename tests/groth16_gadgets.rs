@@ -5,18 +5,23 @@ use ark_serialize::{CanonicalDeserialize, CanonicalSerialize};
 use once_cell::sync::Lazy;
 use proptest::prelude::*;
 
+use ark_ec::{CurveGroup, VariableBaseMSM};
 use ark_r1cs_std::{
-    prelude::{AllocVar, CurveVar, EqGadget},
+    prelude::{AllocVar, Boolean, CurveVar, EqGadget},
     uint8::UInt8,
     ToBitsGadget,
 };
-use ark_relations::r1cs::{ConstraintSynthesizer, ToConstraintField};
+use ark_relations::r1cs::{
+    ConstraintSynthesizer, ConstraintSystem, SynthesisError, ToConstraintField,
+};
 use ark_snark::SNARK;
 use decaf377::{
-    r1cs::{CountConstraints, ElementVar, FqVar},
-    Bls12_377, Element, Fq, Fr,
+    groth16, pedersen,
+    r1cs::{self, CountConstraints, ElementVar, FqVar},
+    schnorr, Bls12_377, Element, Encoding, Fq, Fr, ProofError,
 };
 use rand_core::OsRng;
+use sha2::Sha512;
 
 fn element_strategy() -> BoxedStrategy<Element> {
     any::<[u8; 32]>()
@@ -36,6 +41,17 @@ static DISCRETE_LOG_VK: Lazy<VerifyingKey<Bls12_377>> = Lazy::new(|| {
         .expect("can parse discrete log verifying key")
 });
 
+static FIXED_BASE_PK: Lazy<ProvingKey<Bls12_377>> = Lazy::new(|| {
+    let pk_bytes = include_bytes!("test_vectors/fixed_base_pk.bin");
+    ProvingKey::deserialize_uncompressed(&pk_bytes[..]).expect("can parse fixed base proving key")
+});
+
+static FIXED_BASE_VK: Lazy<VerifyingKey<Bls12_377>> = Lazy::new(|| {
+    let vk_bytes = include_bytes!("test_vectors/fixed_base_vk.param");
+    VerifyingKey::deserialize_uncompressed(&vk_bytes[..])
+        .expect("can parse fixed base verifying key")
+});
+
 static COMPRESSION_PK: Lazy<ProvingKey<Bls12_377>> = Lazy::new(|| {
     let pk_bytes = include_bytes!("test_vectors/compression_pk.bin");
     ProvingKey::deserialize_uncompressed(&pk_bytes[..]).expect("can parse compression proving key")
@@ -70,6 +86,18 @@ static ELLIGATOR_VK: Lazy<VerifyingKey<Bls12_377>> = Lazy::new(|| {
         .expect("can parse elligator verifying key")
 });
 
+static HASH_TO_CURVE_PK: Lazy<ProvingKey<Bls12_377>> = Lazy::new(|| {
+    let pk_bytes = include_bytes!("test_vectors/hash_to_curve_pk.bin");
+    ProvingKey::deserialize_uncompressed(&pk_bytes[..])
+        .expect("can parse hash to curve proving key")
+});
+
+static HASH_TO_CURVE_VK: Lazy<VerifyingKey<Bls12_377>> = Lazy::new(|| {
+    let vk_bytes = include_bytes!("test_vectors/hash_to_curve_vk.param");
+    VerifyingKey::deserialize_uncompressed(&vk_bytes[..])
+        .expect("can parse hash to curve verifying key")
+});
+
 static PUBLIC_ELEMENT_INPUT_PK: Lazy<ProvingKey<Bls12_377>> = Lazy::new(|| {
     let pk_bytes = include_bytes!("test_vectors/public_element_input_pk.bin");
     ProvingKey::deserialize_uncompressed(&pk_bytes[..])
@@ -145,6 +173,17 @@ impl DiscreteLogCircuit {
     }
 }
 
+/// Constraints used by `FixedBaseCircuit`, to catch regressions.
+///
+/// Of these, 765 are the scalar multiplication itself. The rest, shared with
+/// `DiscreteLogCircuit`, witness the scalar bits and compare the result with
+/// the public input.
+const FIXED_BASE_CONSTRAINTS: usize = 2400;
+
+/// Constraints used by `DiscreteLogCircuit`, which multiplies the same
+/// constant base via `scalar_mul_le`, for 1275 constraints.
+const DISCRETE_LOG_CONSTRAINTS: usize = 2910;
+
 fn scalar_strategy_random() -> BoxedStrategy<[u8; 32]> {
     any::<[u8; 32]>().prop_map(|x| x).boxed()
 }
@@ -206,6 +245,129 @@ proptest! {
     }
 }
 
+#[derive(Clone)]
+struct FixedBaseCircuit {
+    // Witness
+    scalar: [u8; 32],
+
+    // Public input
+    pub public: Element,
+}
+
+impl ConstraintSynthesizer<Fq> for FixedBaseCircuit {
+    fn generate_constraints(
+        self,
+        cs: ark_relations::r1cs::ConstraintSystemRef<Fq>,
+    ) -> ark_relations::r1cs::Result<()> {
+        // 1. Add witness variable
+        let witness_vars = UInt8::new_witness_vec(cs.clone(), &self.scalar)?;
+
+        // 2. Add public input variable
+        let public_var = ElementVar::new_input(cs, || Ok(self.public))?;
+
+        // 3. Add constraint that scalar * Basepoint = public
+        let test_public =
+            ElementVar::fixed_base_scalar_mul(Element::GENERATOR, &witness_vars.to_bits_le()?)?;
+        public_var.enforce_equal(&test_public)?;
+
+        Ok(())
+    }
+}
+
+impl FixedBaseCircuit {
+    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+        let scalar = [0u8; 32];
+        let public = Element::default();
+        let circuit = FixedBaseCircuit { scalar, public };
+        let (pk, vk) =
+            Groth16::<Bls12_377, LibsnarkReduction>::circuit_specific_setup(circuit, &mut OsRng)
+                .expect("can perform circuit specific setup");
+        (pk, vk)
+    }
+}
+
+#[test]
+fn fixed_base_constraint_counts() {
+    let scalar = [0u8; 32];
+    let public = Element::default();
+    let (fixed_base, _) =
+        FixedBaseCircuit { scalar, public }.num_constraints_and_instance_variables();
+    let (discrete_log, _) =
+        DiscreteLogCircuit { scalar, public }.num_constraints_and_instance_variables();
+    dbg!(fixed_base, discrete_log);
+
+    assert_eq!(fixed_base, FIXED_BASE_CONSTRAINTS);
+    assert_eq!(discrete_log, DISCRETE_LOG_CONSTRAINTS);
+
+    // The scalar multiplications on their own.
+    let cs = ConstraintSystem::<Fq>::new_ref();
+    let bits = UInt8::new_witness_vec(cs.clone(), &scalar)
+        .unwrap()
+        .to_bits_le()
+        .unwrap();
+    let before = cs.num_constraints();
+    ElementVar::fixed_base_scalar_mul(Element::GENERATOR, &bits).unwrap();
+    assert_eq!(cs.num_constraints() - before, 765);
+    let before = cs.num_constraints();
+    let basepoint_var = ElementVar::new_constant(cs.clone(), Element::GENERATOR).unwrap();
+    basepoint_var.scalar_mul_le(bits.iter()).unwrap();
+    assert_eq!(cs.num_constraints() - before, 1275);
+}
+
+proptest! {
+#![proptest_config(ProptestConfig::with_cases(5))]
+#[test]
+fn groth16_fixed_base_proof_happy_path(scalar in scalar_strategy_random()) {
+        let pk = FIXED_BASE_PK.clone();
+        let vk = FIXED_BASE_VK.clone();
+        let mut rng = OsRng;
+
+        let public = Fr::from_le_bytes_mod_order(&scalar[..]) * Element::GENERATOR;
+
+        // Prover POV
+        let circuit = FixedBaseCircuit { scalar, public };
+        let proof = Groth16::<Bls12_377, LibsnarkReduction>::prove(&pk, circuit, &mut rng)
+            .map_err(|_| anyhow::anyhow!("invalid proof"))
+            .expect("can generate proof");
+
+        // Verifier POV
+        let processed_pvk = Groth16::<Bls12_377, LibsnarkReduction>::process_vk(&vk).expect("can process verifying key");
+        let public_inputs = public.to_field_elements().unwrap();
+        let proof_result =
+            Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(&processed_pvk, &public_inputs, &proof).unwrap();
+
+        assert!(proof_result);
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(5))]
+    #[test]
+    fn groth16_fixed_base_proof_unhappy_path(scalar in scalar_strategy_random()) {
+        let pk = FIXED_BASE_PK.clone();
+        let vk = FIXED_BASE_VK.clone();
+        let mut rng = OsRng;
+
+        let public = Fr::from_le_bytes_mod_order(&scalar[..]) * Element::GENERATOR;
+
+        let wrong_public = Fr::from(666u64) * Element::GENERATOR;
+
+        // Prover POV
+        let circuit = FixedBaseCircuit { scalar, public };
+        let proof = Groth16::<Bls12_377, LibsnarkReduction>::prove(&pk, circuit, &mut rng)
+            .map_err(|_| anyhow::anyhow!("invalid proof"))
+            .expect("can generate proof");
+
+        // Verifier POV
+        let processed_pvk = Groth16::<Bls12_377, LibsnarkReduction>::process_vk(&vk).expect("can process verifying key");
+        let public_inputs = wrong_public.to_field_elements().unwrap();
+        let proof_result =
+            Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(&processed_pvk, &public_inputs, &proof).unwrap();
+
+        assert!(!proof_result);
+    }
+}
+
 #[derive(Clone)]
 struct CompressionCircuit {
     // Witness
@@ -530,6 +692,102 @@ fn groth16_elligator_proof_unhappy_path(field_element in fq_strategy()) {
 }
 }
 
+#[derive(Clone)]
+struct HashToCurveCircuit {
+    // Witness
+    r_1: Fq,
+    r_2: Fq,
+
+    // Public input
+    pub point: Element,
+}
+
+impl ConstraintSynthesizer<Fq> for HashToCurveCircuit {
+    fn generate_constraints(
+        self,
+        cs: ark_relations::r1cs::ConstraintSystemRef<Fq>,
+    ) -> ark_relations::r1cs::Result<()> {
+        // 1. Add witness variables
+        let r_1_var = FqVar::new_witness(cs.clone(), || Ok(self.r_1))?;
+        let r_2_var = FqVar::new_witness(cs.clone(), || Ok(self.r_2))?;
+
+        // 2. Add public input variable
+        let public_var: ElementVar = AllocVar::new_input(cs, || Ok(self.point))?;
+
+        // 3. Add hash to curve constraints
+        let test_public = ElementVar::hash_to_curve(&r_1_var, &r_2_var)?;
+        public_var.enforce_equal(&test_public)?;
+
+        Ok(())
+    }
+}
+
+impl HashToCurveCircuit {
+    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+        let r_1 = Fq::from(100u64);
+        let r_2 = Fq::from(200u64);
+        let point = Element::hash_to_curve(&r_1, &r_2);
+        let circuit = HashToCurveCircuit { point, r_1, r_2 };
+        let (pk, vk) =
+            Groth16::<Bls12_377, LibsnarkReduction>::circuit_specific_setup(circuit, &mut OsRng)
+                .expect("can perform circuit specific setup");
+        (pk, vk)
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+#[test]
+fn groth16_hash_to_curve_proof_happy_path(r_1 in fq_strategy(), r_2 in fq_strategy()) {
+    let pk = HASH_TO_CURVE_PK.clone();
+    let vk = HASH_TO_CURVE_VK.clone();
+    let mut rng = OsRng;
+
+    // Prover POV
+    let point = Element::hash_to_curve(&r_1, &r_2);
+    let circuit = HashToCurveCircuit { point, r_1, r_2 };
+    dbg!(circuit.clone().num_constraints_and_instance_variables());
+
+    let proof = Groth16::<Bls12_377, LibsnarkReduction>::prove(&pk, circuit, &mut rng)
+        .map_err(|_| anyhow::anyhow!("invalid proof"))
+        .expect("can generate proof");
+
+    // Verifier POV
+    let processed_pvk = Groth16::<Bls12_377, LibsnarkReduction>::process_vk(&vk).expect("can process verifying key");
+    let public_inputs = point.to_field_elements().unwrap();
+    let proof_result =
+        Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(&processed_pvk, &public_inputs, &proof).unwrap();
+
+    assert!(proof_result);
+}
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+#[test]
+fn groth16_hash_to_curve_proof_unhappy_path(r_1 in fq_strategy(), r_2 in fq_strategy()) {
+    let pk = HASH_TO_CURVE_PK.clone();
+    let vk = HASH_TO_CURVE_VK.clone();
+    let mut rng = OsRng;
+
+    // Prover POV
+    let point = Element::hash_to_curve(&r_1, &r_2);
+    let circuit = HashToCurveCircuit { point, r_1, r_2 };
+    let proof = Groth16::<Bls12_377, LibsnarkReduction>::prove(&pk, circuit, &mut rng)
+        .map_err(|_| anyhow::anyhow!("invalid proof"))
+        .expect("can generate proof");
+
+    // Verifier POV: a single elligator map of r_1 is not the hash of (r_1, r_2).
+    let wrong_point = Element::encode_to_curve(&r_1);
+    let processed_pvk = Groth16::<Bls12_377, LibsnarkReduction>::process_vk(&vk).expect("can process verifying key");
+    let public_inputs = wrong_point.to_field_elements().unwrap();
+    let proof_result =
+        Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(&processed_pvk, &public_inputs, &proof).unwrap();
+
+    assert!(!proof_result);
+}
+}
+
 #[derive(Clone, Debug)]
 struct PublicElementInput {
     pub point: Element,
@@ -739,6 +997,470 @@ fn groth16_add_addassign(a in element_strategy(), b in element_strategy()) {
 }
 }
 
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(5))]
+    #[test]
+    fn native_groth16_verifier_matches_arkworks(a in element_strategy(), b in element_strategy()) {
+        let pk = ADD_ASSIGN_ADD_PK.clone();
+        let vk_bytes = include_bytes!("test_vectors/add_assign_add_vk.param");
+        let vk = groth16::VerifyingKey::from_uncompressed(&vk_bytes[..])
+            .expect("can parse add assign add verifying key");
+        let pvk = groth16::PreparedVerifyingKey::from(&vk);
+
+        let circuit = AddAssignAddCircuit {
+            a,
+            b,
+            c: a + b,
+            d: a - b,
+        };
+        let proof = Groth16::<Bls12_377, LibsnarkReduction>::prove(&pk, circuit, &mut OsRng)
+            .expect("can generate proof");
+        let mut proof_bytes = [0u8; 192];
+        proof.serialize_compressed(&mut proof_bytes[..]).unwrap();
+        let proof = groth16::Proof::from_compressed(&proof_bytes).expect("can parse proof");
+
+        let mut public_inputs = (a + b).to_field_elements().unwrap();
+        public_inputs.extend_from_slice(&(a - b).to_field_elements().unwrap());
+        assert_eq!(groth16::verify(&vk, &public_inputs, &proof), Ok(()));
+        assert_eq!(pvk.verify(&public_inputs, &proof), Ok(()));
+
+        public_inputs.swap(0, 1);
+        assert_eq!(
+            groth16::verify(&vk, &public_inputs, &proof),
+            Err(ProofError::InvalidProof)
+        );
+        assert_eq!(pvk.verify(&public_inputs, &proof), Err(ProofError::InvalidProof));
+    }
+}
+
+#[derive(Clone)]
+struct VariableBaseScalarMulCircuit {
+    // Witness
+    scalar: [u8; 32],
+    point: Element,
+
+    // Public input
+    pub public: Element,
+
+    // Whether to use the bit-by-bit ladder instead of `scalar_mul_le`
+    ladder: bool,
+}
+
+/// The generic double-and-add ladder that `CurveVar::scalar_mul_le` provides
+/// by default.
+fn ladder_scalar_mul_le(
+    point_var: &ElementVar,
+    bits: &[Boolean<Fq>],
+) -> ark_relations::r1cs::Result<ElementVar> {
+    let mut res = ElementVar::zero();
+    let mut multiple = point_var.clone();
+    for bit in bits {
+        let tmp = res.clone() + &multiple;
+        res = bit.select(&tmp, &res)?;
+        multiple.double_in_place()?;
+    }
+    Ok(res)
+}
+
+impl ConstraintSynthesizer<Fq> for VariableBaseScalarMulCircuit {
+    fn generate_constraints(
+        self,
+        cs: ark_relations::r1cs::ConstraintSystemRef<Fq>,
+    ) -> ark_relations::r1cs::Result<()> {
+        // 1. Add witness variables
+        let scalar_vars = UInt8::new_witness_vec(cs.clone(), &self.scalar)?;
+        let point_var = ElementVar::new_witness(cs.clone(), || Ok(self.point))?;
+
+        // 2. Add public input variable
+        let public_var = ElementVar::new_input(cs, || Ok(self.public))?;
+
+        // 3. Add constraint that scalar * point = public
+        let bits = scalar_vars.to_bits_le()?;
+        let test_public = if self.ladder {
+            ladder_scalar_mul_le(&point_var, &bits)?
+        } else {
+            point_var.scalar_mul_le(bits.iter())?
+        };
+        public_var.enforce_equal(&test_public)?;
+
+        Ok(())
+    }
+}
+
+impl VariableBaseScalarMulCircuit {
+    fn check_satisfied(self) -> bool {
+        let cs = ConstraintSystem::new_ref();
+        self.generate_constraints(cs.clone())
+            .expect("can generate constraints");
+        cs.is_satisfied().expect("can check constraints")
+    }
+}
+
+#[test]
+fn windowed_scalar_mul_uses_fewer_constraints_than_ladder() {
+    let circuit = VariableBaseScalarMulCircuit {
+        scalar: [0u8; 32],
+        point: Element::GENERATOR,
+        public: Element::default(),
+        ladder: false,
+    };
+    let (windowed, _) = circuit.clone().num_constraints_and_instance_variables();
+    let (ladder, _) = VariableBaseScalarMulCircuit {
+        ladder: true,
+        ..circuit
+    }
+    .num_constraints_and_instance_variables();
+    dbg!(windowed, ladder);
+
+    assert!(windowed < ladder);
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+#[test]
+fn variable_base_scalar_mul_satisfied(scalar in scalar_strategy_random(), point in element_strategy()) {
+    let public = Fr::from_le_bytes_mod_order(&scalar[..]) * point;
+
+    for ladder in [false, true] {
+        let circuit = VariableBaseScalarMulCircuit { scalar, point, public, ladder };
+        assert!(circuit.check_satisfied());
+
+        let wrong_public = public + Element::GENERATOR;
+        let circuit = VariableBaseScalarMulCircuit { scalar, point, public: wrong_public, ladder };
+        assert!(!circuit.check_satisfied());
+    }
+}
+}
+
+#[derive(Clone)]
+struct MultiscalarMulCircuit {
+    // Witness
+    scalars: Vec<[u8; 32]>,
+    points: Vec<Element>,
+
+    // Which of the points are circuit constants rather than witnesses
+    constant: Vec<bool>,
+
+    // Public input
+    pub public: Element,
+
+    // Whether to sum separate `scalar_mul_le` calls instead
+    separate: bool,
+}
+
+impl ConstraintSynthesizer<Fq> for MultiscalarMulCircuit {
+    fn generate_constraints(
+        self,
+        cs: ark_relations::r1cs::ConstraintSystemRef<Fq>,
+    ) -> ark_relations::r1cs::Result<()> {
+        // 1. Add witness variables
+        let mut scalar_vars = Vec::new();
+        for scalar in &self.scalars {
+            scalar_vars.push(UInt8::new_witness_vec(cs.clone(), scalar)?.to_bits_le()?);
+        }
+        let mut point_vars = Vec::new();
+        for (point, constant) in self.points.iter().zip(&self.constant) {
+            point_vars.push(if *constant {
+                ElementVar::new_constant(cs.clone(), *point)?
+            } else {
+                ElementVar::new_witness(cs.clone(), || Ok(*point))?
+            });
+        }
+
+        // 2. Add public input variable
+        let public_var = ElementVar::new_input(cs, || Ok(self.public))?;
+
+        // 3. Add constraint that sum_i scalar_i * point_i = public
+        let test_public = if self.separate {
+            let mut sum = ElementVar::zero();
+            for (bits, point_var) in scalar_vars.iter().zip(&point_vars) {
+                sum += point_var.scalar_mul_le(bits.iter())?;
+            }
+            sum
+        } else {
+            ElementVar::multiscalar_mul(&scalar_vars, &point_vars)?
+        };
+        public_var.enforce_equal(&test_public)?;
+
+        Ok(())
+    }
+}
+
+impl MultiscalarMulCircuit {
+    fn check_satisfied(self) -> bool {
+        let cs = ConstraintSystem::new_ref();
+        self.generate_constraints(cs.clone())
+            .expect("can generate constraints");
+        cs.is_satisfied().expect("can check constraints")
+    }
+}
+
+#[test]
+fn multiscalar_mul_uses_fewer_constraints_than_separate_scalar_muls() {
+    for constant in [vec![false; 4], vec![true, true, false, false]] {
+        let circuit = MultiscalarMulCircuit {
+            scalars: vec![[0u8; 32]; 4],
+            points: vec![Element::GENERATOR; 4],
+            constant,
+            public: Element::default(),
+            separate: false,
+        };
+        let (multiscalar, _) = circuit.clone().num_constraints_and_instance_variables();
+        let (separate, _) = MultiscalarMulCircuit {
+            separate: true,
+            ..circuit
+        }
+        .num_constraints_and_instance_variables();
+        dbg!(multiscalar, separate);
+
+        assert!(multiscalar < separate);
+    }
+}
+
+#[test]
+fn multiscalar_mul_rejects_length_mismatch() {
+    let cs = ConstraintSystem::<Fq>::new_ref();
+    let bits = UInt8::new_witness_vec(cs.clone(), &[0u8; 32])
+        .unwrap()
+        .to_bits_le()
+        .unwrap();
+    let base = ElementVar::new_constant(cs, Element::GENERATOR).unwrap();
+    let result = ElementVar::multiscalar_mul(&[&bits[..], &bits[..]], &[base]);
+    assert!(matches!(result, Err(SynthesisError::Unsatisfiable)));
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+#[test]
+fn multiscalar_mul_matches_variable_base_msm(
+    inputs in prop::collection::vec((scalar_strategy_random(), element_strategy(), any::<bool>()), 0..5)
+) {
+    let scalars: Vec<[u8; 32]> = inputs.iter().map(|(scalar, _, _)| *scalar).collect();
+    let points: Vec<Element> = inputs.iter().map(|(_, point, _)| *point).collect();
+    let constant: Vec<bool> = inputs.iter().map(|(_, _, constant)| *constant).collect();
+
+    let frs: Vec<Fr> = scalars.iter().map(|scalar| Fr::from_le_bytes_mod_order(&scalar[..])).collect();
+    let public = Element::msm(&Element::normalize_batch(&points), &frs).expect("lengths match");
+
+    for separate in [false, true] {
+        let circuit = MultiscalarMulCircuit { scalars: scalars.clone(), points: points.clone(), constant: constant.clone(), public, separate };
+        assert!(circuit.check_satisfied());
+
+        let wrong_public = public + Element::GENERATOR;
+        let circuit = MultiscalarMulCircuit { scalars: scalars.clone(), points: points.clone(), constant: constant.clone(), public: wrong_public, separate };
+        assert!(!circuit.check_satisfied());
+    }
+}
+}
+
+#[derive(Clone)]
+struct PedersenCircuit {
+    // Witness
+    values: Vec<[u8; 32]>,
+    blinding: [u8; 32],
+
+    // Public input
+    pub commitment: Element,
+}
+
+fn pedersen_generators() -> pedersen::Generators {
+    pedersen::Generators::new::<Sha512>(b"decaf377-pedersen-test", 3)
+}
+
+impl ConstraintSynthesizer<Fq> for PedersenCircuit {
+    fn generate_constraints(
+        self,
+        cs: ark_relations::r1cs::ConstraintSystemRef<Fq>,
+    ) -> ark_relations::r1cs::Result<()> {
+        // 1. Add witness variables
+        let mut value_vars = Vec::new();
+        for value in &self.values {
+            value_vars.push(UInt8::new_witness_vec(cs.clone(), value)?.to_bits_le()?);
+        }
+        let blinding_vars = UInt8::new_witness_vec(cs.clone(), &self.blinding)?.to_bits_le()?;
+
+        // 2. Add public input variable
+        let commitment_var = ElementVar::new_input(cs, || Ok(self.commitment))?;
+
+        // 3. Add constraint that the commitment opens to the witnessed values
+        let test_commitment =
+            r1cs::pedersen::commit_vector(&pedersen_generators(), &value_vars, &blinding_vars)?;
+        commitment_var.enforce_equal(&test_commitment)?;
+
+        Ok(())
+    }
+}
+
+impl PedersenCircuit {
+    fn check_satisfied(self) -> bool {
+        let cs = ConstraintSystem::new_ref();
+        self.generate_constraints(cs.clone())
+            .expect("can generate constraints");
+        cs.is_satisfied().expect("can check constraints")
+    }
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(10))]
+#[test]
+fn pedersen_commitment_opens_in_circuit(
+    values in prop::collection::vec(scalar_strategy_random(), 0..=3),
+    blinding in scalar_strategy_random(),
+) {
+    let frs: Vec<Fr> = values.iter().map(|value| Fr::from_le_bytes_mod_order(&value[..])).collect();
+    let commitment = pedersen_generators().commit_vector(&frs, Fr::from_le_bytes_mod_order(&blinding[..]));
+
+    let circuit = PedersenCircuit { values: values.clone(), blinding, commitment };
+    assert!(circuit.check_satisfied());
+
+    let mut wrong_blinding = blinding;
+    wrong_blinding[0] ^= 1;
+    let circuit = PedersenCircuit { values, blinding: wrong_blinding, commitment };
+    assert!(!circuit.check_satisfied());
+}
+}
+
+#[derive(Clone)]
+struct SchnorrCircuit {
+    // Witness
+    message: Vec<Fq>,
+    signature: schnorr::Signature,
+
+    // Public input
+    pub vk: Element,
+}
+
+impl ConstraintSynthesizer<Fq> for SchnorrCircuit {
+    fn generate_constraints(
+        self,
+        cs: ark_relations::r1cs::ConstraintSystemRef<Fq>,
+    ) -> ark_relations::r1cs::Result<()> {
+        // 1. Add witness variables
+        let mut message_vars = Vec::new();
+        for element in &self.message {
+            message_vars.push(FqVar::new_witness(cs.clone(), || Ok(*element))?);
+        }
+        let signature_var =
+            r1cs::schnorr::SignatureVar::new_witness(cs.clone(), || Ok(self.signature))?;
+
+        // 2. Add public input variable
+        let vk_var = ElementVar::new_input(cs, || Ok(self.vk))?;
+
+        // 3. Add signature verification constraints
+        r1cs::schnorr::verify(&vk_var, &message_vars, &signature_var)?;
+
+        Ok(())
+    }
+}
+
+impl SchnorrCircuit {
+    /// Sign `message`, encoded as the concatenation of its elements' bytes.
+    fn new(sk: &schnorr::SigningKey, message: Vec<Fq>) -> Self {
+        let bytes: Vec<u8> = message
+            .iter()
+            .flat_map(|element| element.to_bytes())
+            .collect();
+        let signature = sk.sign(&bytes);
+        let vk = Encoding(sk.verification_key().to_bytes())
+            .vartime_decompress()
+            .expect("verification key is valid");
+        SchnorrCircuit {
+            message,
+            signature,
+            vk,
+        }
+    }
+
+    fn generate_test_parameters() -> (ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>) {
+        let sk = schnorr::SigningKey::new(&mut OsRng);
+        let circuit = SchnorrCircuit::new(&sk, vec![Fq::from(100u64)]);
+        let (pk, vk) =
+            Groth16::<Bls12_377, LibsnarkReduction>::circuit_specific_setup(circuit, &mut OsRng)
+                .expect("can perform circuit specific setup");
+        (pk, vk)
+    }
+}
+
+// The in-circuit SHA-512 makes these keys far too large to store as test
+// vectors, so they are generated when the tests run.
+static SCHNORR_PARAMS: Lazy<(ProvingKey<Bls12_377>, VerifyingKey<Bls12_377>)> =
+    Lazy::new(SchnorrCircuit::generate_test_parameters);
+
+#[test]
+fn groth16_schnorr_proof_happy_path() {
+    let (pk, vk) = &*SCHNORR_PARAMS;
+    let mut rng = OsRng;
+
+    // Prover POV
+    let sk = schnorr::SigningKey::new(&mut rng);
+    let circuit = SchnorrCircuit::new(&sk, vec![Fq::from(12345u64)]);
+    let public = circuit.vk;
+    dbg!(circuit.clone().num_constraints_and_instance_variables());
+
+    let proof = Groth16::<Bls12_377, LibsnarkReduction>::prove(pk, circuit, &mut rng)
+        .map_err(|_| anyhow::anyhow!("invalid proof"))
+        .expect("can generate proof");
+
+    // Verifier POV
+    let processed_pvk =
+        Groth16::<Bls12_377, LibsnarkReduction>::process_vk(vk).expect("can process verifying key");
+    let public_inputs = public.to_field_elements().unwrap();
+    let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+        &processed_pvk,
+        &public_inputs,
+        &proof,
+    )
+    .unwrap();
+
+    assert!(proof_result);
+}
+
+#[test]
+fn groth16_schnorr_proof_unhappy_path() {
+    let (pk, vk) = &*SCHNORR_PARAMS;
+    let mut rng = OsRng;
+
+    // Prover POV
+    let sk = schnorr::SigningKey::new(&mut rng);
+    let circuit = SchnorrCircuit::new(&sk, vec![Fq::from(12345u64)]);
+    let proof = Groth16::<Bls12_377, LibsnarkReduction>::prove(pk, circuit, &mut rng)
+        .map_err(|_| anyhow::anyhow!("invalid proof"))
+        .expect("can generate proof");
+
+    // Verifier POV
+    let wrong_vk = Fr::rand(&mut rng) * Element::GENERATOR;
+    let processed_pvk =
+        Groth16::<Bls12_377, LibsnarkReduction>::process_vk(vk).expect("can process verifying key");
+    let public_inputs = wrong_vk.to_field_elements().unwrap();
+    let proof_result = Groth16::<Bls12_377, LibsnarkReduction>::verify_with_processed_vk(
+        &processed_pvk,
+        &public_inputs,
+        &proof,
+    )
+    .unwrap();
+
+    assert!(!proof_result);
+}
+
+#[test]
+fn schnorr_rejects_signature_on_other_message() {
+    let sk = schnorr::SigningKey::new(&mut OsRng);
+    let mut circuit = SchnorrCircuit::new(&sk, vec![Fq::from(1u64), Fq::from(2u64)]);
+    let cs = ConstraintSystem::new_ref();
+    circuit
+        .clone()
+        .generate_constraints(cs.clone())
+        .expect("can generate constraints");
+    assert!(cs.is_satisfied().unwrap());
+
+    circuit.message[1] = Fq::from(3u64);
+    let cs = ConstraintSystem::new_ref();
+    circuit
+        .generate_constraints(cs.clone())
+        .expect("can generate constraints");
+    assert!(!cs.is_satisfied().unwrap());
+}
+
 fn write_params(
     target_dir: &PathBuf,
     name: &str,
@@ -772,6 +1494,10 @@ fn generate_test_vectors() {
     )
     .expect("can write test vectors");
 
+    let (pk, vk) = FixedBaseCircuit::generate_test_parameters();
+    write_params(&PathBuf::from("tests/test_vectors"), "fixed_base", &pk, &vk)
+        .expect("can write test vectors");
+
     let (pk, vk) = CompressionCircuit::generate_test_parameters();
     write_params(
         &PathBuf::from("tests/test_vectors"),
@@ -794,6 +1520,15 @@ fn generate_test_vectors() {
     write_params(&PathBuf::from("tests/test_vectors"), "elligator", &pk, &vk)
         .expect("can write test vectors");
 
+    let (pk, vk) = HashToCurveCircuit::generate_test_parameters();
+    write_params(
+        &PathBuf::from("tests/test_vectors"),
+        "hash_to_curve",
+        &pk,
+        &vk,
+    )
+    .expect("can write test vectors");
+
     let (pk, vk) = PublicElementInput::generate_test_parameters();
     write_params(
         &PathBuf::from("tests/test_vectors"),
@@ -1,5 +1,7 @@
 #![allow(non_snake_case)]
 
+use ark_ec::Group;
+use ark_ff::PrimeField;
 use decaf377::{Element, Fq, Fr};
 use proptest::prelude::*;
 
@@ -41,6 +43,14 @@ proptest! {
         );
     }
 
+    #[test]
+    fn scalar_mul_matches_arkworks_mul_bigint(
+        a in fr_strategy(),
+        P in element_strategy(),
+    ) {
+        assert_eq!(a * P, P.mul_bigint(a.into_bigint()));
+    }
+
     #[test]
     fn vartime_multiscalar_mul_matches_scalar_mul(
         a in fr_strategy(),
@@ -59,3 +69,18 @@ proptest! {
         );
     }
 }
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(4))]
+    #[test]
+    fn vartime_multiscalar_mul_matches_naive_sum(
+        inputs in prop::collection::vec((fr_strategy(), element_strategy()), 100..200),
+    ) {
+        let (scalars, points): (Vec<Fr>, Vec<Element>) = inputs.into_iter().unzip();
+        let expected = scalars
+            .iter()
+            .zip(points.iter())
+            .fold(Element::IDENTITY, |acc, (a, P)| acc + a * P);
+        assert_eq!(Element::vartime_multiscalar_mul(&scalars, &points), expected);
+    }
+}
@@ -0,0 +1,44 @@
+use decaf377::schnorr::{Signature, SigningKey, VerificationKey};
+
+fn decode<const N: usize>(hex: &str) -> [u8; N] {
+    let mut bytes = [0u8; N];
+    hex::decode_to_slice(hex, &mut bytes).unwrap();
+    bytes
+}
+
+/// Regression vectors for signatures under the signing key `01 02 ... 1f 00`.
+///
+/// These were generated by this implementation, and only guard against
+/// changes in its output.
+#[test]
+fn schnorr_regression_vectors() {
+    let sk_bytes = decode("0102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f00");
+    let vk_bytes = decode("b878d91cc7de2c84510dab23b142dd2acb26af714f6289906a7398101353d601");
+    let vectors = [
+        (
+            "",
+            "90a6a2f0ac3f55b304e93aa7d746b32c87fba4e42e076f571f3773d24120760f\
+             e282d490040e8af4533fe8f5796e513e32969a3561e7b8841f5190dbe97bd902",
+        ),
+        (
+            "abc",
+            "46c329e5becc97d8cf63aed48eadde3475e9983c6c3ab66831e13c526add940b\
+             abe7326e1be00bed4435fae07b7294fb067360724d1aafa88e9f10382e299803",
+        ),
+        (
+            "abcdef0123456789",
+            "d272c944eebdc85a0a7d45eb657d2db5e2cb696d491601945eb4eb221b87c200\
+             38e5f9052b76da45983f3c862d1208c1eeaabe1b5c5bfffe58eb260cb8a10800",
+        ),
+    ];
+
+    let sk = SigningKey::from_bytes(&sk_bytes).unwrap();
+    let vk = VerificationKey::from_bytes(&vk_bytes).unwrap();
+    assert_eq!(sk.verification_key(), vk);
+
+    for (msg, expected) in vectors {
+        let signature = Signature::from_bytes(&decode(expected)).unwrap();
+        assert_eq!(sk.sign(msg.as_bytes()), signature);
+        assert_eq!(vk.verify(msg.as_bytes(), &signature), Ok(()));
+    }
+}
@@ -0,0 +1,22 @@
+//! These tests use only `core`, so that they also exercise the `no_std` build
+//! of the crate, as in `cargo test --no-default-features --test zeroize`.
+#![no_std]
+
+use decaf377::{Element, Encoding, Fq, Fr};
+use zeroize::Zeroize;
+
+#[test]
+fn zeroize_clears_scalars_and_encodings() {
+    let mut scalar = -Fr::ONE;
+    scalar.zeroize();
+    assert_eq!(scalar, Fr::ZERO);
+
+    let mut field_element = -Fq::ONE;
+    field_element.zeroize();
+    assert_eq!(field_element, Fq::ZERO);
+
+    let mut encoding = Element::GENERATOR.vartime_compress();
+    assert_ne!(encoding, Encoding([0u8; 32]));
+    encoding.zeroize();
+    assert_eq!(encoding, Encoding([0u8; 32]));
+}